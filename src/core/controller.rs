@@ -1,7 +1,9 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::{Arc, LazyLock, RwLock, Weak};
+use std::time::Instant;
+use crate::Error;
 use crate::core::View;
-use crate::interfaces::{ICommand, IController, INotification, IView};
+use crate::interfaces::{CommandFactory, IController, IInterceptor, INotification, IView};
 use crate::patterns::Observer;
 
 static INSTANCE_MAP: LazyLock<RwLock<HashMap<String, Arc<dyn IController>>>> = LazyLock::new(|| Default::default());
@@ -22,8 +24,18 @@ pub struct Controller {
     key: String,
     /// Local reference to this core's IView
     view: Weak<dyn IView>,
-    /// Mapping of `Notification` names to Command factory functions
-    command_map: RwLock<HashMap<String, fn() -> Box<dyn ICommand + Send + Sync>>>
+    /// Mapping of `Notification` names to Command mappings, in execution order
+    command_map: RwLock<HashMap<String, Vec<CommandMapping>>>,
+    /// The `IInterceptor`s called around the execution of the `ICommand`s for each `Notification`
+    interceptors: RwLock<Vec<Arc<dyn IInterceptor>>>
+}
+
+/// A `Notification` to `ICommand` mapping held by the `Controller`.
+struct CommandMapping {
+    /// The factory creating the `ICommand`
+    factory: CommandFactory,
+    /// The execution priority of the `ICommand`, highest first
+    priority: i32,
 }
 
 impl Controller {
@@ -38,6 +50,7 @@ impl Controller {
             key: key.into(),
             view: Arc::downgrade(&(View::get_instance(&key, |k| View::new(k)))),
             command_map: RwLock::new(HashMap::new()),
+            interceptors: RwLock::new(Vec::new()),
         }
     }
 
@@ -62,6 +75,50 @@ impl Controller {
     pub fn remove_controller(key: &str) {
         INSTANCE_MAP.write().unwrap().remove(key);
     }
+
+    /// List the Multiton keys of the `IController` instances.
+    pub(crate) fn keys() -> Vec<String> {
+        INSTANCE_MAP.read().map(|map| map.keys().cloned().collect()).unwrap_or_default()
+    }
+
+    /// Look up an `IController` instance without creating it.
+    ///
+    /// # Arguments
+    /// * `key` - Multiton key of the `IController` instance.
+    pub(crate) fn instance(key: &str) -> Option<Arc<dyn IController>> {
+        INSTANCE_MAP.read().ok().and_then(|map| map.get(key).cloned())
+    }
+
+    /// Register this `Controller` with the `IView` as an `Observer` of the given `INotification`.
+    ///
+    /// # Arguments
+    /// * `notification_name` - The name of the `INotification` to observe.
+    ///
+    /// # Returns
+    /// `Error::NotFound` if this core's `IView` has been removed.
+    fn observe(&self, notification_name: &str) -> Result<(), Error> {
+        let view = self.view.upgrade().ok_or_else(|| Error::NotFound { kind: "View", name: self.key.clone() })?;
+        let context = Controller::get_instance(&self.key, Controller::new);
+        let notify = {
+            let controller = Arc::clone(&context);
+            Arc::new(move |notification: &Arc<dyn INotification>| {
+                controller.execute_command(notification);
+            })
+        };
+        view.register_observer(notification_name, Arc::new(Observer::new(Some(notify), Some(Arc::new(context)))));
+        Ok(())
+    }
+
+    /// Remove this `Controller`'s `Observer` of the given `INotification` from the `IView`.
+    ///
+    /// # Arguments
+    /// * `notification_name` - The name of the `INotification` to stop observing.
+    fn unobserve(&self, notification_name: &str) {
+        if let Some(view) = self.view.upgrade() {
+            let context = Controller::get_instance(&self.key, Controller::new);
+            view.remove_observer(notification_name, Arc::new(context));
+        }
+    }
 }
 
 impl IController for Controller {
@@ -80,24 +137,16 @@ impl IController for Controller {
     ///
     /// # Arguments
     /// * `notification_name` - The name of the INotification to associate the `ICommand` with.
-    /// * `factory` - A function that creates a new instance of the `ICommand`.
-    fn register_command(&self, notification_name: &str, factory: fn() -> Box<dyn ICommand + Send + Sync>) {
-        self.command_map.write().ok()
-            .and_then(|mut map| {
-                if !map.contains_key(notification_name) && let Some(view) = self.view.upgrade() {
-                    let context = Controller::get_instance(&self.key, |k| Controller::new(k));
-                    let notify = {
-                        let controller = Arc::clone(&context);
-                        Arc::new(move |notification: &Arc<dyn INotification>| {
-                            controller.execute_command(&notification);
-                        })
-                    };
-
-                    let observer = Observer::new(Some(notify), Some(Arc::new(context)));
-                    view.register_observer(notification_name, Arc::new(observer));
-                }
-                map.insert(notification_name.into(), factory)
-            });
+    /// * `factory` - A closure that creates a new instance of the `ICommand`.
+    ///
+    /// Replaces any `ICommand`s previously mapped to the `notification_name`.
+    fn register_command(&self, notification_name: &str, factory: CommandFactory) {
+        if let Ok(mut map) = self.command_map.write() {
+            if !map.contains_key(notification_name) {
+                let _ = self.observe(notification_name);
+            }
+            map.insert(notification_name.into(), vec![CommandMapping { factory, priority: 0 }]);
+        }
     }
 
     /// Execute the `ICommand` previously registered as the handler for `INotifications`
@@ -106,13 +155,7 @@ impl IController for Controller {
     /// # Arguments
     /// * `notification` - The `INotification` to execute the associated `ICommand` for
     fn execute_command(&self, notification: &Arc<dyn INotification>) {
-        self.command_map.read().ok()
-            .and_then(|map| map.get(notification.name()).cloned())
-            .map(|factory| {
-                let mut command = factory();
-                command.initialize_notifier(&self.key);
-                command.execute(notification);
-            });
+        let _ = self.try_execute_command(notification);
     }
 
     /// Check if an `ICommand` is registered for a given `INotification` name with the `Controller`.
@@ -122,22 +165,179 @@ impl IController for Controller {
     ///
     /// Returns `true` if an `ICommand` is currently registered for the given `notification_name`, otherwise `false`.
     fn has_command(&self, notification_name: &str) -> bool {
-        self.command_map.read().ok()
-            .map(|map| map.contains_key(notification_name))
-            .unwrap_or(false)
+        self.try_has_command(notification_name).unwrap_or(false)
     }
 
     /// Remove a previously registered `Notification` to `ICommand` mapping from the `Controller`.
     ///
+    /// Removes every `ICommand` mapped to the `notification_name`.
+    ///
     /// # Arguments
     /// * `notification_name` - The name of the `INotification` to remove the `ICommand` mapping for.
     fn remove_command(&self, notification_name: &str) {
-        self.command_map.write().ok()
-            .and_then(|mut map| map.remove(notification_name))
-            .and_then(|_| self.view.upgrade())
-            .map(|view| {
-                let context = Controller::get_instance(&self.key, |k| Controller::new(k));
-                view.remove_observer(notification_name, Arc::new(context));
+        let _ = self.try_remove_command(notification_name);
+    }
+
+    /// Add an `INotification` to `ICommand` mapping alongside any existing mappings for the
+    /// `INotification`.
+    ///
+    /// # Arguments
+    /// * `notification_name` - The name of the `INotification` to associate the `ICommand` with.
+    /// * `factory` - A closure that creates a new instance of the `ICommand`.
+    /// * `priority` - The execution priority of the `ICommand`, highest first.
+    fn add_command(&self, notification_name: &str, factory: CommandFactory, priority: i32) {
+        if let Ok(mut map) = self.command_map.write() {
+            let mappings = map.entry(notification_name.into()).or_default();
+            if mappings.is_empty() {
+                let _ = self.observe(notification_name);
+            }
+            let index = mappings.partition_point(|mapping| mapping.priority >= priority);
+            mappings.insert(index, CommandMapping { factory, priority });
+        }
+    }
+
+    /// Count the `ICommand`s mapped to each `INotification` name.
+    ///
+    /// # Returns
+    /// The number of `ICommand`s mapped to each `INotification` name.
+    fn command_counts(&self) -> BTreeMap<String, usize> {
+        self.command_map.read()
+            .map(|map| map.iter().map(|(name, mappings)| (name.clone(), mappings.len())).collect())
+            .unwrap_or_default()
+    }
+
+    /// Add an `IInterceptor` to the `Controller`.
+    ///
+    /// `IInterceptor`s are called in the order in which they were added.
+    ///
+    /// # Arguments
+    /// * `interceptor` - The `IInterceptor` to call around the execution of the `ICommand`s for
+    ///   each `INotification`.
+    fn add_interceptor(&self, interceptor: Arc<dyn IInterceptor>) {
+        if let Ok(mut interceptors) = self.interceptors.write() {
+            interceptors.push(interceptor);
+        }
+    }
+
+    /// Remove an `IInterceptor` from the `Controller`.
+    ///
+    /// # Arguments
+    /// * `interceptor` - The `IInterceptor` previously added.
+    fn remove_interceptor(&self, interceptor: &Arc<dyn IInterceptor>) {
+        if let Ok(mut interceptors) = self.interceptors.write() {
+            interceptors.retain(|added| !Arc::ptr_eq(added, interceptor));
+        }
+    }
+
+    /// Remove a single `INotification` to `ICommand` mapping, leaving any other mappings for the
+    /// `INotification` in place.
+    ///
+    /// # Arguments
+    /// * `notification_name` - The name of the `INotification` to remove the mapping for.
+    /// * `factory` - The factory the mapping was added with.
+    fn remove_command_factory(&self, notification_name: &str, factory: &CommandFactory) {
+        let emptied = self.command_map.write().ok()
+            .is_some_and(|mut map| {
+                let Some(mappings) = map.get_mut(notification_name) else { return false };
+                mappings.retain(|mapping| !Arc::ptr_eq(&mapping.factory, factory));
+                mappings.is_empty() && map.remove(notification_name).is_some()
             });
+
+        if emptied {
+            self.unobserve(notification_name);
+        }
+    }
+
+    /// Register an `INotification` to `ICommand` mapping, failing if one already exists.
+    ///
+    /// # Arguments
+    /// * `notification_name` - The name of the `INotification` to associate the `ICommand` with.
+    /// * `factory` - A closure that creates a new instance of the `ICommand`.
+    ///
+    /// # Returns
+    /// `Error::AlreadyRegistered` if an `ICommand` is mapped to the `notification_name`,
+    /// `Error::NotFound` if this core's `IView` has been removed, or `Error::LockPoisoned` if the
+    /// command map lock is poisoned.
+    fn try_register_command(&self, notification_name: &str, factory: CommandFactory) -> Result<(), Error> {
+        let mut map = self.command_map.write().map_err(|_| Error::LockPoisoned { lock: "Controller command map" })?;
+        if map.contains_key(notification_name) {
+            return Err(Error::AlreadyRegistered { kind: "Command", name: notification_name.into() });
+        }
+
+        self.observe(notification_name)?;
+        map.insert(notification_name.into(), vec![CommandMapping { factory, priority: 0 }]);
+        Ok(())
+    }
+
+    /// Execute the `ICommand`s registered for the given `INotification`, failing if there are none.
+    ///
+    /// An `ICommand` stopping the `INotification`'s propagation prevents the remaining
+    /// `ICommand`s from executing.
+    ///
+    /// # Arguments
+    /// * `notification` - The `INotification` to execute the associated `ICommand` for.
+    ///
+    /// # Returns
+    /// `Error::NotFound` if no `ICommand` is mapped to the notification's name, or
+    /// `Error::LockPoisoned` if the command map lock is poisoned.
+    fn try_execute_command(&self, notification: &Arc<dyn INotification>) -> Result<(), Error> {
+        let factories: Vec<CommandFactory> = self.command_map.read()
+            .map_err(|_| Error::LockPoisoned { lock: "Controller command map" })?
+            .get(notification.name())
+            .map(|mappings| mappings.iter().map(|mapping| Arc::clone(&mapping.factory)).collect())
+            .ok_or_else(|| Error::NotFound { kind: "Command", name: notification.name().into() })?;
+
+        let interceptors = self.interceptors.read().map(|interceptors| interceptors.clone()).unwrap_or_default();
+        for interceptor in &interceptors {
+            interceptor.before_execute(&self.key, notification, factories.len());
+        }
+
+        let start = Instant::now();
+        let count = factories.len();
+        for factory in factories {
+            if notification.is_propagation_stopped() {
+                break;
+            }
+            let mut command = factory();
+            command.initialize_notifier(&self.key);
+            command.execute(notification);
+        }
+
+        for interceptor in &interceptors {
+            interceptor.after_execute(&self.key, notification, count, start.elapsed());
+        }
+        Ok(())
     }
-}
\ No newline at end of file
+
+    /// Check if an `ICommand` is registered for a given `INotification` name.
+    ///
+    /// # Arguments
+    /// * `notification_name` - The name of the `INotification`.
+    ///
+    /// # Returns
+    /// Whether an `ICommand` is mapped to the `notification_name`, or `Error::LockPoisoned` if
+    /// the command map lock is poisoned.
+    fn try_has_command(&self, notification_name: &str) -> Result<bool, Error> {
+        self.command_map.read()
+            .map(|map| map.contains_key(notification_name))
+            .map_err(|_| Error::LockPoisoned { lock: "Controller command map" })
+    }
+
+    /// Remove an `INotification` to `ICommand` mapping, failing if there is none.
+    ///
+    /// # Arguments
+    /// * `notification_name` - The name of the `INotification` to remove the `ICommand` mapping for.
+    ///
+    /// # Returns
+    /// `Error::NotFound` if no `ICommand` is mapped to the `notification_name`, or
+    /// `Error::LockPoisoned` if the command map lock is poisoned.
+    fn try_remove_command(&self, notification_name: &str) -> Result<(), Error> {
+        self.command_map.write()
+            .map_err(|_| Error::LockPoisoned { lock: "Controller command map" })?
+            .remove(notification_name)
+            .ok_or_else(|| Error::NotFound { kind: "Command", name: notification_name.into() })?;
+
+        self.unobserve(notification_name);
+        Ok(())
+    }
+}
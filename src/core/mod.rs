@@ -1,7 +1,11 @@
 mod controller;
 mod model;
+mod registry;
 mod view;
+#[cfg(feature = "thread-pool")]
+mod worker_pool;
 
 pub use controller::Controller;
 pub use model::Model;
+pub use registry::{CoreInfo, Registry};
 pub use view::View;
@@ -1,8 +1,17 @@
+use std::cell::RefCell;
 use std::collections::HashMap;
-use std::sync::{Arc, LazyLock, RwLock};
-use crate::interfaces::{IModel, IProxy};
+use std::sync::{Arc, LazyLock, Mutex, RwLock};
+use crate::Error;
+use crate::interfaces::{IModel, IProxy, ProxyFactory};
+#[cfg(feature = "persistence")]
+use crate::interfaces::IStore;
 static INSTANCE_MAP: LazyLock<RwLock<HashMap<String, Arc<dyn IModel>>>> = LazyLock::new(|| Default::default());
 
+thread_local! {
+    /// The Multiton keys and names of the lazily registered Proxies this thread is instantiating, in order.
+    static INSTANTIATING: RefCell<Vec<(String, String)>> = const { RefCell::new(Vec::new()) };
+}
+
 /// A PureMVC MultiCore `IModel` implementation.
 ///
 /// In PureMVC, an `IModel` implementor provides access to `IProxy` objects by named lookup.
@@ -21,6 +30,11 @@ pub struct Model {
     key: String,
     /// Mapping of Proxy names to IProxy instances
     proxy_map: RwLock<HashMap<String, Arc<RwLock<dyn IProxy>>>>,
+    /// Mapping of Proxy names to the factories of Proxies not yet instantiated
+    proxy_factories: RwLock<HashMap<String, Arc<LazyProxy>>>,
+    /// The store the data of persistent proxies is kept in
+    #[cfg(feature = "persistence")]
+    store: RwLock<Option<Arc<dyn IStore>>>,
 }
 
 impl Model {
@@ -33,7 +47,10 @@ impl Model {
     pub fn new(key: &str) -> Self {
         Self {
             key: key.into(),
-            proxy_map: RwLock::new(HashMap::new())
+            proxy_map: RwLock::new(HashMap::new()),
+            proxy_factories: RwLock::new(HashMap::new()),
+            #[cfg(feature = "persistence")]
+            store: RwLock::new(None),
         }
     }
 
@@ -53,11 +70,164 @@ impl Model {
 
     /// Remove an `IModel` instance.
     ///
+    /// Every `Proxy` listed by `IModel::proxy_names` is removed first, so its `on_remove` is
+    /// called even if the core has no `IFacade`.
+    ///
     /// # Arguments
     /// * `key` - The multiton key of the `IModel` instance to remove
     pub fn remove_model(key: &str) {
+        if let Some(model) = Self::instance(key) {
+            for proxy_name in model.proxy_names() {
+                model.remove_proxy(&proxy_name);
+            }
+        }
         INSTANCE_MAP.write().unwrap().remove(key);
     }
+
+    /// List the Multiton keys of the `IModel` instances.
+    pub(crate) fn keys() -> Vec<String> {
+        INSTANCE_MAP.read().map(|map| map.keys().cloned().collect()).unwrap_or_default()
+    }
+
+    /// Look up an `IModel` instance without creating it.
+    ///
+    /// # Arguments
+    /// * `key` - The multiton key of the `IModel` instance.
+    pub(crate) fn instance(key: &str) -> Option<Arc<dyn IModel>> {
+        INSTANCE_MAP.read().ok().and_then(|map| map.get(key).cloned())
+    }
+
+    /// Save the data of a `Proxy` to the store, if it opts in to persistence.
+    ///
+    /// Data saved for a `Proxy` that has none is deleted.
+    ///
+    /// # Arguments
+    /// * `store` - The store to save to.
+    /// * `proxy` - The `Proxy` to save.
+    #[cfg(feature = "persistence")]
+    fn save(store: &dyn IStore, proxy: &mut dyn IProxy) -> Result<(), Error> {
+        let Some(proxy) = proxy.as_persistent() else { return Ok(()) };
+        let key = proxy.persistence_key();
+        match proxy.save_data()? {
+            Some(data) => store.save(&key, &data),
+            None => store.remove(&key),
+        }
+    }
+
+    /// Instantiate a lazily registered `Proxy` from its factory.
+    ///
+    /// The factory is called without holding the `IModel`'s locks, so it may retrieve other
+    /// `Proxy`s, and only once: concurrent retrievals of the same `Proxy` wait for it to be
+    /// instantiated and registered. If it cannot be registered, the next retrieval tries again.
+    ///
+    /// A `Proxy` retrieved again by the same thread while it is instantiated, by its factory, or
+    /// through dependencies that lead back to it, is reported as a cycle rather than waited for.
+    ///
+    /// # Arguments
+    /// * `proxy_name` - The name of the `Proxy` to instantiate.
+    ///
+    /// # Returns
+    /// The `Proxy` instance, `Error::NotFound` if no factory is registered with the given name or
+    /// it was removed while the `Proxy` was instantiated, `Error::MissingDependency` if a
+    /// dependency of the `Proxy` is not registered, `Error::DependencyCycle` if the `Proxy` is
+    /// already being instantiated by this thread, or `Error::LockPoisoned` if a lock is poisoned.
+    fn instantiate_proxy(&self, proxy_name: &str) -> Result<Arc<RwLock<dyn IProxy>>, Error> {
+        let lazy = self.proxy_factories.read()
+            .map_err(|_| Error::LockPoisoned { lock: "Model proxy factories" })?
+            .get(proxy_name).cloned();
+        let Some(lazy) = lazy else {
+            return self.proxy_map.read()
+                .map_err(|_| Error::LockPoisoned { lock: "Model proxy map" })?
+                .get(proxy_name).cloned()
+                .ok_or_else(|| Error::NotFound { kind: "Proxy", name: proxy_name.into() });
+        };
+
+        let _instantiating = Instantiating::enter(&self.key, proxy_name)?;
+        let mut instance = lazy.proxy.lock().map_err(|_| Error::LockPoisoned { lock: "Lazy proxy" })?;
+        if let Some(proxy) = instance.as_ref() {
+            return Ok(Arc::clone(proxy));
+        }
+        let proxy = (lazy.factory)();
+        self.activate_proxy(&proxy, true, || {
+            let mut factories = self.proxy_factories.write()
+                .map_err(|_| Error::LockPoisoned { lock: "Model proxy factories" })?;
+            if !factories.get(proxy_name).is_some_and(|pending| Arc::ptr_eq(pending, &lazy)) {
+                return Err(Error::NotFound { kind: "Proxy", name: proxy_name.into() });
+            }
+            factories.remove(proxy_name);
+            self.proxy_map.write()
+                .map_err(|_| Error::LockPoisoned { lock: "Model proxy map" })?
+                .insert(proxy_name.into(), Arc::clone(&proxy));
+            Ok(())
+        })?;
+        *instance = Some(Arc::clone(&proxy));
+        Ok(proxy)
+    }
+
+    /// Prepare a `Proxy`, publish it, then call its `on_register`.
+    ///
+    /// The `Proxy` is given its Multiton key, its persisted data if any, and the dependencies it
+    /// lists, which must all be registered. Its lock is held from before it is published until
+    /// `on_register` returns, so a concurrent retrieval cannot use it before it is ready.
+    ///
+    /// # Arguments
+    /// * `proxy` - The `Proxy` to prepare.
+    /// * `lenient` - Whether to go on with the `Proxy`'s own data if its persisted data cannot be
+    ///   restored, rather than fail.
+    /// * `publish` - A closure adding the `Proxy` to the proxy map.
+    ///
+    /// # Returns
+    /// The error of `publish`, `Error::MissingDependency` if a dependency is not registered,
+    /// `Error::Persistence` if the persisted data cannot be restored and `lenient` is `false`, or
+    /// `Error::LockPoisoned` if the `Proxy` lock is poisoned; in each case the `Proxy` is not
+    /// published and `on_register` is not called.
+    fn activate_proxy(&self, proxy: &Arc<RwLock<dyn IProxy>>, lenient: bool,
+                      publish: impl FnOnce() -> Result<(), Error>) -> Result<(), Error> {
+        let (name, names) = {
+            let proxy = proxy.read().map_err(|_| Error::LockPoisoned { lock: "Proxy" })?;
+            (proxy.name().to_string(), proxy.dependencies())
+        };
+        let mut dependencies = HashMap::with_capacity(names.len());
+        for dependency in names {
+            match self.try_retrieve_proxy(&dependency) {
+                Ok(proxy) => { dependencies.insert(dependency, proxy); }
+                Err(Error::NotFound { .. }) => return Err(Error::MissingDependency { proxy: name, dependency }),
+                Err(error) => return Err(error),
+            }
+        }
+
+        let mut guard = proxy.write().map_err(|_| Error::LockPoisoned { lock: "Proxy" })?;
+        guard.initialize_notifier(&self.key);
+        #[cfg(feature = "persistence")]
+        if let Some(store) = self.store()
+            && let Err(error) = Self::restore(store.as_ref(), &mut *guard)
+            && !lenient {
+            return Err(error);
+        }
+        #[cfg(not(feature = "persistence"))]
+        let _ = lenient;
+        guard.set_dependencies(dependencies);
+        publish()?;
+        guard.on_register();
+        Ok(())
+    }
+
+    /// Restore the data of a `Proxy` from the store, if it opts in to persistence.
+    ///
+    /// # Arguments
+    /// * `store` - The store to restore from.
+    /// * `proxy` - The `Proxy` to restore.
+    ///
+    /// # Returns
+    /// `true` if data was restored, otherwise `false`.
+    #[cfg(feature = "persistence")]
+    fn restore(store: &dyn IStore, proxy: &mut dyn IProxy) -> Result<bool, Error> {
+        let Some(proxy) = proxy.as_persistent() else { return Ok(false) };
+        match store.load(&proxy.persistence_key())? {
+            Some(data) => proxy.restore_data(data).map(|_| true),
+            None => Ok(false),
+        }
+    }
 }
 
 impl IModel for Model {
@@ -71,16 +241,68 @@ impl IModel for Model {
 
     /// Register a `Proxy` instance with the `IModel`.
     ///
+    /// A `Proxy` listing a dependency that is not registered is not registered either.
+    ///
     /// # Arguments
     /// * `proxy` - An object reference to be held by the `IModel`.
     fn register_proxy(&self, proxy: Arc<RwLock<dyn IProxy>>) {
-        self.proxy_map.write().ok()
-            .map(|mut map| {
-                let mut guard = proxy.write().unwrap();
-                map.insert(guard.name().into(), Arc::clone(&proxy));
-                guard.initialize_notifier(&self.key);
-                guard.on_register();
-            });
+        let Ok(name) = proxy.read().map(|proxy| proxy.name().to_string()) else { return };
+        let _ = self.activate_proxy(&proxy, true, || {
+            if let Ok(mut factories) = self.proxy_factories.write() {
+                factories.remove(&name);
+            }
+            self.proxy_map.write()
+                .map_err(|_| Error::LockPoisoned { lock: "Model proxy map" })?
+                .insert(name, Arc::clone(&proxy));
+            Ok(())
+        });
+    }
+
+    /// Register several `Proxy` instances with the `IModel`, in dependency order.
+    ///
+    /// Each `Proxy` is registered after the `Proxy`s it lists in `IProxy::dependencies`, so its
+    /// dependencies are resolved before its `on_register` is called. Dependencies may also be
+    /// registered already, lazily or not. Nothing is registered if a name is taken, a dependency
+    /// is missing or the dependencies form a cycle. If registering a `Proxy` fails nonetheless,
+    /// for instance because its persisted data cannot be restored, the `Proxy`s registered before
+    /// it are removed again, in reverse order.
+    ///
+    /// # Arguments
+    /// * `proxies` - The `Proxy` instances to register.
+    ///
+    /// # Returns
+    /// `Error::MissingDependency` if a dependency is neither in `proxies` nor registered,
+    /// `Error::DependencyCycle` if the dependencies form a cycle, `Error::AlreadyRegistered` if
+    /// two `Proxy`s share a name or a name is registered already, or the first error met
+    /// registering them, such as `Error::LockPoisoned` if a lock is poisoned.
+    fn register_proxies(&self, proxies: Vec<Arc<RwLock<dyn IProxy>>>) -> Result<(), Error> {
+        let mut nodes: Vec<(String, Vec<String>)> = Vec::with_capacity(proxies.len());
+        for proxy in &proxies {
+            let proxy = proxy.read().map_err(|_| Error::LockPoisoned { lock: "Proxy" })?;
+            if nodes.iter().any(|(name, _)| name == proxy.name()) || self.try_has_proxy(proxy.name())? {
+                return Err(Error::AlreadyRegistered { kind: "Proxy", name: proxy.name().into() });
+            }
+            nodes.push((proxy.name().into(), proxy.dependencies()));
+        }
+
+        for (name, dependencies) in &nodes {
+            for dependency in dependencies {
+                if !nodes.iter().any(|(name, _)| name == dependency) && !self.try_has_proxy(dependency)? {
+                    return Err(Error::MissingDependency { proxy: name.clone(), dependency: dependency.clone() });
+                }
+            }
+        }
+
+        let order = dependency_order(&nodes)?;
+        for (position, &index) in order.iter().enumerate() {
+            if let Err(error) = self.try_register_proxy(Arc::clone(&proxies[index])) {
+                for &registered in order[..position].iter().rev() {
+                    self.remove_proxy(&nodes[registered].0);
+                }
+                return Err(error);
+            }
+        }
+        Ok(())
     }
 
     /// Retrieve a `Proxy` instance from the `IModel`.
@@ -91,9 +313,7 @@ impl IModel for Model {
     /// # Returns
     /// The `Proxy` instance previously registered with the given `proxy_name`.
     fn retrieve_proxy(&self, proxy_name: &str) -> Option<Arc<RwLock<dyn IProxy>>> {
-        self.proxy_map.read().ok()
-            .map(|map| map.get(proxy_name).cloned())
-            .unwrap()
+        self.try_retrieve_proxy(proxy_name).ok()
     }
 
     /// Check if a `Proxy` is registered with the `IModel`.
@@ -104,9 +324,7 @@ impl IModel for Model {
     /// # Returns
     /// Returns `true` if a `Proxy` is currently registered with the given `proxy_name`, otherwise `false`.
     fn has_proxy(&self, proxy_name: &str) -> bool {
-        self.proxy_map.read().ok()
-            .map(|map| map.contains_key(proxy_name))
-            .unwrap()
+        self.try_has_proxy(proxy_name).unwrap_or(false)
     }
 
     /// Remove a `Proxy` instance from the `IModel`.
@@ -117,11 +335,307 @@ impl IModel for Model {
     /// # Returns
     /// The `Proxy` that was removed from the `IModel`.
     fn remove_proxy(&self, proxy_name: &str) -> Option<Arc<RwLock<dyn IProxy>>> {
-        self.proxy_map.write().ok()
-            .and_then(|mut map| map.remove(proxy_name))
-            .map(|proxy| {
-                proxy.write().unwrap().on_remove();
-                proxy
-            })
+        self.try_remove_proxy(proxy_name).ok().flatten()
+    }
+
+    /// List the names of the `Proxy` instances registered with the `IModel`.
+    ///
+    /// # Returns
+    /// The names of the registered `Proxy` instances, sorted.
+    fn proxy_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.proxy_map.read()
+            .map(|map| map.keys().cloned().collect())
+            .unwrap_or_default();
+        if let Ok(factories) = self.proxy_factories.read() {
+            names.extend(factories.keys().cloned());
+        }
+        names.sort();
+        names.dedup();
+        names
+    }
+
+    /// Register a `Proxy` lazily, by name and factory.
+    ///
+    /// The factory is not called until the `Proxy` is first retrieved. Registering a factory
+    /// replaces any factory not yet called under the same name, while a `Proxy` instance already
+    /// registered under the name is kept until it is removed.
+    ///
+    /// # Arguments
+    /// * `proxy_name` - The name the `Proxy` will be registered with.
+    /// * `factory` - A closure that creates the `Proxy` instance.
+    fn register_proxy_factory(&self, proxy_name: &str, factory: ProxyFactory) {
+        if let Ok(mut factories) = self.proxy_factories.write() {
+            factories.insert(proxy_name.into(), Arc::new(LazyProxy { factory, proxy: Mutex::new(None) }));
+        }
+    }
+
+    /// Register a `Proxy` instance with the `IModel`, failing if the name is taken.
+    ///
+    /// # Arguments
+    /// * `proxy` - An object reference to be held by the `IModel`.
+    ///
+    /// # Returns
+    /// `Error::AlreadyRegistered` if a `Proxy` is registered with the same name,
+    /// `Error::Persistence` if its persisted data cannot be restored, in which case it is not
+    /// registered, or `Error::LockPoisoned` if a lock is poisoned.
+    fn try_register_proxy(&self, proxy: Arc<RwLock<dyn IProxy>>) -> Result<(), Error> {
+        let name = proxy.read().map_err(|_| Error::LockPoisoned { lock: "Proxy" })?.name().to_string();
+        if self.proxy_factories.read()
+            .map_err(|_| Error::LockPoisoned { lock: "Model proxy factories" })?
+            .contains_key(&name) {
+            return Err(Error::AlreadyRegistered { kind: "Proxy", name });
+        }
+        let dependencies = proxy.read().map_err(|_| Error::LockPoisoned { lock: "Proxy" })?.dependencies();
+        for dependency in dependencies {
+            if dependency == name {
+                return Err(Error::DependencyCycle { cycle: vec![name.clone(), name] });
+            }
+            if !self.try_has_proxy(&dependency)? {
+                return Err(Error::MissingDependency { proxy: name, dependency });
+            }
+        }
+        self.activate_proxy(&proxy, false, || {
+            if self.proxy_factories.read()
+                .map_err(|_| Error::LockPoisoned { lock: "Model proxy factories" })?
+                .contains_key(&name) {
+                return Err(Error::AlreadyRegistered { kind: "Proxy", name: name.clone() });
+            }
+            let mut map = self.proxy_map.write().map_err(|_| Error::LockPoisoned { lock: "Model proxy map" })?;
+            if map.contains_key(&name) {
+                return Err(Error::AlreadyRegistered { kind: "Proxy", name: name.clone() });
+            }
+            map.insert(name.clone(), Arc::clone(&proxy));
+            Ok(())
+        })
+    }
+
+    /// Retrieve a `Proxy` instance from the `IModel`, failing if it is not registered.
+    ///
+    /// A `Proxy` registered by factory is instantiated on its first retrieval.
+    ///
+    /// # Arguments
+    /// * `proxy_name` - The name of the `Proxy` instance to retrieve.
+    ///
+    /// # Returns
+    /// The `Proxy` instance, `Error::NotFound` if none is registered with the given name, or
+    /// `Error::LockPoisoned` if a lock is poisoned.
+    fn try_retrieve_proxy(&self, proxy_name: &str) -> Result<Arc<RwLock<dyn IProxy>>, Error> {
+        let proxy = self.proxy_map.read()
+            .map_err(|_| Error::LockPoisoned { lock: "Model proxy map" })?
+            .get(proxy_name).cloned();
+        match proxy {
+            Some(proxy) => Ok(proxy),
+            None => self.instantiate_proxy(proxy_name),
+        }
+    }
+
+    /// Check if a `Proxy` is registered with the `IModel`.
+    ///
+    /// # Arguments
+    /// * `proxy_name` - The name of the `Proxy` instance you're looking for.
+    ///
+    /// # Returns
+    /// Whether a `Proxy` is registered with the given name, instantiated or not, or
+    /// `Error::LockPoisoned` if a lock is poisoned.
+    fn try_has_proxy(&self, proxy_name: &str) -> Result<bool, Error> {
+        if self.proxy_map.read()
+            .map_err(|_| Error::LockPoisoned { lock: "Model proxy map" })?
+            .contains_key(proxy_name) {
+            return Ok(true);
+        }
+        self.proxy_factories.read()
+            .map(|factories| factories.contains_key(proxy_name))
+            .map_err(|_| Error::LockPoisoned { lock: "Model proxy factories" })
+    }
+
+    /// Remove a `Proxy` instance from the `IModel`, failing if it is not registered.
+    ///
+    /// The factory of a `Proxy` never retrieved is dropped without being called. The data of a
+    /// persistent `Proxy` is saved before it is removed; if it cannot be saved, the `Proxy` is
+    /// left registered.
+    ///
+    /// # Arguments
+    /// * `proxy_name` - Name of the `Proxy` instance to be removed.
+    ///
+    /// # Returns
+    /// The removed `Proxy`, `None` if it was registered by factory and never instantiated,
+    /// `Error::NotFound` if none is registered with the given name, `Error::Persistence` if its
+    /// data cannot be saved, or `Error::LockPoisoned` if a lock is poisoned.
+    fn try_remove_proxy(&self, proxy_name: &str) -> Result<Option<Arc<RwLock<dyn IProxy>>>, Error> {
+        let lazy = self.proxy_factories.write()
+            .map_err(|_| Error::LockPoisoned { lock: "Model proxy factories" })?
+            .remove(proxy_name);
+        let proxy = self.proxy_map.read()
+            .map_err(|_| Error::LockPoisoned { lock: "Model proxy map" })?
+            .get(proxy_name).cloned();
+        let Some(proxy) = proxy else {
+            return match lazy {
+                Some(_) => Ok(None),
+                None => Err(Error::NotFound { kind: "Proxy", name: proxy_name.into() }),
+            };
+        };
+
+        let mut guard = proxy.write().map_err(|_| Error::LockPoisoned { lock: "Proxy" })?;
+        #[cfg(feature = "persistence")]
+        if let Some(store) = self.store() {
+            Self::save(store.as_ref(), &mut *guard)?;
+        }
+        {
+            let mut map = self.proxy_map.write().map_err(|_| Error::LockPoisoned { lock: "Model proxy map" })?;
+            if !map.get(proxy_name).is_some_and(|registered| Arc::ptr_eq(registered, &proxy)) {
+                return Err(Error::NotFound { kind: "Proxy", name: proxy_name.into() });
+            }
+            map.remove(proxy_name);
+        }
+        guard.on_remove();
+        drop(guard);
+        Ok(Some(proxy))
+    }
+
+    /// Set the store the data of `IPersistentProxy` instances is kept in.
+    ///
+    /// # Arguments
+    /// * `store` - The store, or `None` to stop persisting data.
+    #[cfg(feature = "persistence")]
+    fn set_store(&self, store: Option<Arc<dyn IStore>>) {
+        if let Ok(mut current) = self.store.write() {
+            *current = store;
+        }
+    }
+
+    /// Get the store the data of `IPersistentProxy` instances is kept in.
+    ///
+    /// # Returns
+    /// The store, if one is set.
+    #[cfg(feature = "persistence")]
+    fn store(&self) -> Option<Arc<dyn IStore>> {
+        self.store.read().ok().and_then(|store| store.clone())
+    }
+
+    /// Save the data of every registered `IPersistentProxy` to the store.
+    ///
+    /// # Returns
+    /// The first error met, if any; the remaining proxies are still saved.
+    #[cfg(feature = "persistence")]
+    fn save_proxies(&self) -> Result<(), Error> {
+        let Some(store) = self.store() else { return Ok(()) };
+        let proxies: Vec<Arc<RwLock<dyn IProxy>>> = self.proxy_map.read()
+            .map_err(|_| Error::LockPoisoned { lock: "Model proxy map" })?
+            .values().cloned().collect();
+
+        let mut result = Ok(());
+        for proxy in proxies {
+            let saved = proxy.write()
+                .map_err(|_| Error::LockPoisoned { lock: "Proxy" })
+                .and_then(|mut proxy| Self::save(store.as_ref(), &mut *proxy));
+            if result.is_ok() {
+                result = saved;
+            }
+        }
+        result
+    }
+
+    /// Restore the data of a registered `IPersistentProxy` from the store.
+    ///
+    /// # Arguments
+    /// * `proxy_name` - The name of the `Proxy` instance to restore.
+    ///
+    /// # Returns
+    /// `true` if data was restored, `false` if no store is set, the `Proxy` does not opt in to
+    /// persistence or nothing is saved for it, or an `Error` if it is not registered or its data
+    /// cannot be loaded.
+    #[cfg(feature = "persistence")]
+    fn restore_proxy(&self, proxy_name: &str) -> Result<bool, Error> {
+        let proxy = self.try_retrieve_proxy(proxy_name)?;
+        let Some(store) = self.store() else { return Ok(false) };
+        let mut proxy = proxy.write().map_err(|_| Error::LockPoisoned { lock: "Proxy" })?;
+        Self::restore(store.as_ref(), &mut *proxy)
+    }
+}
+
+/// A `Proxy` registered by factory, instantiated once on its first retrieval.
+struct LazyProxy {
+    /// The factory creating the `Proxy` instance.
+    factory: ProxyFactory,
+    /// The `Proxy` instance, once instantiated and registered.
+    proxy: Mutex<Option<Arc<RwLock<dyn IProxy>>>>,
+}
+
+/// Marks a lazily registered `Proxy` as being instantiated by the current thread, until dropped.
+struct Instantiating;
+
+impl Instantiating {
+    /// Mark a `Proxy` as being instantiated by the current thread.
+    ///
+    /// # Arguments
+    /// * `key` - The Multiton key of the `IModel`.
+    /// * `proxy_name` - The name of the `Proxy`.
+    ///
+    /// # Returns
+    /// The mark, or `Error::DependencyCycle` if the `Proxy` is already being instantiated by the
+    /// current thread.
+    fn enter(key: &str, proxy_name: &str) -> Result<Self, Error> {
+        INSTANTIATING.with_borrow_mut(|stack| {
+            if let Some(start) = stack.iter().position(|(k, name)| k == key && name == proxy_name) {
+                let cycle = stack[start..].iter()
+                    .filter(|(k, _)| k == key)
+                    .map(|(_, name)| name.clone())
+                    .chain([proxy_name.to_string()])
+                    .collect();
+                return Err(Error::DependencyCycle { cycle });
+            }
+            stack.push((key.into(), proxy_name.into()));
+            Ok(Self)
+        })
+    }
+}
+
+impl Drop for Instantiating {
+    /// Unmark the `Proxy` marked last.
+    fn drop(&mut self) {
+        INSTANTIATING.with_borrow_mut(|stack| stack.pop());
+    }
+}
+
+/// Order `Proxy`s so that each comes after the `Proxy`s it depends on.
+///
+/// Dependencies outside of `nodes` are ignored, as they are registered already.
+///
+/// # Arguments
+/// * `nodes` - The name and dependency names of each `Proxy`.
+///
+/// # Returns
+/// The indices of the `Proxy`s in dependency order, or `Error::DependencyCycle` if the
+/// dependencies form a cycle.
+fn dependency_order(nodes: &[(String, Vec<String>)]) -> Result<Vec<usize>, Error> {
+    fn visit(index: usize, nodes: &[(String, Vec<String>)], done: &mut [bool], path: &mut Vec<usize>,
+             order: &mut Vec<usize>) -> Result<(), Error> {
+        if done[index] {
+            return Ok(());
+        }
+        if let Some(start) = path.iter().position(|&visiting| visiting == index) {
+            let cycle = path[start..].iter().chain([&index])
+                .map(|&index| nodes[index].0.clone())
+                .collect();
+            return Err(Error::DependencyCycle { cycle });
+        }
+
+        path.push(index);
+        for dependency in &nodes[index].1 {
+            if let Some(dependency) = nodes.iter().position(|(name, _)| name == dependency) {
+                visit(dependency, nodes, done, path, order)?;
+            }
+        }
+        path.pop();
+        done[index] = true;
+        order.push(index);
+        Ok(())
+    }
+
+    let mut done = vec![false; nodes.len()];
+    let mut order = Vec::with_capacity(nodes.len());
+    for index in 0..nodes.len() {
+        visit(index, nodes, &mut done, &mut vec![], &mut order)?;
     }
+    Ok(order)
 }
@@ -0,0 +1,65 @@
+use std::collections::BTreeMap;
+use crate::core::{Controller, Model, View};
+use crate::patterns::Facade;
+
+/// A snapshot of the actors registered with a Core.
+///
+/// See `Registry::core_info`
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CoreInfo {
+    /// The Multiton key of the Core.
+    pub key: String,
+    /// The names of the `IProxy` instances registered with the Core's `IModel`, sorted.
+    pub proxy_names: Vec<String>,
+    /// The names of the `IMediator` instances registered with the Core's `IView`, sorted.
+    pub mediator_names: Vec<String>,
+    /// The number of `ICommand`s mapped to each `INotification` name by the Core's `IController`.
+    pub command_counts: BTreeMap<String, usize>,
+    /// The number of `IObserver`s registered for each `INotification` name with the Core's `IView`.
+    pub observer_counts: BTreeMap<String, usize>,
+}
+
+/// Introspection of the live Cores, for diagnostics and tests.
+///
+/// A Core is live while any of its `IFacade`, `IModel`, `IView` or `IController` Multiton
+/// instances exists. Looking a Core up through the `Registry` never creates any of them.
+///
+/// See `Facade`, `Model`, `View`, `Controller`
+pub struct Registry;
+
+impl Registry {
+    /// List the Multiton keys of the live Cores.
+    ///
+    /// # Returns
+    /// The keys of the live Cores, sorted.
+    pub fn core_keys() -> Vec<String> {
+        let mut keys: Vec<String> = [Facade::keys(), Model::keys(), View::keys(), Controller::keys()].concat();
+        keys.sort();
+        keys.dedup();
+        keys
+    }
+
+    /// Take a snapshot of the actors registered with a Core.
+    ///
+    /// # Arguments
+    /// * `key` - The Multiton key of the Core.
+    ///
+    /// # Returns
+    /// A `CoreInfo` describing the Core, or `None` if no Core is live with the given `key`.
+    pub fn core_info(key: &str) -> Option<CoreInfo> {
+        let model = Model::instance(key);
+        let view = View::instance(key);
+        let controller = Controller::instance(key);
+        if model.is_none() && view.is_none() && controller.is_none() && !Facade::has_core(key) {
+            return None;
+        }
+
+        Some(CoreInfo {
+            key: key.into(),
+            proxy_names: model.map(|model| model.proxy_names()).unwrap_or_default(),
+            mediator_names: view.as_ref().map(|view| view.mediator_names()).unwrap_or_default(),
+            command_counts: controller.map(|controller| controller.command_counts()).unwrap_or_default(),
+            observer_counts: view.map(|view| view.observer_counts()).unwrap_or_default(),
+        })
+    }
+}
@@ -1,8 +1,15 @@
 use std::any::Any;
-use std::collections::HashMap;
-use std::sync::{Arc, LazyLock, RwLock};
-use crate::interfaces::{IMediator, INotification, IObserver, IView};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::sync::{Arc, LazyLock, Mutex, RwLock};
+use std::thread;
+use std::time::Instant;
+use crate::interfaces::{DispatchMode, IInterceptor, IMediator, INotification, IObserver, IView};
+use crate::Error;
 use crate::patterns::Observer;
+#[cfg(feature = "async")]
+use crate::interfaces::BoxFuture;
+#[cfg(feature = "thread-pool")]
+use crate::core::worker_pool::WorkerPool;
 
 static INSTANCE_MAP: LazyLock<RwLock<HashMap<String, Arc<dyn IView>>>> = LazyLock::new(|| Default::default());
 
@@ -21,10 +28,75 @@ static INSTANCE_MAP: LazyLock<RwLock<HashMap<String, Arc<dyn IView>>>> = LazyLoc
 pub struct View {
     /// The Multiton key for this Core
     key: String,
-    /// Mapping of `Notification` names to IObserver lists
-    observer_map: RwLock<HashMap<String, Vec<Arc<dyn IObserver>>>>,
+    /// Mapping of `Notification` names to IObserver lists, in notification order
+    observer_map: RwLock<HashMap<String, Vec<PrioritizedObserver>>>,
     /// Mapping of Mediator names to IMediator instances
-    mediator_map: RwLock<HashMap<String, Arc<RwLock<dyn IMediator>>>>
+    mediator_map: RwLock<HashMap<String, Arc<RwLock<dyn IMediator>>>>,
+    /// How `Notification`s sent during the delivery of another are delivered
+    dispatch_mode: RwLock<DispatchMode>,
+    /// `Notification`s waiting for delivery in `DispatchMode::Deferred`
+    dispatch_queue: Mutex<DispatchQueue>,
+    /// The `IInterceptor`s called around the delivery of each `Notification`
+    interceptors: RwLock<Vec<Arc<dyn IInterceptor>>>,
+    /// The workers notifying `IObserver`s in `DispatchMode::Background`
+    #[cfg(feature = "thread-pool")]
+    worker_pool: RwLock<Option<Arc<WorkerPool>>>,
+}
+
+/// The `Notification`s waiting for delivery in `DispatchMode::Deferred`.
+#[derive(Default)]
+struct DispatchQueue {
+    /// The `Notification`s sent but not yet delivered, in the order they were sent
+    pending: VecDeque<Arc<dyn INotification>>,
+    /// Whether a `Notification` is being delivered
+    dispatching: bool,
+}
+
+/// Ends a deferred dispatch if an `IObserver` panics, so later `Notification`s are still delivered.
+struct DispatchGuard<'a>(&'a Mutex<DispatchQueue>);
+
+impl Drop for DispatchGuard<'_> {
+    fn drop(&mut self) {
+        if thread::panicking() && let Ok(mut queue) = self.0.lock() {
+            queue.dispatching = false;
+        }
+    }
+}
+
+/// Calls the `IInterceptor`s' `after_notify` once the last background delivery of a
+/// `Notification` has ended.
+///
+/// Each job delivering the `Notification` holds a reference, so the call happens when the last
+/// one is dropped, on whichever thread ran it, even if an `IObserver` panicked.
+#[cfg(feature = "thread-pool")]
+struct AfterNotify {
+    /// The Multiton key of the Core
+    key: String,
+    /// The `Notification` delivered
+    notification: Arc<dyn INotification>,
+    /// The `IInterceptor`s to call
+    interceptors: Vec<Arc<dyn IInterceptor>>,
+    /// The number of `IObserver`s notified
+    count: usize,
+    /// When the delivery started
+    start: Instant,
+}
+
+#[cfg(feature = "thread-pool")]
+impl Drop for AfterNotify {
+    fn drop(&mut self) {
+        for interceptor in &self.interceptors {
+            interceptor.after_notify(&self.key, &self.notification, self.count, self.start.elapsed());
+        }
+    }
+}
+
+/// An `IObserver` registered with the `View`.
+struct PrioritizedObserver {
+    /// The registered `IObserver`
+    observer: Arc<dyn IObserver>,
+    /// The notification priority of the `IObserver`, highest first
+    priority: i32,
 }
 
 impl View {
@@ -40,6 +112,11 @@ impl View {
             key: key.into(),
             observer_map: RwLock::new(HashMap::new()),
             mediator_map: RwLock::new(HashMap::new()),
+            dispatch_mode: RwLock::new(DispatchMode::Immediate),
+            dispatch_queue: Mutex::new(DispatchQueue::default()),
+            interceptors: RwLock::new(Vec::new()),
+            #[cfg(feature = "thread-pool")]
+            worker_pool: RwLock::new(None),
         }
     }
 
@@ -59,11 +136,117 @@ impl View {
 
     /// Remove an `IView` Multiton instance.
     ///
+    /// Every `Mediator` listed by `IView::mediator_names` is removed first, so its `on_remove` is
+    /// called even if the core has no `IFacade`.
+    ///
     /// # Arguments
     /// * `key` - The Multiton key of the `IView` instance to remove.
     pub fn remove_view(key: &str) {
+        if let Some(view) = Self::instance(key) {
+            for mediator_name in view.mediator_names() {
+                view.remove_mediator(&mediator_name);
+            }
+        }
         INSTANCE_MAP.write().unwrap().remove(key);
     }
+
+    /// List the Multiton keys of the `IView` instances.
+    pub(crate) fn keys() -> Vec<String> {
+        INSTANCE_MAP.read().map(|map| map.keys().cloned().collect()).unwrap_or_default()
+    }
+
+    /// Look up an `IView` instance without creating it.
+    ///
+    /// # Arguments
+    /// * `key` - The Multiton key of the `IView` instance.
+    pub(crate) fn instance(key: &str) -> Option<Arc<dyn IView>> {
+        INSTANCE_MAP.read().ok().and_then(|map| map.get(key).cloned())
+    }
+
+    /// Copy the `IObserver` list for a given `Notification` name, in notification order.
+    ///
+    /// # Arguments
+    /// * `notification_name` - The name of the `Notification`.
+    ///
+    /// # Returns
+    /// The `IObserver`s to notify of the `Notification`.
+    fn observers(&self, notification_name: &str) -> Vec<Arc<dyn IObserver>> {
+        self.observer_map.read().ok()
+            .and_then(|map| map.get(notification_name)
+                .map(|observers| observers.iter().map(|entry| Arc::clone(&entry.observer)).collect()))
+            .unwrap_or_default()
+    }
+
+    /// Copy the `IInterceptor` list.
+    ///
+    /// # Returns
+    /// The `IInterceptor`s to call around the delivery of a `Notification`.
+    fn interceptors(&self) -> Vec<Arc<dyn IInterceptor>> {
+        self.interceptors.read().map(|interceptors| interceptors.clone()).unwrap_or_default()
+    }
+
+    /// Notify the `IObserver`s of a `Notification` in turn, until one of them stops its propagation.
+    ///
+    /// # Arguments
+    /// * `notification` - The `Notification` to notify `IObserver`s of.
+    fn deliver(&self, notification: &Arc<dyn INotification>) {
+        let observers = self.observers(notification.name());
+        let interceptors = self.interceptors();
+        for interceptor in &interceptors {
+            interceptor.before_notify(&self.key, notification, observers.len());
+        }
+
+        let start = Instant::now();
+        let count = observers.len();
+        for observer in observers {
+            if notification.is_propagation_stopped() {
+                break;
+            }
+            observer.notify_observer(notification);
+        }
+
+        for interceptor in &interceptors {
+            interceptor.after_notify(&self.key, notification, count, start.elapsed());
+        }
+    }
+
+    /// Hand each `IObserver` of a `Notification` to the worker pool, in notification order.
+    ///
+    /// The `IInterceptor`s' `after_notify` is called once every `IObserver` has been notified,
+    /// possibly on a worker thread, with the time elapsed since the `Notification` was sent.
+    ///
+    /// # Arguments
+    /// * `notification` - The `Notification` to notify `IObserver`s of.
+    #[cfg(feature = "thread-pool")]
+    fn deliver_in_background(&self, notification: &Arc<dyn INotification>) {
+        let Some(pool) = self.worker_pool.read().ok().and_then(|pool| pool.clone()) else {
+            return self.deliver(notification);
+        };
+
+        let observers = self.observers(notification.name());
+        let interceptors = self.interceptors();
+        for interceptor in &interceptors {
+            interceptor.before_notify(&self.key, notification, observers.len());
+        }
+
+        let after_notify = Arc::new(AfterNotify {
+            key: self.key.clone(),
+            notification: Arc::clone(notification),
+            interceptors,
+            count: observers.len(),
+            start: Instant::now(),
+        });
+        for observer in observers {
+            let key = Arc::as_ptr(&observer) as *const () as usize;
+            let after_notify = Arc::clone(&after_notify);
+            pool.execute(key, Box::new(move || {
+                let notification = &after_notify.notification;
+                if !notification.is_propagation_stopped() {
+                    observer.notify_observer(notification);
+                }
+            }));
+        }
+    }
 }
 
 impl IView for View {
@@ -81,12 +264,22 @@ impl IView for View {
     /// * `notification_name` - The name of the `Notification` to notify this `IObserver` of.
     /// * `observer` - The `IObserver` to register.
     fn register_observer(&self, notification_name: &str, observer: Arc<dyn IObserver>) {
-        self.observer_map.write().ok()
-            .map(|mut map| {
-                map.entry(notification_name.into())
-                    .or_default()
-                    .push(observer);
-            });
+        self.register_observer_with_priority(notification_name, observer, 0);
+    }
+
+    /// Register an `IObserver` to be notified of `Notification`s with a given name, ahead of
+    /// `IObserver`s registered with a lower priority.
+    ///
+    /// # Arguments
+    /// * `notification_name` - The name of the `Notification` to notify this `IObserver` of.
+    /// * `observer` - The `IObserver` to register.
+    /// * `priority` - The notification priority of the `IObserver`, highest first.
+    fn register_observer_with_priority(&self, notification_name: &str, observer: Arc<dyn IObserver>, priority: i32) {
+        if let Ok(mut map) = self.observer_map.write() {
+            let observers = map.entry(notification_name.into()).or_default();
+            let index = observers.partition_point(|entry| entry.priority >= priority);
+            observers.insert(index, PrioritizedObserver { observer, priority });
+        }
     }
 
     /// Remove an `IObserver` from the list for a given `Notification` name.
@@ -95,33 +288,158 @@ impl IView for View {
     /// * `notification_name` - Which `IObserver` list to remove from.
     /// * `context` - Remove `IObserver`s with this object as the notify context.
     fn remove_observer(&self, notification_name: &str, context: Arc<dyn Any + Send + Sync>) {
-        self.observer_map.write().ok()
-            .and_then(|mut map| map.get_mut(notification_name).cloned())
-            .map(|mut observers| {
-                observers.retain(|observer| !observer.compare_notify_context(&context));
-
-                if observers.is_empty() {
-                    self.observer_map.write().ok()
-                        .map(|mut map| map.remove(notification_name));
-                }
-            });
+        if let Ok(mut map) = self.observer_map.write() && let Some(observers) = map.get_mut(notification_name) {
+            observers.retain(|entry| !entry.observer.compare_notify_context(&context));
+
+            if observers.is_empty() {
+                map.remove(notification_name);
+            }
+        }
+    }
+
+    /// Count the `IObserver`s registered for each `Notification` name.
+    ///
+    /// # Returns
+    /// The number of `IObserver`s registered for each `Notification` name.
+    fn observer_counts(&self) -> BTreeMap<String, usize> {
+        self.observer_map.read()
+            .map(|map| map.iter().map(|(name, observers)| (name.clone(), observers.len())).collect())
+            .unwrap_or_default()
     }
 
     /// Notify the `IObserver`s for a particular `Notification`.
     ///
     /// All previously attached `IObserver`s for this `Notification`'s list are notified and are
-    /// passed a reference to the `Notification` in the order in which they were registered.
+    /// passed a reference to the `Notification` in priority order, then in the order in which
+    /// they were registered, until one of them stops the `Notification`'s propagation.
+    ///
+    /// In `DispatchMode::Background`, each `IObserver` is notified on a worker thread, and this
+    /// method returns without waiting for them.
+    ///
+    /// In `DispatchMode::Deferred`, a `Notification` sent while another is being delivered, from
+    /// an `IObserver` or from another thread, is queued and delivered by the thread delivering
+    /// the current `Notification` once it has been handled. An `IMediator` may therefore send
+    /// `Notification`s it is itself interested in without deadlocking on its own lock.
     ///
     /// # Arguments
     /// * `notification` - The `Notification` to notify `IObserver`s of.
     fn notify_observers(&self, notification: &Arc<dyn INotification>) {
-        self.observer_map.read().ok()
-            .and_then(|map| map.get(notification.name()).cloned())
-            .map(|observers| {
-                observers.iter().for_each(|observer| {
-                    observer.notify_observer(notification);
-                });
+        match self.dispatch_mode() {
+            DispatchMode::Immediate => return self.deliver(notification),
+            DispatchMode::Deferred => {}
+            #[cfg(feature = "thread-pool")]
+            DispatchMode::Background { .. } => return self.deliver_in_background(notification),
+        }
+
+        {
+            let Ok(mut queue) = self.dispatch_queue.lock() else { return };
+            queue.pending.push_back(Arc::clone(notification));
+            if queue.dispatching {
+                return;
+            }
+            queue.dispatching = true;
+        }
+
+        let _guard = DispatchGuard(&self.dispatch_queue);
+        loop {
+            let next = self.dispatch_queue.lock().ok().and_then(|mut queue| {
+                let next = queue.pending.pop_front();
+                queue.dispatching = next.is_some();
+                next
             });
+            match next {
+                Some(notification) => self.deliver(&notification),
+                None => break,
+            }
+        }
+    }
+
+    /// Get the `DispatchMode` of the `View`.
+    ///
+    /// # Returns
+    /// How `Notification`s sent during the delivery of another are delivered.
+    fn dispatch_mode(&self) -> DispatchMode {
+        self.dispatch_mode.read().map(|mode| *mode).unwrap_or_default()
+    }
+
+    /// Add an `IInterceptor` to the `View`.
+    ///
+    /// `IInterceptor`s are called in the order in which they were added.
+    ///
+    /// # Arguments
+    /// * `interceptor` - The `IInterceptor` to call around the delivery of each `Notification`.
+    fn add_interceptor(&self, interceptor: Arc<dyn IInterceptor>) {
+        if let Ok(mut interceptors) = self.interceptors.write() {
+            interceptors.push(interceptor);
+        }
+    }
+
+    /// Remove an `IInterceptor` from the `View`.
+    ///
+    /// # Arguments
+    /// * `interceptor` - The `IInterceptor` previously added.
+    fn remove_interceptor(&self, interceptor: &Arc<dyn IInterceptor>) {
+        if let Ok(mut interceptors) = self.interceptors.write() {
+            interceptors.retain(|added| !Arc::ptr_eq(added, interceptor));
+        }
+    }
+
+    /// Set the `DispatchMode` of the `View`.
+    ///
+    /// # Arguments
+    /// * `mode` - How `Notification`s sent during the delivery of another are delivered.
+    fn set_dispatch_mode(&self, mode: DispatchMode) {
+        #[cfg(feature = "thread-pool")]
+        if let Ok(mut pool) = self.worker_pool.write() {
+            *pool = match mode {
+                DispatchMode::Background { workers, serialize } => Some(Arc::new(WorkerPool::new(workers, serialize))),
+                _ => None,
+            };
+        }
+
+        if let Ok(mut guard) = self.dispatch_mode.write() {
+            *guard = mode;
+        }
+    }
+
+    /// Notify the `IObserver`s for a particular `Notification`, awaiting each one in turn.
+    ///
+    /// The `IObserver` list is copied before the first `IObserver` is notified, so no lock is
+    /// held across an await point, and `IObserver`s may register or remove `IObserver`s while
+    /// being notified. No further `IObserver`s are notified once one of them stops the
+    /// `Notification`'s propagation. The `Notification` is delivered immediately, regardless of
+    /// the `DispatchMode`.
+    ///
+    /// # Arguments
+    /// * `notification` - The `Notification` to notify `IObserver`s of.
+    ///
+    /// # Returns
+    /// A future completing when every `IObserver` has handled the `Notification`.
+    #[cfg(feature = "async")]
+    fn notify_observers_async(&self, notification: &Arc<dyn INotification>) -> BoxFuture {
+        let observers = self.observers(notification.name());
+        let interceptors = self.interceptors();
+        let key = self.key.clone();
+        let notification = Arc::clone(notification);
+
+        Box::pin(async move {
+            for interceptor in &interceptors {
+                interceptor.before_notify(&key, &notification, observers.len());
+            }
+
+            let start = Instant::now();
+            let count = observers.len();
+            for observer in observers {
+                if notification.is_propagation_stopped() {
+                    break;
+                }
+                observer.notify_observer_async(&notification).await;
+            }
+
+            for interceptor in &interceptors {
+                interceptor.after_notify(&key, &notification, count, start.elapsed());
+            }
+        })
     }
 
     /// Register a `Mediator` instance with the `IView`.
@@ -137,43 +455,107 @@ impl IView for View {
     /// # Arguments
     /// * `mediator` - A reference to the `Mediator` instance.
     fn register_mediator(&self, mediator: Arc<RwLock<dyn IMediator>>) {
-        {
-            let name = mediator.read().unwrap().name().to_string();
-            let mut map = self.mediator_map.write().unwrap();
-            if map.contains_key(&name) { return }
-            map.insert(name, Arc::clone(&mediator));
-        }
+        let _ = self.try_register_mediator(mediator);
+    }
+
+    /// Retrieve a `Mediator` from the `IView`.
+    ///
+    /// # Arguments
+    /// * `mediator_name` - The name of the `Mediator` instance to retrieve.
+    ///
+    /// # Returns
+    /// The `Mediator` instance previously registered in this core with the given `mediator_name`.
+    fn retrieve_mediator(&self, mediator_name: &str) -> Option<Arc<RwLock<dyn IMediator>>> {
+        self.try_retrieve_mediator(mediator_name).ok()
+    }
+
+    /// Check if a `Mediator` is registered with the `IView`.
+    ///
+    /// # Arguments
+    /// * `mediator_name` - The name of the `Mediator` you're looking for.
+    ///
+    /// # Returns
+    /// `true` if a `Mediator` is registered in this core with the given `mediator_name`, otherwise `false`.
+    fn has_mediator(&self, mediator_name: &str) -> bool {
+        self.try_has_mediator(mediator_name).unwrap_or(false)
+    }
+
+    /// Remove a `Mediator` from the `IView`.
+    ///
+    /// # Arguments
+    /// * `mediator_name` - Name of the `Mediator` instance to be removed.
+    ///
+    /// # Returns
+    /// The `Mediator` that was removed from this core's `IView`.
+    fn remove_mediator(&self, mediator_name: &str) -> Option<Arc<RwLock<dyn IMediator>>> {
+        self.try_remove_mediator(mediator_name).ok()
+    }
+
+    /// List the names of the `Mediator` instances registered with the `IView`.
+    ///
+    /// # Returns
+    /// The names of the registered `Mediator` instances, sorted.
+    fn mediator_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.mediator_map.read()
+            .map(|map| map.keys().cloned().collect())
+            .unwrap_or_default();
+        names.sort();
+        names
+    }
+
+    /// Register a `Mediator` instance with the `IView`, failing if the name is taken.
+    ///
+    /// # Arguments
+    /// * `mediator` - A reference to the `Mediator` instance.
+    ///
+    /// # Returns
+    /// `Error::AlreadyRegistered` if a `Mediator` is registered with the same name, or
+    /// `Error::LockPoisoned` if a lock is poisoned.
+    fn try_register_mediator(&self, mediator: Arc<RwLock<dyn IMediator>>) -> Result<(), Error> {
+        let (interests, priority) = {
+            let guard = mediator.read().map_err(|_| Error::LockPoisoned { lock: "Mediator" })?;
+            let mut map = self.mediator_map.write().map_err(|_| Error::LockPoisoned { lock: "View mediator map" })?;
+            if map.contains_key(guard.name()) {
+                return Err(Error::AlreadyRegistered { kind: "Mediator", name: guard.name().into() });
+            }
+            map.insert(guard.name().into(), Arc::clone(&mediator));
+            (guard.list_notification_interests(), guard.priority())
+        };
 
         let notify = {
             let mediator = Arc::clone(&mediator);
             Arc::new(move |notification: &Arc<dyn INotification>| {
-                mediator.write().unwrap().handle_notification(notification);
+                if let Ok(mut guard) = mediator.write() {
+                    guard.handle_notification(notification);
+                }
             })
         };
 
-        for interest in mediator.read().unwrap().list_notification_interests() {
+        for interest in interests {
             let context = Arc::new(Arc::clone(&mediator));
             let observer = Observer::new(Some(notify.clone()), Some(context));
-            self.register_observer(&interest, Arc::new(observer));
+            self.register_observer_with_priority(&interest, Arc::new(observer), priority);
         }
 
-        {
-            let mut guard = mediator.write().unwrap();
-            guard.initialize_notifier(&self.key);
-            guard.on_register();
-        }
+        let mut guard = mediator.write().map_err(|_| Error::LockPoisoned { lock: "Mediator" })?;
+        guard.initialize_notifier(&self.key);
+        guard.on_register();
+        Ok(())
     }
 
-    /// Retrieve a `Mediator` from the `IView`.
+    /// Retrieve a `Mediator` from the `IView`, failing if it is not registered.
     ///
     /// # Arguments
     /// * `mediator_name` - The name of the `Mediator` instance to retrieve.
     ///
     /// # Returns
-    /// The `Mediator` instance previously registered in this core with the given `mediator_name`.
-    fn retrieve_mediator(&self, mediator_name: &str) -> Option<Arc<RwLock<dyn IMediator>>> {
-        self.mediator_map.read().ok()
-            .and_then(|map| map.get(mediator_name).cloned())
+    /// The `Mediator` instance, `Error::NotFound` if none is registered with the given name, or
+    /// `Error::LockPoisoned` if the mediator map lock is poisoned.
+    fn try_retrieve_mediator(&self, mediator_name: &str) -> Result<Arc<RwLock<dyn IMediator>>, Error> {
+        self.mediator_map.read()
+            .map_err(|_| Error::LockPoisoned { lock: "View mediator map" })?
+            .get(mediator_name).cloned()
+            .ok_or_else(|| Error::NotFound { kind: "Mediator", name: mediator_name.into() })
     }
 
     /// Check if a `Mediator` is registered with the `IView`.
@@ -182,27 +564,33 @@ impl IView for View {
     /// * `mediator_name` - The name of the `Mediator` you're looking for.
     ///
     /// # Returns
-    /// `true` if a `Mediator` is registered in this core with the given `mediator_name`, otherwise `false`.
-    fn has_mediator(&self, mediator_name: &str) -> bool {
-        self.mediator_map.read().ok()
+    /// Whether a `Mediator` is registered with the given name, or `Error::LockPoisoned` if the
+    /// mediator map lock is poisoned.
+    fn try_has_mediator(&self, mediator_name: &str) -> Result<bool, Error> {
+        self.mediator_map.read()
             .map(|map| map.contains_key(mediator_name))
-            .unwrap()
+            .map_err(|_| Error::LockPoisoned { lock: "View mediator map" })
     }
 
-    /// Remove a `Mediator` from the `IView`.
+    /// Remove a `Mediator` from the `IView`, failing if it is not registered.
     ///
     /// # Arguments
     /// * `mediator_name` - Name of the `Mediator` instance to be removed.
     ///
     /// # Returns
-    /// The `Mediator` that was removed from this core's `IView`.
-    fn remove_mediator(&self, mediator_name: &str) -> Option<Arc<RwLock<dyn IMediator>>> {
-        self.mediator_map.write().ok()
-            .and_then(|mut map| map.remove(mediator_name))
-            .map(|mediator| {
-                for interest in mediator.read().unwrap().list_notification_interests() {
-                    self.remove_observer(&interest, Arc::new(Arc::clone(&mediator)));
-                }
-                mediator.write().unwrap().on_remove(); mediator })
+    /// The removed `Mediator`, `Error::NotFound` if none is registered with the given name, or
+    /// `Error::LockPoisoned` if a lock is poisoned.
+    fn try_remove_mediator(&self, mediator_name: &str) -> Result<Arc<RwLock<dyn IMediator>>, Error> {
+        let mediator = self.mediator_map.write()
+            .map_err(|_| Error::LockPoisoned { lock: "View mediator map" })?
+            .remove(mediator_name)
+            .ok_or_else(|| Error::NotFound { kind: "Mediator", name: mediator_name.into() })?;
+
+        let interests = mediator.read().map_err(|_| Error::LockPoisoned { lock: "Mediator" })?.list_notification_interests();
+        for interest in interests {
+            self.remove_observer(&interest, Arc::new(Arc::clone(&mediator)));
+        }
+        mediator.write().map_err(|_| Error::LockPoisoned { lock: "Mediator" })?.on_remove();
+        Ok(mediator)
     }
 }
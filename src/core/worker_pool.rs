@@ -0,0 +1,79 @@
+use std::hash::{BuildHasher, RandomState};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// A unit of work executed by a `WorkerPool`.
+pub(crate) type Job = Box<dyn FnOnce() + Send>;
+
+/// A pool of `std::thread` workers executing `Job`s in the background.
+///
+/// When serializing, each worker has its own queue and `Job`s with the same key always run on
+/// the same worker, in the order they were submitted. Keys are hashed to pick the worker, so
+/// keys sharing a common factor with the number of workers, such as aligned addresses, are still
+/// spread across all of them. Otherwise all workers share one queue and
+/// `Job`s run on whichever worker is idle.
+///
+/// Workers exit once the pool is dropped and their queue has drained.
+pub(crate) struct WorkerPool {
+    /// The queues `Job`s are submitted to
+    queues: Vec<Sender<Job>>,
+    /// Hashes keys to queue indices
+    hasher: RandomState,
+}
+
+impl WorkerPool {
+    /// Construct a new `WorkerPool` and start its workers.
+    ///
+    /// # Arguments
+    /// * `workers` - The number of worker threads, at least one.
+    /// * `serialize` - Whether `Job`s with the same key run one at a time, in order.
+    pub(crate) fn new(workers: usize, serialize: bool) -> Self {
+        let workers = workers.max(1);
+        let (queue_count, workers_per_queue) = if serialize { (workers, 1) } else { (1, workers) };
+
+        let queues = (0..queue_count)
+            .map(|_| {
+                let (sender, receiver) = mpsc::channel::<Job>();
+                let receiver = Arc::new(Mutex::new(receiver));
+                for _ in 0..workers_per_queue {
+                    let receiver = Arc::clone(&receiver);
+                    thread::spawn(move || Self::work(&receiver));
+                }
+                sender
+            })
+            .collect();
+
+        Self { queues, hasher: RandomState::new() }
+    }
+
+    /// Submit a `Job` for execution.
+    ///
+    /// # Arguments
+    /// * `key` - Identifies the `Job`s to serialize with one another.
+    /// * `job` - The `Job` to execute.
+    pub(crate) fn execute(&self, key: usize, job: Job) {
+        let index = self.hasher.hash_one(key) as usize % self.queues.len();
+        let _ = self.queues[index].send(job);
+    }
+
+    /// Run the `Job`s received from a queue until it is closed.
+    ///
+    /// A panicking `Job` does not stop the worker.
+    ///
+    /// # Arguments
+    /// * `receiver` - The queue to receive `Job`s from.
+    fn work(receiver: &Mutex<Receiver<Job>>) {
+        loop {
+            let job = match receiver.lock() {
+                Ok(receiver) => receiver.recv(),
+                Err(_) => return,
+            };
+            match job {
+                Ok(job) => { let _ = panic::catch_unwind(AssertUnwindSafe(job)); }
+                Err(_) => return,
+            }
+        }
+    }
+}
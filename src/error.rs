@@ -0,0 +1,100 @@
+use std::fmt;
+use crate::interfaces::DispatchMode;
+
+/// The errors reported by the PureMVC framework.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// A `Notification` body was requested, but the `Notification` has none.
+    MissingBody {
+        /// The name of the `Notification`.
+        notification: String,
+    },
+    /// A `Notification` body was requested as a type it is not.
+    BodyTypeMismatch {
+        /// The name of the `Notification`.
+        notification: String,
+        /// The name of the requested type.
+        expected: &'static str,
+    },
+    /// A lock guarding framework state was poisoned by a panicking thread.
+    LockPoisoned {
+        /// A description of the poisoned lock.
+        lock: &'static str,
+    },
+    /// An actor is already registered under the given name.
+    AlreadyRegistered {
+        /// The kind of actor, such as `"Proxy"`, `"Mediator"` or `"Command"`.
+        kind: &'static str,
+        /// The name the actor is registered under.
+        name: String,
+    },
+    /// No actor is registered under the given name.
+    NotFound {
+        /// The kind of actor, such as `"Proxy"`, `"Mediator"` or `"Command"`.
+        kind: &'static str,
+        /// The name that was looked up.
+        name: String,
+    },
+    /// A `Proxy` depends on a `Proxy` that is not registered.
+    MissingDependency {
+        /// The name of the dependent `Proxy`.
+        proxy: String,
+        /// The name of the missing dependency.
+        dependency: String,
+    },
+    /// `Proxy` dependencies form a cycle.
+    DependencyCycle {
+        /// The names of the `Proxy`s in the cycle, starting and ending with the same name.
+        cycle: Vec<String>,
+    },
+    /// An actor cannot work with the `DispatchMode` of its core's `IView`.
+    UnsupportedDispatchMode {
+        /// The kind of actor, such as `"StateMachine"`.
+        kind: &'static str,
+        /// The `DispatchMode` of the core's `IView`.
+        mode: DispatchMode,
+    },
+    /// A `Notification` could not be serialized or deserialized.
+    Serialization {
+        /// The name of the `Notification`, or an empty string if it could not be read.
+        notification: String,
+        /// A description of the failure.
+        message: String,
+    },
+    /// Data could not be loaded from or saved to a store.
+    Persistence {
+        /// The key the data is saved under.
+        key: String,
+        /// A description of the failure.
+        message: String,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::MissingBody { notification } =>
+                write!(f, "Notification '{}' has no body", notification),
+            Error::BodyTypeMismatch { notification, expected } =>
+                write!(f, "Notification '{}' body is not of type {}", notification, expected),
+            Error::LockPoisoned { lock } =>
+                write!(f, "{} lock is poisoned", lock),
+            Error::AlreadyRegistered { kind, name } =>
+                write!(f, "{} '{}' is already registered", kind, name),
+            Error::NotFound { kind, name } =>
+                write!(f, "{} '{}' is not registered", kind, name),
+            Error::MissingDependency { proxy, dependency } =>
+                write!(f, "Proxy '{}' depends on '{}', which is not registered", proxy, dependency),
+            Error::DependencyCycle { cycle } =>
+                write!(f, "Proxy dependencies form a cycle: {}", cycle.join(" -> ")),
+            Error::UnsupportedDispatchMode { kind, mode } =>
+                write!(f, "{} does not support the {:?} dispatch mode", kind, mode),
+            Error::Serialization { notification, message } =>
+                write!(f, "Notification '{}' could not be serialized: {}", notification, message),
+            Error::Persistence { key, message } =>
+                write!(f, "Data saved under '{}' could not be loaded or saved: {}", key, message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,17 @@
+use std::sync::Arc;
+use crate::interfaces::ICommand;
+
+/// The trait definition for a PureMVC MultiCore `IAsyncCommand`.
+///
+/// An `IAsyncCommand` may finish its work after `execute` returns, for instance on a worker
+/// thread. It signals completion by invoking the callback set with `set_on_complete`, which is
+/// how an `AsyncMacroCommand` knows when to advance to its next SubCommand.
+///
+/// See `ICommand`, `AsyncCommand`, `AsyncMacroCommand`
+pub trait IAsyncCommand: ICommand {
+    /// Set the callback to invoke when the `IAsyncCommand` completes.
+    ///
+    /// # Arguments
+    /// * `on_complete` - The callback to invoke once the `IAsyncCommand`'s work is done (optional).
+    fn set_on_complete(&mut self, on_complete: Option<Arc<dyn Fn() + Send + Sync>>);
+}
@@ -1,6 +1,12 @@
 use std::sync::{Arc};
 use crate::interfaces::{INotification, INotifier};
 
+/// A factory creating a new instance of an `ICommand` each time it is called.
+///
+/// Being a closure, a factory may capture configuration or services to inject into the
+/// `ICommand`s it creates.
+pub type CommandFactory = Arc<dyn Fn() -> Box<dyn ICommand + Send + Sync> + Send + Sync>;
+
 /// The trait definition for a PureMVC MultiCore `ICommand`.
 ///
 /// See `IController`, `INotification`
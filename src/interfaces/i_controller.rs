@@ -1,6 +1,8 @@
 use std::any::Any;
+use std::collections::BTreeMap;
 use std::sync::{Arc};
-use crate::interfaces::{ICommand, INotification};
+use crate::Error;
+use crate::interfaces::{CommandFactory, IInterceptor, INotification};
 
 /// The trait definition for a PureMVC MultiCore `IController`.
 ///
@@ -25,8 +27,8 @@ pub trait IController: Any + Send + Sync {
     ///
     /// # Arguments
     /// * `notification_name` - The name of the `Notification` to associate the `ICommand` with.
-    /// * `factory` - A function that creates a new instance of the `ICommand`.
-    fn register_command(&self, notification_name: &str, factory: fn() -> Box<dyn ICommand + Send + Sync>);
+    /// * `factory` - A closure that creates a new instance of the `ICommand`.
+    fn register_command(&self, notification_name: &str, factory: CommandFactory);
 
     /// Execute the `ICommand` previously registered as the handler for `Notification`s
     /// with the given notification's name.
@@ -49,4 +51,123 @@ pub trait IController: Any + Send + Sync {
     /// # Arguments
     /// * `notification_name` - The name of the `Notification` to remove the `ICommand` mapping for.
     fn remove_command(&self, notification_name: &str);
+
+    /// Add a `Notification` to `ICommand` mapping alongside any existing mappings for the
+    /// `Notification`.
+    ///
+    /// All `ICommand`s mapped to a `Notification` are executed, highest `priority` first, and in
+    /// registration order among equal priorities. `register_command` maps with priority `0`.
+    /// The default implementation panics, as `register_command` would replace the existing
+    /// mappings; implement it to support several `ICommand`s per `Notification`.
+    ///
+    /// # Arguments
+    /// * `notification_name` - The name of the `Notification` to associate the `ICommand` with.
+    /// * `factory` - A closure that creates a new instance of the `ICommand`.
+    /// * `priority` - The execution priority of the `ICommand`.
+    fn add_command(&self, notification_name: &str, factory: CommandFactory, priority: i32) {
+        let _ = factory; let _ = priority;
+        unimplemented!("IController::add_command is not implemented, cannot add a command for '{}'", notification_name);
+    }
+
+    /// Remove a single `Notification` to `ICommand` mapping, leaving any other mappings for the
+    /// `Notification` in place.
+    ///
+    /// The default implementation panics, as `remove_command` would remove every mapping for the
+    /// `Notification`; implement it along with `add_command`.
+    ///
+    /// # Arguments
+    /// * `notification_name` - The name of the `Notification` to remove the mapping for.
+    /// * `factory` - The factory the mapping was added with.
+    fn remove_command_factory(&self, notification_name: &str, factory: &CommandFactory) {
+        let _ = factory;
+        unimplemented!("IController::remove_command_factory is not implemented, cannot remove a command for '{}'", notification_name);
+    }
+
+    /// Count the `ICommand`s mapped to each `Notification` name.
+    ///
+    /// # Returns
+    /// The number of `ICommand`s mapped to each `Notification` name. The default implementation
+    /// returns an empty map.
+    fn command_counts(&self) -> BTreeMap<String, usize> {
+        BTreeMap::new()
+    }
+
+    /// Add an `IInterceptor` to the `IController`.
+    ///
+    /// The default implementation ignores the `IInterceptor`.
+    ///
+    /// # Arguments
+    /// * `interceptor` - The `IInterceptor` to call around the execution of the `ICommand`s for each `Notification`.
+    fn add_interceptor(&self, interceptor: Arc<dyn IInterceptor>) {
+        let _ = interceptor;
+    }
+
+    /// Remove an `IInterceptor` from the `IController`.
+    ///
+    /// # Arguments
+    /// * `interceptor` - The `IInterceptor` previously added.
+    fn remove_interceptor(&self, interceptor: &Arc<dyn IInterceptor>) {
+        let _ = interceptor;
+    }
+
+    /// Register an `INotification` to `ICommand` mapping, failing if one already exists.
+    ///
+    /// # Arguments
+    /// * `notification_name` - The name of the `INotification` to associate the `ICommand` with.
+    /// * `factory` - A closure that creates a new instance of the `ICommand`.
+    ///
+    /// # Returns
+    /// `Error::AlreadyRegistered` if an `ICommand` is mapped to the `notification_name`, or
+    /// `Error::LockPoisoned` if a lock is poisoned.
+    fn try_register_command(&self, notification_name: &str, factory: CommandFactory) -> Result<(), Error> {
+        if self.try_has_command(notification_name)? {
+            return Err(Error::AlreadyRegistered { kind: "Command", name: notification_name.into() });
+        }
+        self.register_command(notification_name, factory);
+        Ok(())
+    }
+
+    /// Execute the `ICommand` registered for the given `INotification`, failing if there is none.
+    ///
+    /// # Arguments
+    /// * `notification` - The `INotification` to execute the associated `ICommand` for.
+    ///
+    /// # Returns
+    /// `Error::NotFound` if no `ICommand` is mapped to the notification's name, or
+    /// `Error::LockPoisoned` if a lock is poisoned.
+    fn try_execute_command(&self, notification: &Arc<dyn INotification>) -> Result<(), Error> {
+        if !self.try_has_command(notification.name())? {
+            return Err(Error::NotFound { kind: "Command", name: notification.name().into() });
+        }
+        self.execute_command(notification);
+        Ok(())
+    }
+
+    /// Check if an `ICommand` is registered for a given `INotification` name.
+    ///
+    /// # Arguments
+    /// * `notification_name` - The name of the `INotification`.
+    ///
+    /// # Returns
+    /// Whether an `ICommand` is mapped to the `notification_name`, or `Error::LockPoisoned` if a
+    /// lock is poisoned.
+    fn try_has_command(&self, notification_name: &str) -> Result<bool, Error> {
+        Ok(self.has_command(notification_name))
+    }
+
+    /// Remove an `INotification` to `ICommand` mapping, failing if there is none.
+    ///
+    /// # Arguments
+    /// * `notification_name` - The name of the `INotification` to remove the `ICommand` mapping for.
+    ///
+    /// # Returns
+    /// `Error::NotFound` if no `ICommand` is mapped to the `notification_name`, or
+    /// `Error::LockPoisoned` if a lock is poisoned.
+    fn try_remove_command(&self, notification_name: &str) -> Result<(), Error> {
+        if !self.try_has_command(notification_name)? {
+            return Err(Error::NotFound { kind: "Command", name: notification_name.into() });
+        }
+        self.remove_command(notification_name);
+        Ok(())
+    }
 }
@@ -1,5 +1,13 @@
 use std::sync::{Arc, RwLock};
-use crate::interfaces::{ICommand, IMediator, INotification, INotifier, IProxy};
+use crate::Error;
+use crate::interfaces::{CommandFactory, IInterceptor, IMediator, INotification, INotifier, IProxy, ProxyFactory};
+#[cfg(feature = "async")]
+use crate::interfaces::BoxFuture;
+#[cfg(feature = "persistence")]
+use crate::interfaces::IStore;
+
+/// A callback run once when a core is removed, receiving the core's Multiton key.
+pub type ShutdownHook = Box<dyn FnOnce(&str) + Send>;
 
 /// The trait definition for a PureMVC MultiCore `IFacade`.
 ///
@@ -45,8 +53,8 @@ pub trait IFacade: INotifier {
     ///
     /// # Arguments
     /// * `notification_name` - The name of the `Notification` to associate the `ICommand` with.
-    /// * `factory` - A function that creates a new instance of the `ICommand`.
-    fn register_command(&self, notification_name: &str, factory: fn() -> Box<dyn ICommand + Send + Sync>) {
+    /// * `factory` - A closure that creates a new instance of the `ICommand`.
+    fn register_command(&self, notification_name: &str, factory: CommandFactory) {
         let _ = notification_name; let _ = factory;
     }
 
@@ -69,6 +77,26 @@ pub trait IFacade: INotifier {
         let _ = notification_name;
     }
 
+    /// Add a `Notification` to `ICommand` mapping alongside any existing mappings for the
+    /// `Notification`.
+    ///
+    /// # Arguments
+    /// * `notification_name` - The name of the `Notification` to associate the `ICommand` with.
+    /// * `factory` - A closure that creates a new instance of the `ICommand`.
+    /// * `priority` - The execution priority of the `ICommand`, highest first.
+    fn add_command(&self, notification_name: &str, factory: CommandFactory, priority: i32) {
+        let _ = notification_name; let _ = factory; let _ = priority;
+    }
+
+    /// Remove a single `Notification` to `ICommand` mapping with the `IController`.
+    ///
+    /// # Arguments
+    /// * `notification_name` - The name of the `Notification` to remove the mapping for.
+    /// * `factory` - The factory the mapping was added with.
+    fn remove_command_factory(&self, notification_name: &str, factory: &CommandFactory) {
+        let _ = notification_name; let _ = factory;
+    }
+
     /// Register a `Proxy` instance with the `IModel`.
     ///
     /// # Arguments
@@ -77,6 +105,24 @@ pub trait IFacade: INotifier {
         let _ = proxy;
     }
 
+    /// Register several `Proxy` instances with the `IModel`, in dependency order.
+    ///
+    /// The default implementation registers them in the given order.
+    ///
+    /// # Arguments
+    /// * `proxies` - The `Proxy` instances to register.
+    ///
+    /// # Returns
+    /// `Error::MissingDependency` if a dependency is neither in `proxies` nor registered,
+    /// `Error::DependencyCycle` if the dependencies form a cycle, or another `Error` if
+    /// registration fails.
+    fn register_proxies(&self, proxies: Vec<Arc<RwLock<dyn IProxy>>>) -> Result<(), Error> {
+        for proxy in proxies {
+            self.try_register_proxy(proxy)?;
+        }
+        Ok(())
+    }
+
     /// Retrieve a `Proxy` instance from the `IModel`.
     ///
     /// # Arguments
@@ -110,6 +156,17 @@ pub trait IFacade: INotifier {
         let _ = proxy_name; None
     }
 
+    /// Register a `Proxy` lazily with the `IModel`, by name and factory.
+    ///
+    /// The factory is not called until the `Proxy` is first retrieved.
+    ///
+    /// # Arguments
+    /// * `proxy_name` - The name the `Proxy` will be registered with.
+    /// * `factory` - A closure that creates the `Proxy` instance.
+    fn register_proxy_factory(&self, proxy_name: &str, factory: ProxyFactory) {
+        let _ = (proxy_name, factory);
+    }
+
     /// Register a `Mediator` instance with the `IView`.
     ///
     /// Registers the `Mediator` so that it can be retrieved by name, and interrogates the
@@ -159,6 +216,164 @@ pub trait IFacade: INotifier {
         let _ = mediator_name; None
     }
 
+    /// Register a `Notification` to `ICommand` mapping with the `IController`, failing if one already exists.
+    ///
+    /// # Arguments
+    /// * `notification_name` - The name of the `Notification` to associate the `ICommand` with.
+    /// * `factory` - A closure that creates a new instance of the `ICommand`.
+    ///
+    /// # Returns
+    /// `Error::AlreadyRegistered` if an `ICommand` is mapped to the `notification_name`, or
+    /// `Error::LockPoisoned` if a lock is poisoned.
+    fn try_register_command(&self, notification_name: &str, factory: CommandFactory) -> Result<(), Error> {
+        if self.has_command(notification_name) {
+            return Err(Error::AlreadyRegistered { kind: "Command", name: notification_name.into() });
+        }
+        self.register_command(notification_name, factory);
+        Ok(())
+    }
+
+    /// Check if an `ICommand` is registered for a given `Notification` name with the `IController`.
+    ///
+    /// # Arguments
+    /// * `notification_name` - The name of the `Notification`.
+    ///
+    /// # Returns
+    /// Whether an `ICommand` is mapped to the `notification_name`, or `Error::LockPoisoned` if a
+    /// lock is poisoned.
+    fn try_has_command(&self, notification_name: &str) -> Result<bool, Error> {
+        Ok(self.has_command(notification_name))
+    }
+
+    /// Remove a `Notification` to `ICommand` mapping from the `IController`, failing if there is none.
+    ///
+    /// # Arguments
+    /// * `notification_name` - The name of the `Notification` to remove the `ICommand` mapping for.
+    ///
+    /// # Returns
+    /// `Error::NotFound` if no `ICommand` is mapped to the `notification_name`, or
+    /// `Error::LockPoisoned` if a lock is poisoned.
+    fn try_remove_command(&self, notification_name: &str) -> Result<(), Error> {
+        if !self.has_command(notification_name) {
+            return Err(Error::NotFound { kind: "Command", name: notification_name.into() });
+        }
+        self.remove_command(notification_name);
+        Ok(())
+    }
+
+    /// Register a `Proxy` instance with the `IModel`, failing if the name is taken.
+    ///
+    /// # Arguments
+    /// * `proxy` - An object reference to be held by the `IModel`.
+    ///
+    /// # Returns
+    /// `Error::AlreadyRegistered` if a `Proxy` is registered with the same name, or
+    /// `Error::LockPoisoned` if a lock is poisoned.
+    fn try_register_proxy(&self, proxy: Arc<RwLock<dyn IProxy>>) -> Result<(), Error> {
+        let name = proxy.read().map_err(|_| Error::LockPoisoned { lock: "Proxy" })?.name().to_string();
+        if self.has_proxy(&name) {
+            return Err(Error::AlreadyRegistered { kind: "Proxy", name });
+        }
+        self.register_proxy(proxy);
+        Ok(())
+    }
+
+    /// Retrieve a `Proxy` instance from the `IModel`, failing if it is not registered.
+    ///
+    /// # Arguments
+    /// * `proxy_name` - The name of the `Proxy` instance to retrieve.
+    ///
+    /// # Returns
+    /// The `Proxy` instance, `Error::NotFound` if none is registered with the given name, or
+    /// `Error::LockPoisoned` if a lock is poisoned.
+    fn try_retrieve_proxy(&self, proxy_name: &str) -> Result<Arc<RwLock<dyn IProxy>>, Error> {
+        self.retrieve_proxy(proxy_name)
+            .ok_or_else(|| Error::NotFound { kind: "Proxy", name: proxy_name.into() })
+    }
+
+    /// Check if a `Proxy` is registered with the `IModel`.
+    ///
+    /// # Arguments
+    /// * `proxy_name` - The name of the `Proxy` instance you're looking for.
+    ///
+    /// # Returns
+    /// Whether a `Proxy` is registered with the given name, or `Error::LockPoisoned` if a lock
+    /// is poisoned.
+    fn try_has_proxy(&self, proxy_name: &str) -> Result<bool, Error> {
+        Ok(self.has_proxy(proxy_name))
+    }
+
+    /// Remove a `Proxy` instance from the `IModel`, failing if it is not registered.
+    ///
+    /// # Arguments
+    /// * `proxy_name` - Name of the `Proxy` instance to be removed.
+    ///
+    /// # Returns
+    /// The removed `Proxy`, `None` if it was registered by factory and never instantiated,
+    /// `Error::NotFound` if none is registered with the given name, or `Error::LockPoisoned` if a
+    /// lock is poisoned.
+    fn try_remove_proxy(&self, proxy_name: &str) -> Result<Option<Arc<RwLock<dyn IProxy>>>, Error> {
+        if !self.try_has_proxy(proxy_name)? {
+            return Err(Error::NotFound { kind: "Proxy", name: proxy_name.into() });
+        }
+        Ok(self.remove_proxy(proxy_name))
+    }
+
+    /// Register a `Mediator` instance with the `IView`, failing if the name is taken.
+    ///
+    /// # Arguments
+    /// * `mediator` - A reference to the `Mediator` instance.
+    ///
+    /// # Returns
+    /// `Error::AlreadyRegistered` if a `Mediator` is registered with the same name, or
+    /// `Error::LockPoisoned` if a lock is poisoned.
+    fn try_register_mediator(&self, mediator: Arc<RwLock<dyn IMediator>>) -> Result<(), Error> {
+        let name = mediator.read().map_err(|_| Error::LockPoisoned { lock: "Mediator" })?.name().to_string();
+        if self.has_mediator(&name) {
+            return Err(Error::AlreadyRegistered { kind: "Mediator", name });
+        }
+        self.register_mediator(mediator);
+        Ok(())
+    }
+
+    /// Retrieve a `Mediator` from the `IView`, failing if it is not registered.
+    ///
+    /// # Arguments
+    /// * `mediator_name` - The name of the `Mediator` instance to retrieve.
+    ///
+    /// # Returns
+    /// The `Mediator` instance, `Error::NotFound` if none is registered with the given name, or
+    /// `Error::LockPoisoned` if a lock is poisoned.
+    fn try_retrieve_mediator(&self, mediator_name: &str) -> Result<Arc<RwLock<dyn IMediator>>, Error> {
+        self.retrieve_mediator(mediator_name)
+            .ok_or_else(|| Error::NotFound { kind: "Mediator", name: mediator_name.into() })
+    }
+
+    /// Check if a `Mediator` is registered with the `IView`.
+    ///
+    /// # Arguments
+    /// * `mediator_name` - The name of the `Mediator` you're looking for.
+    ///
+    /// # Returns
+    /// Whether a `Mediator` is registered with the given name, or `Error::LockPoisoned` if a
+    /// lock is poisoned.
+    fn try_has_mediator(&self, mediator_name: &str) -> Result<bool, Error> {
+        Ok(self.has_mediator(mediator_name))
+    }
+
+    /// Remove a `Mediator` from the `IView`, failing if it is not registered.
+    ///
+    /// # Arguments
+    /// * `mediator_name` - Name of the `Mediator` instance to be removed.
+    ///
+    /// # Returns
+    /// The removed `Mediator`, `Error::NotFound` if none is registered with the given name, or
+    /// `Error::LockPoisoned` if a lock is poisoned.
+    fn try_remove_mediator(&self, mediator_name: &str) -> Result<Arc<RwLock<dyn IMediator>>, Error> {
+        self.remove_mediator(mediator_name)
+            .ok_or_else(|| Error::NotFound { kind: "Mediator", name: mediator_name.into() })
+    }
+
     /// Notify the `Observer`s for a particular `Notification`.
     ///
     /// This method allows you to send custom `Notification` classes using the `IFacade`.
@@ -171,4 +386,81 @@ pub trait IFacade: INotifier {
     fn notify_observers(&self, notification: &Arc<dyn INotification>) {
         let _ = notification;
     }
+
+    /// Notify the `Observer`s for a particular `Notification`, awaiting each one in turn.
+    ///
+    /// # Arguments
+    /// * `notification` - The `Notification` to have the `IView` notify `Observer`s of.
+    ///
+    /// # Returns
+    /// A future completing when every `Observer` has handled the `Notification`.
+    #[cfg(feature = "async")]
+    fn notify_observers_async(&self, notification: &Arc<dyn INotification>) -> BoxFuture {
+        self.notify_observers(notification);
+        Box::pin(std::future::ready(()))
+    }
+
+    /// Register a hook to run when the core is removed.
+    ///
+    /// # Arguments
+    /// * `hook` - The callback to run, receiving the core's Multiton key.
+    fn add_shutdown_hook(&self, hook: ShutdownHook) {
+        let _ = hook;
+    }
+
+    /// Tear down the core before it is removed.
+    ///
+    /// Called by `Facade::remove_core` while the core's actors are still registered. The default
+    /// implementation does nothing.
+    fn shutdown(&self) {}
+
+    /// Add an `IInterceptor` to the Core's `IView` and `IController`.
+    ///
+    /// The default implementation ignores the `IInterceptor`.
+    ///
+    /// # Arguments
+    /// * `interceptor` - The `IInterceptor` to call around the delivery of each `Notification`
+    ///   and the execution of its `ICommand`s.
+    fn add_interceptor(&self, interceptor: Arc<dyn IInterceptor>) {
+        let _ = interceptor;
+    }
+
+    /// Remove an `IInterceptor` from the Core's `IView` and `IController`.
+    ///
+    /// # Arguments
+    /// * `interceptor` - The `IInterceptor` previously added.
+    fn remove_interceptor(&self, interceptor: &Arc<dyn IInterceptor>) {
+        let _ = interceptor;
+    }
+
+    /// Set the `IStore` the `IModel` keeps the data of `IPersistentProxy` instances in.
+    ///
+    /// # Arguments
+    /// * `store` - The store, or `None` to stop persisting data.
+    #[cfg(feature = "persistence")]
+    fn set_store(&self, store: Option<Arc<dyn IStore>>) {
+        let _ = store;
+    }
+
+    /// Save the data of every registered `IPersistentProxy` to the `IStore`.
+    ///
+    /// # Returns
+    /// The first error met, if any. The default implementation does nothing.
+    #[cfg(feature = "persistence")]
+    fn save_proxies(&self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Restore the data of a registered `IPersistentProxy` from the `IStore`.
+    ///
+    /// # Arguments
+    /// * `proxy_name` - The name of the `Proxy` instance to restore.
+    ///
+    /// # Returns
+    /// `true` if data was restored, otherwise `false`, or an `Error` if the `Proxy` is not
+    /// registered or its data cannot be loaded. The default implementation returns `false`.
+    #[cfg(feature = "persistence")]
+    fn restore_proxy(&self, proxy_name: &str) -> Result<bool, Error> {
+        let _ = proxy_name; Ok(false)
+    }
 }
@@ -0,0 +1,57 @@
+use std::sync::Arc;
+use std::time::Duration;
+use crate::interfaces::INotification;
+
+/// The trait definition for a PureMVC MultiCore `IInterceptor`.
+///
+/// An `IInterceptor` is a middleware registered with an `IView` and/or an `IController`, called
+/// around the delivery of each `INotification` to its `IObserver`s and around the execution of
+/// the `ICommand`s mapped to it. Interceptors are called in the order in which they were added.
+///
+/// An `IInterceptor` may trace, time or audit the traffic of a Core, or veto an `INotification`
+/// by stopping its propagation in `before_notify`.
+///
+/// See `IView`, `IController`, `INotification`
+pub trait IInterceptor: Send + Sync {
+    /// Called before an `INotification` is delivered to its `IObserver`s.
+    ///
+    /// # Arguments
+    /// * `key` - The Multiton key of the Core delivering the `INotification`.
+    /// * `notification` - The `INotification` being delivered.
+    /// * `observer_count` - The number of `IObserver`s to notify.
+    fn before_notify(&self, key: &str, notification: &Arc<dyn INotification>, observer_count: usize) {
+        let _ = key; let _ = notification; let _ = observer_count;
+    }
+
+    /// Called after an `INotification` has been delivered to its `IObserver`s.
+    ///
+    /// # Arguments
+    /// * `key` - The Multiton key of the Core delivering the `INotification`.
+    /// * `notification` - The `INotification` delivered.
+    /// * `observer_count` - The number of `IObserver`s to notify.
+    /// * `elapsed` - The time taken to deliver the `INotification`.
+    fn after_notify(&self, key: &str, notification: &Arc<dyn INotification>, observer_count: usize, elapsed: Duration) {
+        let _ = key; let _ = notification; let _ = observer_count; let _ = elapsed;
+    }
+
+    /// Called before the `ICommand`s mapped to an `INotification` are executed.
+    ///
+    /// # Arguments
+    /// * `key` - The Multiton key of the Core executing the `ICommand`s.
+    /// * `notification` - The `INotification` the `ICommand`s handle.
+    /// * `command_count` - The number of `ICommand`s to execute.
+    fn before_execute(&self, key: &str, notification: &Arc<dyn INotification>, command_count: usize) {
+        let _ = key; let _ = notification; let _ = command_count;
+    }
+
+    /// Called after the `ICommand`s mapped to an `INotification` have been executed.
+    ///
+    /// # Arguments
+    /// * `key` - The Multiton key of the Core executing the `ICommand`s.
+    /// * `notification` - The `INotification` the `ICommand`s handled.
+    /// * `command_count` - The number of `ICommand`s to execute.
+    /// * `elapsed` - The time taken to execute the `ICommand`s.
+    fn after_execute(&self, key: &str, notification: &Arc<dyn INotification>, command_count: usize, elapsed: Duration) {
+        let _ = key; let _ = notification; let _ = command_count; let _ = elapsed;
+    }
+}
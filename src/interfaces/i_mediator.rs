@@ -50,6 +50,18 @@ pub trait IMediator: INotifier {
         vec![]
     }
 
+    /// Get the `IMediator`'s notification priority.
+    ///
+    /// The `IView` notifies the `IMediator` of its `Notification` interests ahead of `IObserver`s
+    /// with a lower priority, so that, for instance, a validating `IMediator` handles a
+    /// `Notification` before a rendering one.
+    ///
+    /// # Returns
+    /// The notification priority of the `IMediator`, `0` by default.
+    fn priority(&self) -> i32 {
+        0
+    }
+
     /// Handle a `Notification`.
     ///
     /// # Arguments
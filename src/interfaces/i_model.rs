@@ -1,6 +1,9 @@
 use std::any::Any;
 use std::sync::{Arc, RwLock};
-use crate::interfaces::IProxy;
+use crate::Error;
+use crate::interfaces::{IProxy, ProxyFactory};
+#[cfg(feature = "persistence")]
+use crate::interfaces::IStore;
 
 /// The trait definition for a PureMVC MultiCore `IModel`.
 ///
@@ -29,6 +32,29 @@ pub trait IModel: Any + Sync + Send {
     /// * `proxy` - An object reference to be held by the `IModel`.
     fn register_proxy(&self, proxy: Arc<RwLock<dyn IProxy>>);
 
+    /// Register several `Proxy` instances with the `IModel`, in dependency order.
+    ///
+    /// Each `Proxy` is registered after the `Proxy`s it lists in `IProxy::dependencies`, so its
+    /// dependencies are resolved before its `on_register` is called. Nothing is registered if a
+    /// name is taken, a dependency is missing or the dependencies form a cycle, or if registering
+    /// any of them fails. The default implementation registers them in the given order, stopping
+    /// at the first failure.
+    ///
+    /// # Arguments
+    /// * `proxies` - The `Proxy` instances to register.
+    ///
+    /// # Returns
+    /// `Error::MissingDependency` if a dependency is neither in `proxies` nor registered,
+    /// `Error::DependencyCycle` if the dependencies form a cycle, `Error::AlreadyRegistered` if
+    /// two `Proxy`s share a name or a name is registered already, or `Error::LockPoisoned` if a
+    /// lock is poisoned.
+    fn register_proxies(&self, proxies: Vec<Arc<RwLock<dyn IProxy>>>) -> Result<(), Error> {
+        for proxy in proxies {
+            self.try_register_proxy(proxy)?;
+        }
+        Ok(())
+    }
+
     /// Retrieve a `Proxy` instance from the `IModel`.
     ///
     /// # Arguments
@@ -55,4 +81,131 @@ pub trait IModel: Any + Sync + Send {
     /// # Returns
     /// The `Proxy` that was removed from the `IModel`.
     fn remove_proxy(&self, proxy_name: &str) -> Option<Arc<RwLock<dyn IProxy>>>;
+
+    /// List the names of the `Proxy` instances registered with the `IModel`.
+    ///
+    /// # Returns
+    /// The names of the registered `Proxy` instances, sorted. The default implementation
+    /// returns an empty list, in which case `Model::remove_model` cannot call the `on_remove` of
+    /// the registered `Proxy` instances; implement it to have them torn down.
+    fn proxy_names(&self) -> Vec<String> {
+        vec![]
+    }
+
+    /// Register a `Proxy` lazily, by name and factory.
+    ///
+    /// The factory is not called until the `Proxy` is first retrieved, at which point the
+    /// instance is registered as by `register_proxy`, so `initialize_notifier` and `on_register`
+    /// are called then. Until that point the `Proxy` counts as registered for `has_proxy` and
+    /// `proxy_names`, and removing it drops the factory. The default implementation does nothing.
+    ///
+    /// # Arguments
+    /// * `proxy_name` - The name the `Proxy` will be registered with.
+    /// * `factory` - A closure that creates the `Proxy` instance.
+    fn register_proxy_factory(&self, proxy_name: &str, factory: ProxyFactory) {
+        let _ = (proxy_name, factory);
+    }
+
+    /// Register a `Proxy` instance with the `IModel`, failing if the name is taken.
+    ///
+    /// # Arguments
+    /// * `proxy` - An object reference to be held by the `IModel`.
+    ///
+    /// # Returns
+    /// `Error::AlreadyRegistered` if a `Proxy` is registered with the same name, or
+    /// `Error::LockPoisoned` if a lock is poisoned.
+    fn try_register_proxy(&self, proxy: Arc<RwLock<dyn IProxy>>) -> Result<(), Error> {
+        let name = proxy.read().map_err(|_| Error::LockPoisoned { lock: "Proxy" })?.name().to_string();
+        if self.try_has_proxy(&name)? {
+            return Err(Error::AlreadyRegistered { kind: "Proxy", name });
+        }
+        self.register_proxy(proxy);
+        Ok(())
+    }
+
+    /// Retrieve a `Proxy` instance from the `IModel`, failing if it is not registered.
+    ///
+    /// # Arguments
+    /// * `proxy_name` - The name of the `Proxy` instance to retrieve.
+    ///
+    /// # Returns
+    /// The `Proxy` instance, `Error::NotFound` if none is registered with the given name, or
+    /// `Error::LockPoisoned` if a lock is poisoned.
+    fn try_retrieve_proxy(&self, proxy_name: &str) -> Result<Arc<RwLock<dyn IProxy>>, Error> {
+        self.retrieve_proxy(proxy_name)
+            .ok_or_else(|| Error::NotFound { kind: "Proxy", name: proxy_name.into() })
+    }
+
+    /// Check if a `Proxy` is registered with the `IModel`.
+    ///
+    /// # Arguments
+    /// * `proxy_name` - The name of the `Proxy` instance you're looking for.
+    ///
+    /// # Returns
+    /// Whether a `Proxy` is registered with the given name, or `Error::LockPoisoned` if a lock
+    /// is poisoned.
+    fn try_has_proxy(&self, proxy_name: &str) -> Result<bool, Error> {
+        Ok(self.has_proxy(proxy_name))
+    }
+
+    /// Remove a `Proxy` instance from the `IModel`, failing if it is not registered.
+    ///
+    /// # Arguments
+    /// * `proxy_name` - Name of the `Proxy` instance to be removed.
+    ///
+    /// # Returns
+    /// The removed `Proxy`, `None` if it was registered by factory and never instantiated,
+    /// `Error::NotFound` if none is registered with the given name, or `Error::LockPoisoned` if a
+    /// lock is poisoned.
+    fn try_remove_proxy(&self, proxy_name: &str) -> Result<Option<Arc<RwLock<dyn IProxy>>>, Error> {
+        if !self.try_has_proxy(proxy_name)? {
+            return Err(Error::NotFound { kind: "Proxy", name: proxy_name.into() });
+        }
+        Ok(self.remove_proxy(proxy_name))
+    }
+
+    /// Set the `IStore` the data of `IPersistentProxy` instances is kept in.
+    ///
+    /// Once a store is set, `IPersistentProxy` instances are restored from it when registered
+    /// and saved to it when removed. The default implementation does nothing.
+    ///
+    /// # Arguments
+    /// * `store` - The store, or `None` to stop persisting data.
+    #[cfg(feature = "persistence")]
+    fn set_store(&self, store: Option<Arc<dyn IStore>>) {
+        let _ = store;
+    }
+
+    /// Get the `IStore` the data of `IPersistentProxy` instances is kept in.
+    ///
+    /// # Returns
+    /// The store, if one is set. The default implementation returns `None`.
+    #[cfg(feature = "persistence")]
+    fn store(&self) -> Option<Arc<dyn IStore>> {
+        None
+    }
+
+    /// Save the data of every registered `IPersistentProxy` to the `IStore`.
+    ///
+    /// # Returns
+    /// The first error met, if any; the remaining proxies are still saved. The default
+    /// implementation does nothing.
+    #[cfg(feature = "persistence")]
+    fn save_proxies(&self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Restore the data of a registered `IPersistentProxy` from the `IStore`.
+    ///
+    /// # Arguments
+    /// * `proxy_name` - The name of the `Proxy` instance to restore.
+    ///
+    /// # Returns
+    /// `true` if data was restored, `false` if no store is set, the `Proxy` does not opt in to
+    /// persistence or nothing is saved for it, or an `Error` if it is not registered or its data
+    /// cannot be loaded. The default implementation returns `false`.
+    #[cfg(feature = "persistence")]
+    fn restore_proxy(&self, proxy_name: &str) -> Result<bool, Error> {
+        let _ = proxy_name; Ok(false)
+    }
 }
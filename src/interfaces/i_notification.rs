@@ -1,5 +1,6 @@
-use std::any::Any;
+use std::any::{type_name, Any};
 use std::sync::{Arc};
+use crate::Error;
 
 /// The trait definition for a PureMVC MultiCore `INotification`.
 ///
@@ -52,4 +53,45 @@ pub trait INotification: Any + Send + Sync {
     /// # Returns
     /// A string representation of the `Notification`.
     fn to_string(&self) -> String;
+
+    /// Stop the `Notification` from being delivered to any further `Observer`s.
+    ///
+    /// Called by an `Observer`, typically an interceptor `Mediator` registered with a high
+    /// priority, to veto the `Notification` before later `Observer`s and `ICommand`s handle it.
+    /// The default implementation does nothing.
+    fn stop_propagation(&self) {}
+
+    /// Check if the `Notification` has been stopped from being delivered to further `Observer`s.
+    ///
+    /// # Returns
+    /// `true` if `stop_propagation` has been called, otherwise `false`.
+    fn is_propagation_stopped(&self) -> bool {
+        false
+    }
+}
+
+impl dyn INotification {
+    /// Get the body of the `Notification` as a given type.
+    ///
+    /// # Returns
+    /// The body of the `Notification`, or an `Error` if the `Notification` has no body or its
+    /// body is not a `T`.
+    pub fn body_as<T: Any + Send + Sync>(&self) -> Result<Arc<T>, Error> {
+        self.body()
+            .ok_or_else(|| Error::MissingBody { notification: self.name().into() })
+            .and_then(|body| Arc::clone(body).downcast::<T>()
+                .map_err(|_| Error::BodyTypeMismatch { notification: self.name().into(), expected: type_name::<T>() }))
+    }
+
+    /// Get a reference to the body of the `Notification` as a given type.
+    ///
+    /// # Returns
+    /// The body of the `Notification`, or an `Error` if the `Notification` has no body or its
+    /// body is not a `T`.
+    pub fn body_ref<T: Any + Send + Sync>(&self) -> Result<&T, Error> {
+        self.body()
+            .ok_or_else(|| Error::MissingBody { notification: self.name().into() })
+            .and_then(|body| body.downcast_ref::<T>()
+                .ok_or_else(|| Error::BodyTypeMismatch { notification: self.name().into(), expected: type_name::<T>() }))
+    }
 }
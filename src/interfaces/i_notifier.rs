@@ -1,6 +1,8 @@
 use std::any::Any;
 use std::sync::{Arc};
 use crate::interfaces::IFacade;
+#[cfg(feature = "async")]
+use crate::interfaces::BoxFuture;
 
 /// The trait definition for a PureMVC `INotifier`.
 ///
@@ -50,4 +52,43 @@ pub trait INotifier: Any + Send + Sync {
     /// * `body` - The body of the `Notification` (optional).
     /// * `type_` - The type of the `Notification` (optional).
     fn send_notification(&self, name: &str, body: Option<Arc<dyn Any + Send + Sync>>, type_: Option<&str>);
+
+    /// Send a `Notification` and await its delivery.
+    ///
+    /// The awaitable counterpart of `send_notification`: `Observer`s are notified in turn, and
+    /// asynchronous `Observer`s are awaited instead of blocking the calling thread.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the `Notification` to send.
+    /// * `body` - The body of the `Notification` (optional).
+    /// * `type_` - The type of the `Notification` (optional).
+    ///
+    /// # Returns
+    /// A future completing when every `Observer` has handled the `Notification`.
+    #[cfg(feature = "async")]
+    fn send_notification_async(&self, name: &str, body: Option<Arc<dyn Any + Send + Sync>>, type_: Option<&str>) -> BoxFuture {
+        self.facade().send_notification_async(name, body, type_)
+    }
+}
+
+/// Typed `Notification` sending, available on every `INotifier`, including `dyn IFacade`.
+///
+/// See `INotifier`, `TypedNotification`
+pub trait ITypedNotifier {
+    /// Send a `Notification` with a typed body.
+    ///
+    /// Receivers retrieve the body with `INotification::body_as::<T>()` or
+    /// `TypedNotification::<T>::try_from`.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the `Notification` to send.
+    /// * `body` - The body of the `Notification`.
+    /// * `type_` - The type of the `Notification` (optional).
+    fn send_typed_notification<T: Any + Send + Sync>(&self, name: &str, body: T, type_: Option<&str>);
+}
+
+impl<N: INotifier + ?Sized> ITypedNotifier for N {
+    fn send_typed_notification<T: Any + Send + Sync>(&self, name: &str, body: T, type_: Option<&str>) {
+        self.send_notification(name, Some(Arc::new(body)), type_);
+    }
 }
@@ -1,7 +1,19 @@
 use std::any::Any;
+#[cfg(feature = "async")]
+use std::future::Future;
+#[cfg(feature = "async")]
+use std::pin::Pin;
 use std::sync::{Arc};
 use crate::interfaces::INotification;
 
+/// A boxed, sendable future returned by asynchronous notification delivery.
+#[cfg(feature = "async")]
+pub type BoxFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// An asynchronous notification callback method.
+#[cfg(feature = "async")]
+pub type AsyncNotifyFn = Arc<dyn Fn(Arc<dyn INotification>) -> BoxFuture + Send + Sync>;
+
 /// The trait definition for a PureMVC MultiCore `IObserver`.
 ///
 /// In PureMVC, an `IObserver` implementor assumes these responsibilities:
@@ -55,6 +67,22 @@ pub trait IObserver: Any + Send + Sync {
     /// * `notification` - The `Notification` to pass to the interested object's notification method.
     fn notify_observer(&self, notification: &Arc<dyn INotification>);
 
+    /// Notify the interested object, returning a future that completes once it has handled the
+    /// `Notification`.
+    ///
+    /// The default implementation calls `notify_observer` and returns a ready future.
+    ///
+    /// # Arguments
+    /// * `notification` - The `Notification` to pass to the interested object's notification method.
+    ///
+    /// # Returns
+    /// A future completing when the interested object has handled the `Notification`.
+    #[cfg(feature = "async")]
+    fn notify_observer_async(&self, notification: &Arc<dyn INotification>) -> BoxFuture {
+        self.notify_observer(notification);
+        Box::pin(std::future::ready(()))
+    }
+
     /// Compare a given object to the notification context.
     ///
     /// # Arguments
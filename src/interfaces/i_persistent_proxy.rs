@@ -0,0 +1,37 @@
+use serde_json::Value;
+use crate::Error;
+use crate::interfaces::IProxy;
+
+/// The trait definition for a PureMVC MultiCore `IPersistentProxy`.
+///
+/// An `IProxy` opts in to persistence by implementing `IPersistentProxy` and returning itself
+/// from `IProxy::as_persistent`. When the `IModel` it is registered with has an `IStore`, its
+/// data is restored from the store on registration, before `on_register` is called, and saved
+/// to the store when it is removed or when `IModel::save_proxies` is called.
+///
+/// See `IProxy`, `IStore`, `PersistentProxy`
+pub trait IPersistentProxy: IProxy {
+    /// Get the key the data is saved under.
+    ///
+    /// # Returns
+    /// The key in the `IStore`. The default implementation returns the name of the `IProxy`.
+    fn persistence_key(&self) -> String {
+        self.name().into()
+    }
+
+    /// Convert the data to its serializable form.
+    ///
+    /// # Returns
+    /// The serializable data, `None` if there is nothing to save, or `Error::Serialization` if
+    /// the data cannot be serialized.
+    fn save_data(&self) -> Result<Option<Value>, Error>;
+
+    /// Replace the data with data converted back from its serializable form.
+    ///
+    /// # Arguments
+    /// * `data` - The serializable data, as returned by `save_data`.
+    ///
+    /// # Returns
+    /// `Error::Serialization` if the data is not valid for the `IProxy`.
+    fn restore_data(&mut self, data: Value) -> Result<(), Error>;
+}
@@ -1,6 +1,16 @@
 use std::any::Any;
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
 use crate::interfaces::INotifier;
+#[cfg(feature = "persistence")]
+use crate::interfaces::IPersistentProxy;
+
+/// A factory creating the `IProxy` instance of a lazily registered `Proxy`.
+///
+/// The factory is called on the first retrieval of the `Proxy`, and again only if the instance
+/// it created could not be registered. It runs without the `IModel`'s locks held, so it may
+/// retrieve other `Proxy`s; retrieving its own `Proxy` fails with `Error::DependencyCycle`.
+pub type ProxyFactory = Arc<dyn Fn() -> Arc<RwLock<dyn IProxy>> + Send + Sync>;
 
 /// The trait definition for a PureMVC MultiCore `IProxy`.
 ///
@@ -36,6 +46,28 @@ pub trait IProxy: INotifier {
     /// * `data` - The Data Object this `Proxy` will tend.
     fn set_data(&mut self, data: Option<Arc<dyn Any + Send + Sync>>);
 
+    /// Get the names of the `Proxy` instances this `Proxy` depends on.
+    ///
+    /// The `IModel` resolves them when this `Proxy` is registered, and hands them to
+    /// `set_dependencies` before calling `on_register`. A `Proxy` whose dependencies are not all
+    /// registered is not registered either.
+    ///
+    /// # Returns
+    /// The names of the dependencies. The default implementation returns an empty list.
+    fn dependencies(&self) -> Vec<String> {
+        vec![]
+    }
+
+    /// Receive the resolved dependencies of the `Proxy`.
+    ///
+    /// Called by the `IModel` before `on_register`. The default implementation ignores them.
+    ///
+    /// # Arguments
+    /// * `dependencies` - Each name listed by `dependencies` mapped to the registered `Proxy`.
+    fn set_dependencies(&mut self, dependencies: HashMap<String, Arc<RwLock<dyn IProxy>>>) {
+        let _ = dependencies;
+    }
+
     /// Called by the `IModel` when the `Proxy` is registered.
     fn on_register(&mut self) {
 
@@ -51,4 +83,39 @@ pub trait IProxy: INotifier {
     /// # Returns
     /// The `Proxy` instance as a mutable `Any` reference.
     fn as_any(&mut self) -> &mut dyn Any;
+
+    /// Get the `Proxy` as an `IPersistentProxy`, if it opts in to persistence.
+    ///
+    /// # Returns
+    /// The `Proxy` instance as an `IPersistentProxy`. The default implementation returns `None`.
+    #[cfg(feature = "persistence")]
+    fn as_persistent(&mut self) -> Option<&mut dyn IPersistentProxy> {
+        None
+    }
+}
+
+impl dyn IProxy {
+    /// Get the `Proxy` as a dynamic `Any` type, without requiring mutable access.
+    ///
+    /// # Returns
+    /// The `Proxy` instance as an `Any` reference.
+    pub fn as_any_ref(&self) -> &dyn Any {
+        self
+    }
+
+    /// Get a reference to the `Proxy` as a given concrete type.
+    ///
+    /// # Returns
+    /// The `Proxy` instance, or `None` if it is not a `T`.
+    pub fn downcast_ref<T: IProxy>(&self) -> Option<&T> {
+        self.as_any_ref().downcast_ref::<T>()
+    }
+
+    /// Get a mutable reference to the `Proxy` as a given concrete type.
+    ///
+    /// # Returns
+    /// The `Proxy` instance, or `None` if it is not a `T`.
+    pub fn downcast_mut<T: IProxy>(&mut self) -> Option<&mut T> {
+        (self as &mut dyn Any).downcast_mut::<T>()
+    }
 }
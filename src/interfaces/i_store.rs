@@ -0,0 +1,40 @@
+use serde_json::Value;
+use crate::Error;
+
+/// The trait definition for a PureMVC MultiCore `IStore`.
+///
+/// An `IStore` keeps the data of `IPersistentProxy` instances between runs of an application,
+/// by key. Set one on an `IModel` with `set_store` to have its `IPersistentProxy`s restored when
+/// they are registered and saved when they are removed.
+///
+/// See `IPersistentProxy`, `IModel`, `FileStore`
+pub trait IStore: Send + Sync {
+    /// Load the data saved under a key.
+    ///
+    /// # Arguments
+    /// * `key` - The key the data was saved under.
+    ///
+    /// # Returns
+    /// The data, `None` if nothing is saved under the key, or `Error::Persistence` if it cannot
+    /// be read.
+    fn load(&self, key: &str) -> Result<Option<Value>, Error>;
+
+    /// Save data under a key, replacing any data saved under it.
+    ///
+    /// # Arguments
+    /// * `key` - The key to save the data under.
+    /// * `data` - The data to save.
+    ///
+    /// # Returns
+    /// `Error::Persistence` if the data cannot be written.
+    fn save(&self, key: &str, data: &Value) -> Result<(), Error>;
+
+    /// Delete the data saved under a key, if any.
+    ///
+    /// # Arguments
+    /// * `key` - The key the data was saved under.
+    ///
+    /// # Returns
+    /// `Error::Persistence` if the data cannot be deleted.
+    fn remove(&self, key: &str) -> Result<(), Error>;
+}
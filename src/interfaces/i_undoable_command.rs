@@ -0,0 +1,20 @@
+use std::sync::Arc;
+use crate::interfaces::ICommand;
+
+/// A factory creating a new instance of an `IUndoableCommand` each time it is called.
+pub type UndoableCommandFactory = Arc<dyn Fn() -> Box<dyn IUndoableCommand + Send + Sync> + Send + Sync>;
+
+/// The trait definition for a PureMVC MultiCore `IUndoableCommand`.
+///
+/// An `IUndoableCommand` can revert the changes made by its `execute`, then apply them again.
+/// Once executed, it is kept by a `CommandHistoryProxy`, which calls `undo` and `redo` as the
+/// user steps back and forth through the history.
+///
+/// See `ICommand`, `UndoableCommand`, `CommandHistoryProxy`
+pub trait IUndoableCommand: ICommand {
+    /// Revert the changes made by the last `execute` or `redo`.
+    fn undo(&mut self);
+
+    /// Apply again the changes reverted by the last `undo`.
+    fn redo(&mut self);
+}
@@ -1,6 +1,38 @@
 use std::any::Any;
+use std::collections::BTreeMap;
 use std::sync::{Arc, RwLock};
-use crate::interfaces::{IMediator, INotification, IObserver};
+use crate::Error;
+use crate::interfaces::{IInterceptor, IMediator, INotification, IObserver};
+#[cfg(feature = "async")]
+use crate::interfaces::BoxFuture;
+
+/// How an `IView` delivers `Notification`s sent while it is already notifying `Observer`s.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DispatchMode {
+    /// Every `Notification` is delivered as soon as it is sent, nesting inside the delivery of
+    /// the `Notification` being handled.
+    #[default]
+    Immediate,
+    /// A `Notification` sent while another is being delivered is queued, and delivered once the
+    /// current `Notification` has been handled by all of its `Observer`s. Queued `Notification`s
+    /// are delivered in the order in which they were sent. Actors relying on nested delivery,
+    /// such as the guards of a `StateMachine`, do not work in this mode.
+    Deferred,
+    /// `notify_observers` returns immediately, and each `Observer` is notified on one of a pool
+    /// of worker threads, so long-running `Observer`s and the `ICommand`s they execute do not
+    /// block the sender.
+    ///
+    /// `Observer`s are handed to the workers in priority order, but may be notified concurrently
+    /// and in any order. If `serialize` is set, each `Observer` is always notified on the same
+    /// worker, so it handles one `Notification` at a time, in the order they were sent.
+    #[cfg(feature = "thread-pool")]
+    Background {
+        /// The number of worker threads, at least one.
+        workers: usize,
+        /// Whether each `Observer` handles its `Notification`s one at a time, in order.
+        serialize: bool,
+    },
+}
 
 /// The trait definition for a PureMVC MultiCore `IView`.
 ///
@@ -28,6 +60,22 @@ pub trait IView: Any + Sync + Send {
     /// * `observer` - The `Observer` to register.
     fn register_observer(&self, notification_name: &str, observer: Arc<dyn IObserver>);
 
+    /// Register an `Observer` to be notified of `Notification`s with a given name, ahead of
+    /// `Observer`s registered with a lower priority.
+    ///
+    /// `Observer`s with equal priorities are notified in the order in which they were
+    /// registered, and `register_observer` registers with priority `0`. The default
+    /// implementation ignores the priority and calls `register_observer`.
+    ///
+    /// # Arguments
+    /// * `notification_name` - The name of the `Notification` to notify this `Observer` of.
+    /// * `observer` - The `Observer` to register.
+    /// * `priority` - The notification priority of the `Observer`, highest first.
+    fn register_observer_with_priority(&self, notification_name: &str, observer: Arc<dyn IObserver>, priority: i32) {
+        let _ = priority;
+        self.register_observer(notification_name, observer);
+    }
+
     /// Remove an `Observer` from the list for a given `Notification` name.
     ///
     /// # Arguments
@@ -35,15 +83,79 @@ pub trait IView: Any + Sync + Send {
     /// * `context` - Remove `Observer`s with this object as the notification context.
     fn remove_observer(&self, notification_name: &str, context: Arc<dyn Any + Send + Sync>);
 
+    /// Count the `Observer`s registered for each `Notification` name.
+    ///
+    /// # Returns
+    /// The number of `Observer`s registered for each `Notification` name. The default
+    /// implementation returns an empty map.
+    fn observer_counts(&self) -> BTreeMap<String, usize> {
+        BTreeMap::new()
+    }
+
     /// Notify the `Observer`s for a particular `Notification`.
     ///
     /// All previously attached `Observer`s for this `Notification`'s list are notified and
-    /// are passed a reference to the `Notification` in the order in which they were registered.
+    /// are passed a reference to the `Notification` in priority order, then in the order in which
+    /// they were registered.
     ///
     /// # Arguments
     /// * `notification` - The `Notification` to notify `Observer`s of.
     fn notify_observers(&self, notification: &Arc<dyn INotification>);
 
+    /// Get the `DispatchMode` of the `IView`.
+    ///
+    /// # Returns
+    /// How `Notification`s sent during the delivery of another are delivered. The default
+    /// implementation returns `DispatchMode::Immediate`.
+    fn dispatch_mode(&self) -> DispatchMode {
+        DispatchMode::Immediate
+    }
+
+    /// Set the `DispatchMode` of the `IView`.
+    ///
+    /// The default implementation ignores the `DispatchMode`.
+    ///
+    /// # Arguments
+    /// * `mode` - How `Notification`s sent during the delivery of another are delivered.
+    fn set_dispatch_mode(&self, mode: DispatchMode) {
+        let _ = mode;
+    }
+
+    /// Add an `IInterceptor` to the `IView`.
+    ///
+    /// The default implementation ignores the `IInterceptor`.
+    ///
+    /// # Arguments
+    /// * `interceptor` - The `IInterceptor` to call around the delivery of each `Notification`.
+    fn add_interceptor(&self, interceptor: Arc<dyn IInterceptor>) {
+        let _ = interceptor;
+    }
+
+    /// Remove an `IInterceptor` from the `IView`.
+    ///
+    /// # Arguments
+    /// * `interceptor` - The `IInterceptor` previously added.
+    fn remove_interceptor(&self, interceptor: &Arc<dyn IInterceptor>) {
+        let _ = interceptor;
+    }
+
+    /// Notify the `Observer`s for a particular `Notification`, awaiting each one in turn.
+    ///
+    /// The `Observer`s are notified in the same order as by `notify_observers`, each through
+    /// its `notify_observer_async` method. The default implementation calls `notify_observers`
+    /// and returns a ready future.
+    ///
+    /// # Arguments
+    /// * `notification` - The `Notification` to notify `Observer`s of.
+    ///
+    /// # Returns
+    /// A future completing when every `Observer` has handled the `Notification`.
+    #[cfg(feature = "async")]
+    fn notify_observers_async(&self, notification: &Arc<dyn INotification>) -> BoxFuture {
+        self.notify_observers(notification);
+        Box::pin(std::future::ready(()))
+    }
+
     /// Register a `Mediator` instance with the `IView`.
     ///
     /// Registers the `Mediator` so that it can be retrieved by name, and interrogates the
@@ -84,4 +196,69 @@ pub trait IView: Any + Sync + Send {
     /// # Returns
     /// The `Mediator` that was removed from this core's `IView`.
     fn remove_mediator(&self, mediator_name: &str) -> Option<Arc<RwLock<dyn IMediator>>>;
+
+    /// List the names of the `Mediator` instances registered with the `IView`.
+    ///
+    /// # Returns
+    /// The names of the registered `Mediator` instances, sorted. The default implementation
+    /// returns an empty list, in which case `View::remove_view` cannot call the `on_remove` of
+    /// the registered `Mediator` instances; implement it to have them torn down.
+    fn mediator_names(&self) -> Vec<String> {
+        vec![]
+    }
+
+    /// Register a `Mediator` instance with the `IView`, failing if the name is taken.
+    ///
+    /// # Arguments
+    /// * `mediator` - A reference to the `Mediator` instance.
+    ///
+    /// # Returns
+    /// `Error::AlreadyRegistered` if a `Mediator` is registered with the same name, or
+    /// `Error::LockPoisoned` if a lock is poisoned.
+    fn try_register_mediator(&self, mediator: Arc<RwLock<dyn IMediator>>) -> Result<(), Error> {
+        let name = mediator.read().map_err(|_| Error::LockPoisoned { lock: "Mediator" })?.name().to_string();
+        if self.try_has_mediator(&name)? {
+            return Err(Error::AlreadyRegistered { kind: "Mediator", name });
+        }
+        self.register_mediator(mediator);
+        Ok(())
+    }
+
+    /// Retrieve a `Mediator` from the `IView`, failing if it is not registered.
+    ///
+    /// # Arguments
+    /// * `mediator_name` - The name of the `Mediator` instance to retrieve.
+    ///
+    /// # Returns
+    /// The `Mediator` instance, `Error::NotFound` if none is registered with the given name, or
+    /// `Error::LockPoisoned` if a lock is poisoned.
+    fn try_retrieve_mediator(&self, mediator_name: &str) -> Result<Arc<RwLock<dyn IMediator>>, Error> {
+        self.retrieve_mediator(mediator_name)
+            .ok_or_else(|| Error::NotFound { kind: "Mediator", name: mediator_name.into() })
+    }
+
+    /// Check if a `Mediator` is registered with the `IView`.
+    ///
+    /// # Arguments
+    /// * `mediator_name` - The name of the `Mediator` you're looking for.
+    ///
+    /// # Returns
+    /// Whether a `Mediator` is registered with the given name, or `Error::LockPoisoned` if a
+    /// lock is poisoned.
+    fn try_has_mediator(&self, mediator_name: &str) -> Result<bool, Error> {
+        Ok(self.has_mediator(mediator_name))
+    }
+
+    /// Remove a `Mediator` from the `IView`, failing if it is not registered.
+    ///
+    /// # Arguments
+    /// * `mediator_name` - Name of the `Mediator` instance to be removed.
+    ///
+    /// # Returns
+    /// The removed `Mediator`, `Error::NotFound` if none is registered with the given name, or
+    /// `Error::LockPoisoned` if a lock is poisoned.
+    fn try_remove_mediator(&self, mediator_name: &str) -> Result<Arc<RwLock<dyn IMediator>>, Error> {
+        self.remove_mediator(mediator_name)
+            .ok_or_else(|| Error::NotFound { kind: "Mediator", name: mediator_name.into() })
+    }
 }
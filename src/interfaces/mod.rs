@@ -1,21 +1,37 @@
+mod i_async_command;
 mod i_command;
 mod i_controller;
 mod i_facade;
+mod i_interceptor;
 mod i_mediator;
 mod i_model;
 mod i_notification;
 mod i_notifier;
 mod i_observer;
+#[cfg(feature = "persistence")]
+mod i_persistent_proxy;
 mod i_proxy;
+#[cfg(feature = "persistence")]
+mod i_store;
+mod i_undoable_command;
 mod i_view;
 
 pub use i_controller::IController;
 pub use i_model::IModel;
-pub use i_view::IView;
-pub use i_command::ICommand;
-pub use i_facade::IFacade;
+pub use i_view::{DispatchMode, IView};
+pub use i_command::{CommandFactory, ICommand};
+pub use i_async_command::IAsyncCommand;
+pub use i_facade::{IFacade, ShutdownHook};
+pub use i_interceptor::IInterceptor;
 pub use i_mediator::IMediator;
 pub use i_notification::INotification;
-pub use i_notifier::INotifier;
+pub use i_notifier::{INotifier, ITypedNotifier};
 pub use i_observer::IObserver;
-pub use i_proxy::IProxy;
+#[cfg(feature = "async")]
+pub use i_observer::{AsyncNotifyFn, BoxFuture};
+pub use i_proxy::{IProxy, ProxyFactory};
+#[cfg(feature = "persistence")]
+pub use i_persistent_proxy::IPersistentProxy;
+#[cfg(feature = "persistence")]
+pub use i_store::IStore;
+pub use i_undoable_command::{IUndoableCommand, UndoableCommandFactory};
@@ -1,3 +1,7 @@
 pub mod core;
 pub mod interfaces;
 pub mod patterns;
+pub mod utilities;
+
+mod error;
+pub use error::Error;
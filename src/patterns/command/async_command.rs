@@ -0,0 +1,110 @@
+use std::any::Any;
+use std::sync::{Arc};
+use crate::interfaces::{IAsyncCommand, ICommand, IFacade, INotification, INotifier};
+use crate::patterns::SimpleCommand;
+
+/// A base `IAsyncCommand` implementation.
+///
+/// Your implementation should override the `execute` method, start its long-running work, and
+/// call `command_complete` when the work is done. If the work continues on another thread, take
+/// the callback with `on_complete` and move it into that thread, as the `AsyncCommand` itself is
+/// dropped once `execute` returns.
+///
+/// See `IAsyncCommand`, `AsyncMacroCommand`, `SimpleCommand`
+pub struct AsyncCommand {
+    /// The underlying `SimpleCommand` instance used for `INotifier` implementation.
+    command: SimpleCommand,
+    /// The callback to invoke when the command completes.
+    on_complete: Option<Arc<dyn Fn() + Send + Sync>>,
+}
+
+impl AsyncCommand {
+    /// Construct a new `AsyncCommand`.
+    pub fn new() -> Self {
+        Self {
+            command: SimpleCommand::new(),
+            on_complete: None
+        }
+    }
+
+    /// Get the completion callback.
+    ///
+    /// # Returns
+    /// The callback to invoke when the command's work is done, if one has been set.
+    pub fn on_complete(&self) -> Option<Arc<dyn Fn() + Send + Sync>> {
+        self.on_complete.clone()
+    }
+
+    /// Notify the owner of this `AsyncCommand` that its work is done.
+    ///
+    /// Invokes the completion callback, if one has been set.
+    pub fn command_complete(&self) {
+        if let Some(on_complete) = &self.on_complete {
+            on_complete();
+        }
+    }
+}
+
+impl Default for AsyncCommand {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IAsyncCommand for AsyncCommand {
+    /// Set the callback to invoke when the `AsyncCommand` completes.
+    ///
+    /// # Arguments
+    /// * `on_complete` - The callback to invoke once the `AsyncCommand`'s work is done (optional).
+    fn set_on_complete(&mut self, on_complete: Option<Arc<dyn Fn() + Send + Sync>>) {
+        self.on_complete = on_complete;
+    }
+}
+
+impl ICommand for AsyncCommand {
+    /// Respond to the `Notification` that triggered this `Command`.
+    ///
+    /// Start the long-running work, then call `command_complete` once it is done.
+    ///
+    /// # Arguments
+    /// * `notification` - The `Notification` object that triggered the execution of this `Command`.
+    fn execute(&mut self, _notification: &Arc<dyn INotification>) {
+
+    }
+}
+
+impl INotifier for AsyncCommand {
+    /// Get the Multiton key for this `AsyncCommand`.
+    ///
+    /// # Returns
+    /// The Multiton key of the `AsyncCommand`.
+    fn key(&self) -> &str {
+        self.command.key()
+    }
+
+    /// Get the `IFacade` instance associated with this `AsyncCommand`.
+    ///
+    /// # Returns
+    /// The `IFacade` instance.
+    fn facade(&self) -> Arc<dyn IFacade> {
+        self.command.facade()
+    }
+
+    /// Initialize this `AsyncCommand` instance.
+    ///
+    /// # Arguments
+    /// * `key` - The Multiton key for this `AsyncCommand`.
+    fn initialize_notifier(&mut self, key: &str) {
+        self.command.initialize_notifier(key);
+    }
+
+    /// Send a `Notification`.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the `Notification` to send.
+    /// * `body` - The body of the `Notification` (optional).
+    /// * `type_` - The type of the `Notification` (optional).
+    fn send_notification(&self, name: &str, body: Option<Arc<dyn Any + Send + Sync>>, type_: Option<&str>) {
+        self.command.send_notification(name, body, type_);
+    }
+}
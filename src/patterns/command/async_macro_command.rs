@@ -0,0 +1,213 @@
+use std::any::Any;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use crate::interfaces::{IAsyncCommand, ICommand, IFacade, INotification, INotifier};
+use crate::patterns::SimpleCommand;
+
+/// A SubCommand factory of an `AsyncMacroCommand`.
+enum SubCommand {
+    /// A factory for an `ICommand` that is done when `execute` returns.
+    Sync(Box<dyn Fn() -> Box<dyn ICommand + Send + Sync> + Send + Sync>),
+    /// A factory for an `IAsyncCommand` that signals its own completion.
+    Async(Box<dyn Fn() -> Box<dyn IAsyncCommand + Send + Sync> + Send + Sync>),
+}
+
+/// The state of an `AsyncMacroCommand` execution, shared with the completion callbacks of its
+/// asynchronous SubCommands.
+struct Sequence {
+    /// The Multiton key passed on to each SubCommand.
+    key: String,
+    /// The `Notification` passed on to each SubCommand.
+    notification: Arc<dyn INotification>,
+    /// The SubCommands left to execute.
+    sub_commands: VecDeque<SubCommand>,
+    /// The callback to invoke once every SubCommand has completed.
+    on_complete: Option<Arc<dyn Fn() + Send + Sync>>,
+}
+
+/// A base `IAsyncCommand` implementation that executes other `ICommand`s one after another,
+/// waiting for each asynchronous SubCommand to complete before starting the next.
+///
+/// SubCommands added with `add_sub_command` are considered complete when their `execute`
+/// returns. SubCommands added with `add_async_sub_command` are considered complete when they
+/// invoke their completion callback, which may happen on another thread; the next SubCommand is
+/// then executed on that thread. Once the last SubCommand completes, the `AsyncMacroCommand`
+/// invokes its own completion callback, so it can itself be nested in another
+/// `AsyncMacroCommand`.
+///
+/// Like `MacroCommand`, your implementation should not override `execute`, but instead, should
+/// override the `initialize_macro_command` method, adding each SubCommand to be executed.
+///
+/// See `IAsyncCommand`, `AsyncCommand`, `MacroCommand`
+pub struct AsyncMacroCommand {
+    /// The underlying `SimpleCommand` instance used for `INotifier` implementation.
+    command: SimpleCommand,
+    /// The list of SubCommand factories.
+    sub_commands: VecDeque<SubCommand>,
+    /// The callback to invoke once every SubCommand has completed.
+    on_complete: Option<Arc<dyn Fn() + Send + Sync>>,
+}
+
+impl AsyncMacroCommand {
+    /// Construct a new `AsyncMacroCommand`.
+    ///
+    /// You should not need to define a custom constructor; instead, override the
+    /// `initialize_macro_command` method to initialize the SubCommand list.
+    pub fn new() -> Self {
+        Self {
+            command: SimpleCommand::new(),
+            sub_commands: VecDeque::new(),
+            on_complete: None
+        }
+    }
+
+    /// Initialize the `AsyncMacroCommand`.
+    ///
+    /// In your implementation, override this method to initialize the `AsyncMacroCommand`'s
+    /// SubCommand list by calling `add_sub_command` and `add_async_sub_command`.
+    pub fn initialize_macro_command(&mut self) {
+
+    }
+
+    /// Add a SubCommand that is complete when its `execute` returns.
+    ///
+    /// The SubCommands will be called in First In/First Out (FIFO) order.
+    ///
+    /// # Arguments
+    /// * `factory` - A function that constructs an instance of a `Command`.
+    pub fn add_sub_command<T: ICommand + Send + Sync>(&mut self, factory: fn() -> T) {
+        self.sub_commands.push_back(SubCommand::Sync(Box::new(move || Box::new(factory()))));
+    }
+
+    /// Add a SubCommand that signals its own completion.
+    ///
+    /// The SubCommands will be called in First In/First Out (FIFO) order.
+    ///
+    /// # Arguments
+    /// * `factory` - A function that constructs an instance of an `IAsyncCommand`.
+    pub fn add_async_sub_command<T: IAsyncCommand + Send + Sync>(&mut self, factory: fn() -> T) {
+        self.sub_commands.push_back(SubCommand::Async(Box::new(move || Box::new(factory()))));
+    }
+
+    /// Execute SubCommands until one is asynchronous or none are left.
+    ///
+    /// An asynchronous SubCommand's completion callback resumes the sequence. When no
+    /// SubCommands are left, the sequence's completion callback is invoked.
+    ///
+    /// # Arguments
+    /// * `sequence` - The execution state.
+    fn next_command(sequence: &Arc<Mutex<Sequence>>) {
+        loop {
+            let (sub_command, key, notification) = {
+                let mut guard = sequence.lock().unwrap();
+                match guard.sub_commands.pop_front() {
+                    Some(sub_command) => (sub_command, guard.key.clone(), Arc::clone(&guard.notification)),
+                    None => {
+                        let on_complete = guard.on_complete.take();
+                        drop(guard);
+                        if let Some(on_complete) = on_complete {
+                            on_complete();
+                        }
+                        return;
+                    }
+                }
+            };
+
+            match sub_command {
+                SubCommand::Sync(factory) => {
+                    let mut command = factory();
+                    command.initialize_notifier(&key);
+                    command.execute(&notification);
+                }
+                SubCommand::Async(factory) => {
+                    let mut command = factory();
+                    command.initialize_notifier(&key);
+                    command.set_on_complete(Some({
+                        let sequence = Arc::clone(sequence);
+                        let completed = AtomicBool::new(false);
+                        Arc::new(move || {
+                            if !completed.swap(true, Ordering::SeqCst) {
+                                Self::next_command(&sequence);
+                            }
+                        })
+                    }));
+                    command.execute(&notification);
+                    return;
+                }
+            }
+        }
+    }
+}
+
+impl Default for AsyncMacroCommand {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IAsyncCommand for AsyncMacroCommand {
+    /// Set the callback to invoke once every SubCommand has completed.
+    ///
+    /// # Arguments
+    /// * `on_complete` - The callback to invoke once the `AsyncMacroCommand`'s work is done (optional).
+    fn set_on_complete(&mut self, on_complete: Option<Arc<dyn Fn() + Send + Sync>>) {
+        self.on_complete = on_complete;
+    }
+}
+
+impl ICommand for AsyncMacroCommand {
+    /// Execute this `AsyncMacroCommand`'s SubCommands.
+    ///
+    /// The SubCommands will be called in First In/First Out (FIFO) order, each one after the
+    /// previous one has completed. This method returns as soon as the first asynchronous
+    /// SubCommand has been started.
+    ///
+    /// # Arguments
+    /// * `notification` - The `Notification` object to be passed to each SubCommand.
+    fn execute(&mut self, notification: &Arc<dyn INotification>) {
+        let sequence = Arc::new(Mutex::new(Sequence {
+            key: self.command.key().into(),
+            notification: Arc::clone(notification),
+            sub_commands: std::mem::take(&mut self.sub_commands),
+            on_complete: self.on_complete.take(),
+        }));
+        Self::next_command(&sequence);
+    }
+}
+
+impl INotifier for AsyncMacroCommand {
+    /// Get the Multiton key for this `AsyncMacroCommand`.
+    ///
+    /// # Returns
+    /// The Multiton key of the `AsyncMacroCommand`.
+    fn key(&self) -> &str {
+        self.command.key()
+    }
+
+    /// Get the `IFacade` instance associated with this `AsyncMacroCommand`.
+    ///
+    /// # Returns
+    /// The `IFacade` instance.
+    fn facade(&self) -> Arc<dyn IFacade> {
+        self.command.facade()
+    }
+
+    /// Initialize this `AsyncMacroCommand` instance.
+    ///
+    /// # Arguments
+    /// * `key` - The Multiton key for this `AsyncMacroCommand`.
+    fn initialize_notifier(&mut self, key: &str) {
+        self.command.initialize_notifier(key);
+    }
+
+    /// Send a `Notification`.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the `Notification` to send.
+    /// * `body` - The body of the `Notification` (optional).
+    /// * `type_` - The type of the `Notification` (optional).
+    fn send_notification(&self, name: &str, body: Option<Arc<dyn Any + Send + Sync>>, type_: Option<&str>) {
+        self.command.send_notification(name, body, type_);
+    }
+}
@@ -3,3 +3,12 @@ pub use simple_command::SimpleCommand;
 
 mod macro_command;
 pub use macro_command::MacroCommand;
+
+mod async_command;
+pub use async_command::AsyncCommand;
+
+mod async_macro_command;
+pub use async_macro_command::AsyncMacroCommand;
+
+mod undoable_command;
+pub use undoable_command::UndoableCommand;
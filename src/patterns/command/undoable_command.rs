@@ -0,0 +1,107 @@
+use std::any::Any;
+use std::sync::{Arc};
+use crate::interfaces::{ICommand, IFacade, INotification, INotifier, IUndoableCommand};
+use crate::patterns::SimpleCommand;
+
+/// A base `IUndoableCommand` implementation.
+///
+/// Your implementation should override the `execute` method to apply its changes, keeping what
+/// it needs to revert them and apply them again, the `undo` method to revert them, and the
+/// `redo` method to apply them again. The base `undo` and `redo` do nothing; the base `execute`
+/// keeps the `Notification` that triggered the command, available from `notification` to
+/// implementations that redo by applying it again.
+///
+/// To have executed commands kept for undo, register them through
+/// `CommandHistoryProxy::recording`.
+///
+/// See `IUndoableCommand`, `CommandHistoryProxy`, `SimpleCommand`
+pub struct UndoableCommand {
+    /// The underlying `SimpleCommand` instance used for `INotifier` implementation.
+    command: SimpleCommand,
+    /// The `Notification` that triggered this command.
+    notification: Option<Arc<dyn INotification>>,
+}
+
+impl UndoableCommand {
+    /// Construct a new `UndoableCommand`.
+    pub fn new() -> Self {
+        Self {
+            command: SimpleCommand::new(),
+            notification: None
+        }
+    }
+
+    /// Get the `Notification` that triggered this command.
+    ///
+    /// # Returns
+    /// The `Notification` last executed, if the command has been executed.
+    pub fn notification(&self) -> Option<Arc<dyn INotification>> {
+        self.notification.clone()
+    }
+}
+
+impl Default for UndoableCommand {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IUndoableCommand for UndoableCommand {
+    /// Revert the changes made by the last `execute` or `redo`.
+    fn undo(&mut self) {
+
+    }
+
+    /// Apply again the changes reverted by the last `undo`.
+    fn redo(&mut self) {
+
+    }
+}
+
+impl ICommand for UndoableCommand {
+    /// Respond to the `Notification` that triggered this `Command`.
+    ///
+    /// Keeps the `Notification`, available from `notification`.
+    ///
+    /// # Arguments
+    /// * `notification` - The `Notification` object that triggered the execution of this `Command`.
+    fn execute(&mut self, notification: &Arc<dyn INotification>) {
+        self.notification = Some(Arc::clone(notification));
+    }
+}
+
+impl INotifier for UndoableCommand {
+    /// Get the Multiton key for this `UndoableCommand`.
+    ///
+    /// # Returns
+    /// The Multiton key of the `UndoableCommand`.
+    fn key(&self) -> &str {
+        self.command.key()
+    }
+
+    /// Get the `IFacade` instance associated with this `UndoableCommand`.
+    ///
+    /// # Returns
+    /// The `IFacade` instance.
+    fn facade(&self) -> Arc<dyn IFacade> {
+        self.command.facade()
+    }
+
+    /// Initialize this `UndoableCommand` instance.
+    ///
+    /// # Arguments
+    /// * `key` - The Multiton key for this `UndoableCommand`.
+    fn initialize_notifier(&mut self, key: &str) {
+        self.command.initialize_notifier(key);
+    }
+
+    /// Send a `Notification`.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the `Notification` to send.
+    /// * `body` - The body of the `Notification` (optional).
+    /// * `type_` - The type of the `Notification` (optional).
+    fn send_notification(&self, name: &str, body: Option<Arc<dyn Any + Send + Sync>>, type_: Option<&str>) {
+        self.command.send_notification(name, body, type_);
+    }
+}
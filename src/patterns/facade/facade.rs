@@ -1,9 +1,14 @@
 use std::any::Any;
 use std::collections::HashMap;
-use std::sync::{Arc, LazyLock, RwLock};
+use std::sync::{Arc, LazyLock, Mutex, RwLock};
 use crate::core::{Controller, Model, View};
-use crate::interfaces::{ICommand, IController, IFacade, IMediator, IModel, INotification, INotifier, IProxy, IView};
+use crate::interfaces::{CommandFactory, IController, IFacade, IInterceptor, IMediator, IModel, INotification, INotifier, IProxy, IView, ProxyFactory, ShutdownHook};
+use crate::Error;
 use crate::patterns::Notification;
+#[cfg(feature = "async")]
+use crate::interfaces::BoxFuture;
+#[cfg(feature = "persistence")]
+use crate::interfaces::IStore;
 
 /// The Multiton instance map for `Facade` instances.
 static INSTANCE_MAP: LazyLock<RwLock<HashMap<String, Arc<dyn IFacade>>>> = LazyLock::new(|| Default::default());
@@ -31,10 +36,18 @@ pub struct Facade {
     /// The `IModel` instance for this core.
     model: Arc<dyn IModel>,
     /// The `IView` instance for this core.
-    view: Arc<dyn IView>
+    view: Arc<dyn IView>,
+    /// The hooks to run when this core is removed.
+    shutdown_hooks: Mutex<Vec<ShutdownHook>>
 }
 
 impl Facade {
+    /// Sent by a core once it has been created and initialized, with the core's Multiton key as its body.
+    pub const CORE_CREATED: &'static str = "Facade/notes/coreCreated";
+
+    /// Sent by a core before it is removed, with the core's Multiton key as its body.
+    pub const CORE_REMOVED: &'static str = "Facade/notes/coreRemoved";
+
     /// Construct a new `Facade` instance.
     ///
     /// This `IFacade` implementation is a Multiton, so you should not call this constructor
@@ -47,12 +60,15 @@ impl Facade {
             key: key.into(),
             controller: Controller::get_instance(key, |k| Controller::new(k)),
             model: Model::get_instance(key, |k| Model::new(k)),
-            view: View::get_instance(key, |k| View::new(k))
+            view: View::get_instance(key, View::new),
+            shutdown_hooks: Mutex::new(Vec::new())
         }
     }
 
     /// Get or create a Multiton `IFacade` instance for the specified key.
     ///
+    /// A newly created `IFacade` is initialized, then sends `Facade::CORE_CREATED`.
+    ///
     /// # Arguments
     /// * `key` - The Multiton key for the `IFacade`.
     /// * `factory` - A function that creates a new `IFacade` instance for the given key.
@@ -60,14 +76,38 @@ impl Facade {
     /// # Returns
     /// The `IFacade` Multiton instance for the specified key.
     pub fn get_instance<T: IFacade>(key: &str, factory: impl Fn(&str) -> T) -> Arc<dyn IFacade> {
-        INSTANCE_MAP.write().unwrap()
-            .entry(key.into())
-            .or_insert_with(|| {
-                let instance = factory(key);
-                instance.initialize_facade();
-                Arc::new(instance)
-            })
-            .clone()
+        let (facade, created) = {
+            let mut map = INSTANCE_MAP.write().unwrap();
+            match map.get(key) {
+                Some(facade) => (Arc::clone(facade), false),
+                None => {
+                    let instance = factory(key);
+                    instance.initialize_facade();
+                    let facade: Arc<dyn IFacade> = Arc::new(instance);
+                    map.insert(key.into(), Arc::clone(&facade));
+                    (facade, true)
+                }
+            }
+        };
+
+        if created {
+            facade.send_notification(Facade::CORE_CREATED, Some(Arc::new(key.to_string())), None);
+        }
+        facade
+    }
+
+    /// Look up a Multiton `IFacade` instance without creating it.
+    ///
+    /// Unlike `get_instance`, this never recreates a Core that was removed, so it is the lookup to
+    /// use from threads and listeners that may outlive their Core.
+    ///
+    /// # Arguments
+    /// * `key` - The Multiton key for the `IFacade`.
+    ///
+    /// # Returns
+    /// The `IFacade` instance, or `None` if no Core is registered with the given `key`.
+    pub fn try_get_instance(key: &str) -> Option<Arc<dyn IFacade>> {
+        INSTANCE_MAP.read().ok().and_then(|map| map.get(key).cloned())
     }
 
     /// Check if a Core is registered.
@@ -81,15 +121,25 @@ impl Facade {
         INSTANCE_MAP.read().unwrap().contains_key(key)
     }
 
+    /// List the Multiton keys of the `IFacade` instances.
+    pub(crate) fn keys() -> Vec<String> {
+        INSTANCE_MAP.read().map(|map| map.keys().cloned().collect()).unwrap_or_default()
+    }
+
     /// Remove a Core.
     ///
-    /// Removes the `IModel`, `IView`, `IController`, and `IFacade` instances for the given key.
+    /// Shuts the Core's `IFacade` down, then removes the `IModel`, `IView`, `IController`, and
+    /// `IFacade` instances for the given key.
     ///
     /// # Arguments
     /// * `key` - The Multiton key of the Core to remove.
     pub fn remove_core(key: &str) {
-        Model::remove_model(key);
+        if let Some(facade) = Self::try_get_instance(key) {
+            facade.shutdown();
+        }
+
         View::remove_view(key);
+        Model::remove_model(key);
         Controller::remove_controller(key);
         INSTANCE_MAP.write().unwrap().remove(key);
     }
@@ -136,7 +186,7 @@ impl IFacade for Facade {
     /// # Arguments
     /// * `notification_name` - The name of the `Notification` to associate the `Command` with.
     /// * `factory` - A function that creates a new instance of a `Command`.
-    fn register_command(&self, notification_name: &str, factory: fn() -> Box<dyn ICommand + Send + Sync>) {
+    fn register_command(&self, notification_name: &str, factory: CommandFactory) {
         self.controller.register_command(notification_name, factory);
     }
 
@@ -159,6 +209,26 @@ impl IFacade for Facade {
         self.controller.remove_command(notification_name);
     }
 
+    /// Add a `Notification` to `Command` mapping alongside any existing mappings for the
+    /// `Notification`.
+    ///
+    /// # Arguments
+    /// * `notification_name` - The name of the `Notification` to associate the `Command` with.
+    /// * `factory` - A closure that creates a new instance of a `Command`.
+    /// * `priority` - The execution priority of the `Command`, highest first.
+    fn add_command(&self, notification_name: &str, factory: CommandFactory, priority: i32) {
+        self.controller.add_command(notification_name, factory, priority);
+    }
+
+    /// Remove a single `Notification` to `Command` mapping from the `IController`.
+    ///
+    /// # Arguments
+    /// * `notification_name` - The name of the `Notification` to remove the mapping for.
+    /// * `factory` - The factory the mapping was added with.
+    fn remove_command_factory(&self, notification_name: &str, factory: &CommandFactory) {
+        self.controller.remove_command_factory(notification_name, factory);
+    }
+
     /// Register a `Proxy` instance with the `IModel`.
     ///
     /// # Arguments
@@ -167,6 +237,19 @@ impl IFacade for Facade {
         self.model.register_proxy(proxy);
     }
 
+    /// Register several `Proxy` instances with the `IModel`, in dependency order.
+    ///
+    /// # Arguments
+    /// * `proxies` - The `Proxy` instances to register.
+    ///
+    /// # Returns
+    /// `Error::MissingDependency` if a dependency is neither in `proxies` nor registered,
+    /// `Error::DependencyCycle` if the dependencies form a cycle, or another `Error` if
+    /// registration fails.
+    fn register_proxies(&self, proxies: Vec<Arc<RwLock<dyn IProxy>>>) -> Result<(), Error> {
+        self.model.register_proxies(proxies)
+    }
+
     /// Retrieve a `Proxy` instance from the `IModel`.
     ///
     /// # Arguments
@@ -200,6 +283,17 @@ impl IFacade for Facade {
         self.model.remove_proxy(name)
     }
 
+    /// Register a `Proxy` lazily with the `IModel`, by name and factory.
+    ///
+    /// The factory is not called until the `Proxy` is first retrieved.
+    ///
+    /// # Arguments
+    /// * `proxy_name` - The name the `Proxy` will be registered with.
+    /// * `factory` - A closure that creates the `Proxy` instance.
+    fn register_proxy_factory(&self, proxy_name: &str, factory: ProxyFactory) {
+        self.model.register_proxy_factory(proxy_name, factory);
+    }
+
     /// Register a `Mediator` instance with the `IView`.
     ///
     /// Registers the `Mediator` so that it can be retrieved by name, and interrogates the
@@ -249,6 +343,140 @@ impl IFacade for Facade {
         self.view.remove_mediator(mediator_name)
     }
 
+    /// Register a `Notification` to `ICommand` mapping with the `IController`, failing if one already exists.
+    ///
+    /// # Arguments
+    /// * `notification_name` - The name of the `Notification` to associate the `ICommand` with.
+    /// * `factory` - A closure that creates a new instance of the `ICommand`.
+    ///
+    /// # Returns
+    /// `Error::AlreadyRegistered` if an `ICommand` is mapped to the `notification_name`, or
+    /// `Error::LockPoisoned` if a lock is poisoned.
+    fn try_register_command(&self, notification_name: &str, factory: CommandFactory) -> Result<(), Error> {
+        self.controller.try_register_command(notification_name, factory)
+    }
+
+    /// Check if an `ICommand` is registered for a given `Notification` name with the `IController`.
+    ///
+    /// # Arguments
+    /// * `notification_name` - The name of the `Notification`.
+    ///
+    /// # Returns
+    /// Whether an `ICommand` is mapped to the `notification_name`, or `Error::LockPoisoned` if a
+    /// lock is poisoned.
+    fn try_has_command(&self, notification_name: &str) -> Result<bool, Error> {
+        self.controller.try_has_command(notification_name)
+    }
+
+    /// Remove a `Notification` to `ICommand` mapping from the `IController`, failing if there is none.
+    ///
+    /// # Arguments
+    /// * `notification_name` - The name of the `Notification` to remove the `ICommand` mapping for.
+    ///
+    /// # Returns
+    /// `Error::NotFound` if no `ICommand` is mapped to the `notification_name`, or
+    /// `Error::LockPoisoned` if a lock is poisoned.
+    fn try_remove_command(&self, notification_name: &str) -> Result<(), Error> {
+        self.controller.try_remove_command(notification_name)
+    }
+
+    /// Register a `Proxy` instance with the `IModel`, failing if the name is taken.
+    ///
+    /// # Arguments
+    /// * `proxy` - An object reference to be held by the `IModel`.
+    ///
+    /// # Returns
+    /// `Error::AlreadyRegistered` if a `Proxy` is registered with the same name, or
+    /// `Error::LockPoisoned` if a lock is poisoned.
+    fn try_register_proxy(&self, proxy: Arc<RwLock<dyn IProxy>>) -> Result<(), Error> {
+        self.model.try_register_proxy(proxy)
+    }
+
+    /// Retrieve a `Proxy` instance from the `IModel`, failing if it is not registered.
+    ///
+    /// # Arguments
+    /// * `proxy_name` - The name of the `Proxy` instance to retrieve.
+    ///
+    /// # Returns
+    /// The `Proxy` instance, `Error::NotFound` if none is registered with the given name, or
+    /// `Error::LockPoisoned` if a lock is poisoned.
+    fn try_retrieve_proxy(&self, proxy_name: &str) -> Result<Arc<RwLock<dyn IProxy>>, Error> {
+        self.model.try_retrieve_proxy(proxy_name)
+    }
+
+    /// Check if a `Proxy` is registered with the `IModel`.
+    ///
+    /// # Arguments
+    /// * `proxy_name` - The name of the `Proxy` instance you're looking for.
+    ///
+    /// # Returns
+    /// Whether a `Proxy` is registered with the given name, or `Error::LockPoisoned` if a lock
+    /// is poisoned.
+    fn try_has_proxy(&self, proxy_name: &str) -> Result<bool, Error> {
+        self.model.try_has_proxy(proxy_name)
+    }
+
+    /// Remove a `Proxy` instance from the `IModel`, failing if it is not registered.
+    ///
+    /// # Arguments
+    /// * `proxy_name` - Name of the `Proxy` instance to be removed.
+    ///
+    /// # Returns
+    /// The removed `Proxy`, `None` if it was registered by factory and never instantiated,
+    /// `Error::NotFound` if none is registered with the given name, or `Error::LockPoisoned` if a
+    /// lock is poisoned.
+    fn try_remove_proxy(&self, proxy_name: &str) -> Result<Option<Arc<RwLock<dyn IProxy>>>, Error> {
+        self.model.try_remove_proxy(proxy_name)
+    }
+
+    /// Register a `Mediator` instance with the `IView`, failing if the name is taken.
+    ///
+    /// # Arguments
+    /// * `mediator` - A reference to the `Mediator` instance.
+    ///
+    /// # Returns
+    /// `Error::AlreadyRegistered` if a `Mediator` is registered with the same name, or
+    /// `Error::LockPoisoned` if a lock is poisoned.
+    fn try_register_mediator(&self, mediator: Arc<RwLock<dyn IMediator>>) -> Result<(), Error> {
+        self.view.try_register_mediator(mediator)
+    }
+
+    /// Retrieve a `Mediator` from the `IView`, failing if it is not registered.
+    ///
+    /// # Arguments
+    /// * `mediator_name` - The name of the `Mediator` instance to retrieve.
+    ///
+    /// # Returns
+    /// The `Mediator` instance, `Error::NotFound` if none is registered with the given name, or
+    /// `Error::LockPoisoned` if a lock is poisoned.
+    fn try_retrieve_mediator(&self, mediator_name: &str) -> Result<Arc<RwLock<dyn IMediator>>, Error> {
+        self.view.try_retrieve_mediator(mediator_name)
+    }
+
+    /// Check if a `Mediator` is registered with the `IView`.
+    ///
+    /// # Arguments
+    /// * `mediator_name` - The name of the `Mediator` you're looking for.
+    ///
+    /// # Returns
+    /// Whether a `Mediator` is registered with the given name, or `Error::LockPoisoned` if a
+    /// lock is poisoned.
+    fn try_has_mediator(&self, mediator_name: &str) -> Result<bool, Error> {
+        self.view.try_has_mediator(mediator_name)
+    }
+
+    /// Remove a `Mediator` from the `IView`, failing if it is not registered.
+    ///
+    /// # Arguments
+    /// * `mediator_name` - Name of the `Mediator` instance to be removed.
+    ///
+    /// # Returns
+    /// The removed `Mediator`, `Error::NotFound` if none is registered with the given name, or
+    /// `Error::LockPoisoned` if a lock is poisoned.
+    fn try_remove_mediator(&self, mediator_name: &str) -> Result<Arc<RwLock<dyn IMediator>>, Error> {
+        self.view.try_remove_mediator(mediator_name)
+    }
+
     /// Notify `Observer`s.
     ///
     /// This method allows you to send custom `Notification` instances using the `IFacade`.
@@ -261,6 +489,102 @@ impl IFacade for Facade {
     fn notify_observers(&self, notification: &Arc<dyn INotification>) {
         self.view.notify_observers(notification);
     }
+
+    /// Notify `Observer`s, awaiting each one in turn.
+    ///
+    /// # Arguments
+    /// * `notification` - The `Notification` to have the `View` notify `Observer`s of.
+    ///
+    /// # Returns
+    /// A future completing when every `Observer` has handled the `Notification`.
+    #[cfg(feature = "async")]
+    fn notify_observers_async(&self, notification: &Arc<dyn INotification>) -> BoxFuture {
+        self.view.notify_observers_async(notification)
+    }
+
+    /// Register a hook to run when the core is removed.
+    ///
+    /// Hooks run in the reverse order of their registration.
+    ///
+    /// # Arguments
+    /// * `hook` - The callback to run, receiving the core's Multiton key.
+    fn add_shutdown_hook(&self, hook: ShutdownHook) {
+        if let Ok(mut hooks) = self.shutdown_hooks.lock() {
+            hooks.push(hook);
+        }
+    }
+
+    /// Tear down the core before it is removed.
+    ///
+    /// Sends `Facade::CORE_REMOVED`, runs the shutdown hooks, then removes every registered
+    /// `Mediator` and `Proxy`, calling their `on_remove` methods.
+    fn shutdown(&self) {
+        self.send_notification(Facade::CORE_REMOVED, Some(Arc::new(self.key.clone())), None);
+
+        let hooks = self.shutdown_hooks.lock()
+            .map(|mut hooks| std::mem::take(&mut *hooks))
+            .unwrap_or_default();
+        for hook in hooks.into_iter().rev() {
+            hook(&self.key);
+        }
+
+        for mediator_name in self.view.mediator_names() {
+            self.view.remove_mediator(&mediator_name);
+        }
+        for proxy_name in self.model.proxy_names() {
+            self.model.remove_proxy(&proxy_name);
+        }
+    }
+
+    /// Add an `IInterceptor` to the `View` and the `Controller`.
+    ///
+    /// # Arguments
+    /// * `interceptor` - The `IInterceptor` to call around the delivery of each `Notification`
+    ///   and the execution of its `Command`s.
+    fn add_interceptor(&self, interceptor: Arc<dyn IInterceptor>) {
+        self.view.add_interceptor(Arc::clone(&interceptor));
+        self.controller.add_interceptor(interceptor);
+    }
+
+    /// Remove an `IInterceptor` from the `View` and the `Controller`.
+    ///
+    /// # Arguments
+    /// * `interceptor` - The `IInterceptor` previously added.
+    fn remove_interceptor(&self, interceptor: &Arc<dyn IInterceptor>) {
+        self.view.remove_interceptor(interceptor);
+        self.controller.remove_interceptor(interceptor);
+    }
+
+    /// Set the `IStore` the `Model` keeps the data of `IPersistentProxy` instances in.
+    ///
+    /// # Arguments
+    /// * `store` - The store, or `None` to stop persisting data.
+    #[cfg(feature = "persistence")]
+    fn set_store(&self, store: Option<Arc<dyn IStore>>) {
+        self.model.set_store(store);
+    }
+
+    /// Save the data of every registered `IPersistentProxy` to the `IStore`.
+    ///
+    /// # Returns
+    /// The first error met, if any.
+    #[cfg(feature = "persistence")]
+    fn save_proxies(&self) -> Result<(), Error> {
+        self.model.save_proxies()
+    }
+
+    /// Restore the data of a registered `IPersistentProxy` from the `IStore`.
+    ///
+    /// # Arguments
+    /// * `proxy_name` - The name of the `Proxy` instance to restore.
+    ///
+    /// # Returns
+    /// `true` if data was restored, otherwise `false`, or an `Error` if the `Proxy` is not
+    /// registered or its data cannot be loaded.
+    #[cfg(feature = "persistence")]
+    fn restore_proxy(&self, proxy_name: &str) -> Result<bool, Error> {
+        self.model.restore_proxy(proxy_name)
+    }
 }
 
 impl INotifier for Facade {
@@ -277,7 +601,7 @@ impl INotifier for Facade {
     /// # Returns
     /// The `IFacade` instance.
     fn facade(&self) -> Arc<dyn IFacade> {
-        Facade::get_instance(&self.key, |k| Facade::new(k))
+        Facade::get_instance(&self.key, Facade::new)
     }
 
     /// Initialize this `Facade` instance.
@@ -304,4 +628,19 @@ impl INotifier for Facade {
         let notification = Notification::new(notification_name, body, type_);
         self.notify_observers(&(Arc::new(notification) as Arc<dyn INotification>));
     }
+
+    /// Create and send a `Notification`, awaiting its delivery.
+    ///
+    /// # Arguments
+    /// * `notification_name` - The name of the `Notification` to send.
+    /// * `body` - The body of the `Notification` (optional).
+    /// * `type_` - The type of the `Notification` (optional).
+    ///
+    /// # Returns
+    /// A future completing when every `Observer` has handled the `Notification`.
+    #[cfg(feature = "async")]
+    fn send_notification_async(&self, notification_name: &str, body: Option<Arc<dyn Any + Send + Sync>>, type_: Option<&str>) -> BoxFuture {
+        let notification = Notification::new(notification_name, body, type_);
+        self.notify_observers_async(&(Arc::new(notification) as Arc<dyn INotification>))
+    }
 }
@@ -0,0 +1,67 @@
+use std::sync::Arc;
+use std::time::Duration;
+use log::Level;
+use crate::interfaces::{IInterceptor, INotification};
+
+/// An `IInterceptor` tracing a Core's traffic through the `log` crate.
+///
+/// Logs a record for each `INotification` delivered by the `IView` and for each `INotification`
+/// whose `ICommand`s are executed by the `IController`, with the Core's key, the
+/// `INotification`'s name and type, the number of `IObserver`s or `ICommand`s, and the time
+/// taken. Records are logged with the `puremvc` target.
+///
+/// See `IInterceptor`, `IFacade::add_interceptor`
+pub struct LogInterceptor {
+    /// The level to log records at.
+    level: Level,
+}
+
+impl LogInterceptor {
+    /// Construct a new `LogInterceptor` instance.
+    ///
+    /// # Arguments
+    /// * `level` - The level to log records at.
+    pub fn new(level: Level) -> Self {
+        Self { level }
+    }
+
+    /// Get the level records are logged at.
+    ///
+    /// # Returns
+    /// The level to log records at.
+    pub fn level(&self) -> Level {
+        self.level
+    }
+}
+
+impl Default for LogInterceptor {
+    fn default() -> Self {
+        Self::new(Level::Debug)
+    }
+}
+
+impl IInterceptor for LogInterceptor {
+    /// Log the delivery of an `INotification`.
+    ///
+    /// # Arguments
+    /// * `key` - The Multiton key of the Core delivering the `INotification`.
+    /// * `notification` - The `INotification` delivered.
+    /// * `observer_count` - The number of `IObserver`s to notify.
+    /// * `elapsed` - The time taken to deliver the `INotification`.
+    fn after_notify(&self, key: &str, notification: &Arc<dyn INotification>, observer_count: usize, elapsed: Duration) {
+        log::log!(target: "puremvc", self.level, "[{}] notify {} (type: {}) observers: {} elapsed: {:?}",
+            key, notification.name(), notification.get_type().unwrap_or("none"), observer_count, elapsed);
+    }
+
+    /// Log the execution of the `ICommand`s mapped to an `INotification`.
+    ///
+    /// # Arguments
+    /// * `key` - The Multiton key of the Core executing the `ICommand`s.
+    /// * `notification` - The `INotification` the `ICommand`s handled.
+    /// * `command_count` - The number of `ICommand`s to execute.
+    /// * `elapsed` - The time taken to execute the `ICommand`s.
+    fn after_execute(&self, key: &str, notification: &Arc<dyn INotification>, command_count: usize, elapsed: Duration) {
+        log::log!(target: "puremvc", self.level, "[{}] execute {} (type: {}) commands: {} elapsed: {:?}",
+            key, notification.name(), notification.get_type().unwrap_or("none"), command_count, elapsed);
+    }
+}
@@ -0,0 +1,7 @@
+#[cfg(feature = "log")]
+mod log_interceptor;
+#[cfg(feature = "log")]
+pub use log_interceptor::LogInterceptor;
+
+mod notification_recorder;
+pub use notification_recorder::{NotificationRecorder, RecordedNotification};
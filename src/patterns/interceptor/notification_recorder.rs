@@ -0,0 +1,150 @@
+use std::any::Any;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+use crate::interfaces::{IFacade, IInterceptor, INotification};
+use crate::patterns::Notification;
+
+/// A `Notification` captured by a `NotificationRecorder`.
+///
+/// See `NotificationRecorder`
+#[derive(Clone)]
+pub struct RecordedNotification {
+    /// The Multiton key of the Core that delivered the `Notification`.
+    pub key: String,
+    /// The name of the `Notification`.
+    pub name: String,
+    /// The type of the `Notification`.
+    pub type_: Option<String>,
+    /// The body of the `Notification`, kept for replay.
+    pub body: Option<Arc<dyn Any + Send + Sync>>,
+    /// A text representation of the body, when its type is a string, number or `bool`.
+    pub serialized_body: Option<String>,
+    /// When the `Notification` was delivered.
+    pub timestamp: SystemTime,
+}
+
+impl RecordedNotification {
+    /// Create a `Notification` from the record.
+    ///
+    /// # Returns
+    /// A new `Notification` with the recorded name, body and type.
+    pub fn to_notification(&self) -> Arc<dyn INotification> {
+        Arc::new(Notification::new(&self.name, self.body.clone(), self.type_.as_deref()))
+    }
+}
+
+/// An `IInterceptor` capturing every `Notification` delivered by a Core's `IView`.
+///
+/// The most recent `Notification`s are kept in a ring buffer of a fixed capacity, so the
+/// sequence leading to a bug may be inspected, then replayed into a fresh Core to reproduce it.
+///
+/// Add the recorder to the Core's `IView`, or to its `IFacade`, with `add_interceptor`.
+///
+/// See `IInterceptor`, `RecordedNotification`
+pub struct NotificationRecorder {
+    /// The maximum number of records kept.
+    capacity: usize,
+    /// The records, oldest first.
+    records: Mutex<VecDeque<RecordedNotification>>,
+}
+
+impl NotificationRecorder {
+    /// Construct a new `NotificationRecorder` instance.
+    ///
+    /// # Arguments
+    /// * `capacity` - The maximum number of records kept; older records are dropped first.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            records: Mutex::new(VecDeque::with_capacity(capacity)),
+        }
+    }
+
+    /// Get the maximum number of records kept.
+    ///
+    /// # Returns
+    /// The capacity of the ring buffer.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Copy the records.
+    ///
+    /// # Returns
+    /// The recorded `Notification`s, oldest first.
+    pub fn records(&self) -> Vec<RecordedNotification> {
+        self.records.lock().map(|records| records.iter().cloned().collect()).unwrap_or_default()
+    }
+
+    /// Drop all records.
+    pub fn clear(&self) {
+        if let Ok(mut records) = self.records.lock() {
+            records.clear();
+        }
+    }
+
+    /// Re-send the recorded `Notification`s, oldest first.
+    ///
+    /// The records are copied before the first `Notification` is sent, so replaying into the
+    /// recorded Core does not replay the replayed `Notification`s.
+    ///
+    /// # Arguments
+    /// * `facade` - The `IFacade` of the Core to notify.
+    pub fn replay(&self, facade: &dyn IFacade) {
+        for record in self.records() {
+            facade.notify_observers(&record.to_notification());
+        }
+    }
+}
+
+impl IInterceptor for NotificationRecorder {
+    /// Record an `INotification` about to be delivered.
+    ///
+    /// # Arguments
+    /// * `key` - The Multiton key of the Core delivering the `INotification`.
+    /// * `notification` - The `INotification` being delivered.
+    /// * `observer_count` - The number of `IObserver`s to notify.
+    fn before_notify(&self, key: &str, notification: &Arc<dyn INotification>, observer_count: usize) {
+        let _ = observer_count;
+        if self.capacity == 0 {
+            return;
+        }
+
+        let record = RecordedNotification {
+            key: key.into(),
+            name: notification.name().into(),
+            type_: notification.get_type().map(|type_| type_.into()),
+            body: notification.body().cloned(),
+            serialized_body: notification.body().and_then(|body| serialize(body.as_ref())),
+            timestamp: SystemTime::now(),
+        };
+
+        if let Ok(mut records) = self.records.lock() {
+            if records.len() == self.capacity {
+                records.pop_front();
+            }
+            records.push_back(record);
+        }
+    }
+}
+
+/// Represent a `Notification` body as text, if its type is a string, number or `bool`.
+///
+/// # Arguments
+/// * `body` - The body to represent.
+///
+/// # Returns
+/// The text representation, or `None` if the body's type is not supported.
+fn serialize(body: &(dyn Any + Send + Sync)) -> Option<String> {
+    macro_rules! display {
+        ($($type_:ty),*) => {
+            $(if let Some(value) = body.downcast_ref::<$type_>() {
+                return Some(value.to_string());
+            })*
+        };
+    }
+
+    display!(String, &'static str, bool, char, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+    None
+}
@@ -1,14 +1,36 @@
 mod command;
 mod facade;
+mod interceptor;
 mod mediator;
 mod observer;
+#[cfg(feature = "persistence")]
+mod persistence;
 mod proxy;
 
 pub use command::SimpleCommand;
 pub use command::MacroCommand;
+pub use command::AsyncCommand;
+pub use command::AsyncMacroCommand;
+pub use command::UndoableCommand;
 pub use facade::Facade;
+#[cfg(feature = "log")]
+pub use interceptor::LogInterceptor;
+pub use interceptor::{NotificationRecorder, RecordedNotification};
 pub use mediator::Mediator;
 pub use observer::Observer;
+#[cfg(feature = "async")]
+pub use observer::AsyncObserver;
 pub use observer::Notifier;
 pub use observer::Notification;
+pub use observer::TypedNotification;
+#[cfg(feature = "serde")]
+pub use observer::{BodyCodecRegistry, SerializedNotification};
 pub use proxy::Proxy;
+pub use proxy::{CollectionItem, CollectionProxy, CollectionSummary, KeyedCollectionProxy};
+pub use proxy::{CommandHistoryProxy, CommandHistoryState};
+pub use proxy::{DataChange, ObservableProxy};
+pub use proxy::{ProxyReadGuard, ProxyWriteGuard, TypedProxy};
+#[cfg(feature = "persistence")]
+pub use proxy::PersistentProxy;
+#[cfg(feature = "persistence")]
+pub use persistence::{FileStore, StoreFormat};
@@ -0,0 +1,154 @@
+use std::any::Any;
+use std::future::Future;
+use std::sync::{Arc};
+use std::task::{Context, Wake, Waker};
+use std::thread::{self, Thread};
+use crate::interfaces::{AsyncNotifyFn, BoxFuture, INotification, IObserver};
+use crate::patterns::Observer;
+
+/// An `IObserver` whose notification callback method returns a future.
+///
+/// When notified through `notify_observer_async`, for instance by `IView::notify_observers_async`
+/// or `INotifier::send_notification_async`, the future returned by the callback is awaited. When
+/// notified through the synchronous `notify_observer`, the calling thread blocks until the future
+/// completes, so an `AsyncObserver` behaves like an `Observer` for synchronous senders.
+///
+/// The notification callback method receives its own reference to the `INotification` so the
+/// returned future may hold on to it across await points.
+///
+/// See `Observer`, `IView`
+pub struct AsyncObserver {
+    /// The asynchronous notification callback method of the interested object.
+    notify: Option<AsyncNotifyFn>,
+    /// The underlying `Observer` holding the notification context.
+    observer: Observer,
+}
+
+impl AsyncObserver {
+    /// Construct a new `AsyncObserver` instance.
+    ///
+    /// # Arguments
+    /// * `notify` - The asynchronous notification callback method (optional).
+    /// * `context` - The context object (optional).
+    pub fn new(notify: Option<AsyncNotifyFn>, context: Option<Arc<dyn Any + Send + Sync>>) -> Self {
+        Self {
+            notify,
+            observer: Observer::new(None, context),
+        }
+    }
+
+    /// Get the asynchronous notification callback method.
+    ///
+    /// # Returns
+    /// The asynchronous notification callback method of the interested object.
+    pub fn notify_async(&self) -> Option<AsyncNotifyFn> {
+        self.notify.clone()
+    }
+
+    /// Set the asynchronous notification callback method.
+    ///
+    /// # Arguments
+    /// * `notify` - The asynchronous notification callback method of the interested object.
+    pub fn set_notify_async(&mut self, notify: Option<AsyncNotifyFn>) {
+        self.notify = notify;
+    }
+}
+
+impl IObserver for AsyncObserver {
+    /// Get the notification callback method.
+    ///
+    /// # Returns
+    /// A synchronous callback that blocks until the asynchronous callback has completed.
+    fn notify(&self) -> Option<Arc<dyn Fn(&Arc<dyn INotification>) + Send + Sync>> {
+        self.notify.clone().map(|notify| {
+            Arc::new(move |notification: &Arc<dyn INotification>| block_on(notify(Arc::clone(notification))))
+                as Arc<dyn Fn(&Arc<dyn INotification>) + Send + Sync>
+        })
+    }
+
+    /// Set the notification callback method.
+    ///
+    /// The synchronous callback replaces the asynchronous one and completes immediately.
+    ///
+    /// # Arguments
+    /// * `notify` - The notification callback method of the interested object.
+    fn set_notify(&mut self, notify: Option<Arc<dyn Fn(&Arc<dyn INotification>) + Send + Sync>>) {
+        self.notify = notify.map(|notify| {
+            Arc::new(move |notification: Arc<dyn INotification>| {
+                notify(&notification);
+                Box::pin(std::future::ready(())) as BoxFuture
+            }) as AsyncNotifyFn
+        });
+    }
+
+    /// Get the notification context.
+    ///
+    /// # Returns
+    /// The notification context of the interested object.
+    fn context(&self) -> Option<Arc<dyn Any + Send + Sync>> {
+        self.observer.context()
+    }
+
+    /// Set the notification context.
+    ///
+    /// # Arguments
+    /// * `context` - The notification context of the interested object.
+    fn set_context(&mut self, context: Option<Arc<dyn Any + Send + Sync>>) {
+        self.observer.set_context(context);
+    }
+
+    /// Notify the interested object, blocking until its callback has completed.
+    ///
+    /// # Arguments
+    /// * `notification` - The `Notification` to pass to the callback method.
+    fn notify_observer(&self, notification: &Arc<dyn INotification>) {
+        block_on(self.notify_observer_async(notification));
+    }
+
+    /// Notify the interested object.
+    ///
+    /// # Arguments
+    /// * `notification` - The `Notification` to pass to the callback method.
+    ///
+    /// # Returns
+    /// The future returned by the callback method.
+    fn notify_observer_async(&self, notification: &Arc<dyn INotification>) -> BoxFuture {
+        match &self.notify {
+            Some(notify) => notify(Arc::clone(notification)),
+            None => Box::pin(std::future::ready(())),
+        }
+    }
+
+    /// Compare a given object to the notification context object.
+    ///
+    /// # Arguments
+    /// * `object` - The object to compare.
+    ///
+    /// # Returns
+    /// `true` if the given object and the notification context are the same, otherwise `false`.
+    fn compare_notify_context(&self, object: &Arc<dyn Any + Send + Sync>) -> bool {
+        self.observer.compare_notify_context(object)
+    }
+}
+
+/// Wakes a thread parked in `block_on`.
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Run a future to completion on the current thread.
+///
+/// # Arguments
+/// * `future` - The future to run.
+fn block_on(future: impl Future<Output = ()>) {
+    let mut future = std::pin::pin!(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut context = Context::from_waker(&waker);
+    while future.as_mut().poll(&mut context).is_pending() {
+        thread::park();
+    }
+}
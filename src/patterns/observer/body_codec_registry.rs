@@ -0,0 +1,132 @@
+use std::any::{type_name, Any};
+use std::collections::HashMap;
+use std::sync::Arc;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use crate::Error;
+use crate::interfaces::INotification;
+use crate::patterns::{Notification, SerializedNotification};
+
+/// Encodes the body of the named `Notification`.
+type EncodeFn = Box<dyn Fn(&str, &Arc<dyn Any + Send + Sync>) -> Result<Value, Error> + Send + Sync>;
+
+/// Decodes the body of the named `Notification`.
+type DecodeFn = Box<dyn Fn(&str, Value) -> Result<Arc<dyn Any + Send + Sync>, Error> + Send + Sync>;
+
+/// Converts a `Notification` body to and from its serializable form.
+struct BodyCodec {
+    /// Encode a body, or fail if it is not of the codec's type.
+    encode: EncodeFn,
+    /// Decode a body.
+    decode: DecodeFn,
+}
+
+/// A registry of `Notification` body codecs, keyed by `Notification` name.
+///
+/// As `Notification` bodies are `dyn Any`, the registry must be told which type the body of each
+/// `Notification` is before it can serialize it. Register a type implementing serde's
+/// `Serialize` and `Deserialize` for a `Notification` name with `register`, then convert
+/// `INotification`s with `encode` and `decode`.
+///
+/// `Notification`s without a body need no codec.
+///
+/// See `SerializedNotification`
+#[derive(Default)]
+pub struct BodyCodecRegistry {
+    /// Mapping of `Notification` names to body codecs.
+    codecs: HashMap<String, BodyCodec>,
+}
+
+impl BodyCodecRegistry {
+    /// Construct a new, empty `BodyCodecRegistry`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register the body type of a `Notification`, replacing any previous codec for the name.
+    ///
+    /// # Arguments
+    /// * `notification_name` - The name of the `Notification` whose body is a `T`.
+    pub fn register<T: Serialize + DeserializeOwned + Send + Sync + 'static>(&mut self, notification_name: &str) {
+        self.codecs.insert(notification_name.into(), BodyCodec {
+            encode: Box::new(|name, body| {
+                let body = body.downcast_ref::<T>()
+                    .ok_or_else(|| Error::BodyTypeMismatch { notification: name.into(), expected: type_name::<T>() })?;
+                serde_json::to_value(body).map_err(|error| Error::Serialization { notification: name.into(), message: error.to_string() })
+            }),
+            decode: Box::new(|name, value| {
+                serde_json::from_value::<T>(value)
+                    .map(|body| Arc::new(body) as Arc<dyn Any + Send + Sync>)
+                    .map_err(|error| Error::Serialization { notification: name.into(), message: error.to_string() })
+            }),
+        });
+    }
+
+    /// Remove the codec of a `Notification`.
+    ///
+    /// # Arguments
+    /// * `notification_name` - The name of the `Notification`.
+    pub fn remove(&mut self, notification_name: &str) {
+        self.codecs.remove(notification_name);
+    }
+
+    /// Check if a codec is registered for a `Notification`.
+    ///
+    /// # Arguments
+    /// * `notification_name` - The name of the `Notification`.
+    ///
+    /// # Returns
+    /// `true` if a codec is registered for the given name, otherwise `false`.
+    pub fn has_codec(&self, notification_name: &str) -> bool {
+        self.codecs.contains_key(notification_name)
+    }
+
+    /// Convert an `INotification` to its serializable form.
+    ///
+    /// # Arguments
+    /// * `notification` - The `INotification` to convert.
+    ///
+    /// # Returns
+    /// The `SerializedNotification`, `Error::NotFound` if it has a body but no codec is
+    /// registered for its name, `Error::BodyTypeMismatch` if its body is not of the registered
+    /// type, or `Error::Serialization` if its body cannot be serialized.
+    pub fn encode(&self, notification: &dyn INotification) -> Result<SerializedNotification, Error> {
+        let name = notification.name();
+        let body = match notification.body() {
+            Some(body) => Some((self.codec(name)?.encode)(name, body)?),
+            None => None,
+        };
+
+        Ok(SerializedNotification {
+            name: name.into(),
+            type_: notification.get_type().map(|type_| type_.into()),
+            body,
+        })
+    }
+
+    /// Convert a `SerializedNotification` back to an `INotification`.
+    ///
+    /// # Arguments
+    /// * `serialized` - The `SerializedNotification` to convert.
+    ///
+    /// # Returns
+    /// A new `Notification`, `Error::NotFound` if it has a body but no codec is registered for
+    /// its name, or `Error::Serialization` if its body is not valid for the registered type.
+    pub fn decode(&self, serialized: SerializedNotification) -> Result<Arc<dyn INotification>, Error> {
+        let body = match serialized.body {
+            Some(value) => Some((self.codec(&serialized.name)?.decode)(&serialized.name, value)?),
+            None => None,
+        };
+        Ok(Arc::new(Notification::new(&serialized.name, body, serialized.type_.as_deref())))
+    }
+
+    /// Look up the codec of a `Notification`.
+    ///
+    /// # Arguments
+    /// * `notification_name` - The name of the `Notification`.
+    fn codec(&self, notification_name: &str) -> Result<&BodyCodec, Error> {
+        self.codecs.get(notification_name)
+            .ok_or_else(|| Error::NotFound { kind: "Body codec", name: notification_name.into() })
+    }
+}
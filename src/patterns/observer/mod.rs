@@ -6,3 +6,21 @@ pub use notifier::Notifier;
 
 mod notification;
 pub use notification::Notification;
+
+mod typed_notification;
+pub use typed_notification::TypedNotification;
+
+#[cfg(feature = "async")]
+mod async_observer;
+#[cfg(feature = "async")]
+pub use async_observer::AsyncObserver;
+
+#[cfg(feature = "serde")]
+mod serialized_notification;
+#[cfg(feature = "serde")]
+pub use serialized_notification::SerializedNotification;
+
+#[cfg(feature = "serde")]
+mod body_codec_registry;
+#[cfg(feature = "serde")]
+pub use body_codec_registry::BodyCodecRegistry;
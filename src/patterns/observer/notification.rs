@@ -1,5 +1,6 @@
 use std::any::Any;
 use std::sync::{Arc};
+use std::sync::atomic::{AtomicBool, Ordering};
 use crate::interfaces::INotification;
 
 /// A base `INotification` implementation.
@@ -24,6 +25,8 @@ pub struct Notification {
     body: Option<Arc<dyn Any + Send + Sync>>,
     /// The `Notification`'s type.
     type_: Option<String>,
+    /// Whether delivery of the `Notification` has been stopped.
+    stopped: AtomicBool,
 }
 
 impl Notification {
@@ -38,6 +41,7 @@ impl Notification {
             name: name.into(),
             body,
             type_: type_.map(|t| t.into()),
+            stopped: AtomicBool::new(false),
         }
     }
 }
@@ -96,4 +100,17 @@ impl INotification for Notification {
         let type_ = self.r#type_.as_deref().unwrap_or("null");
         format!("Notification Name: {}\nBody: {}\nType: {}", name, body, type_)
     }
+
+    /// Stop the `Notification` from being delivered to any further `Observer`s.
+    fn stop_propagation(&self) {
+        self.stopped.store(true, Ordering::Release);
+    }
+
+    /// Check if the `Notification` has been stopped from being delivered to further `Observer`s.
+    ///
+    /// # Returns
+    /// `true` if `stop_propagation` has been called, otherwise `false`.
+    fn is_propagation_stopped(&self) -> bool {
+        self.stopped.load(Ordering::Acquire)
+    }
 }
@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::Error;
+
+/// A serializable representation of an `INotification`.
+///
+/// Create one from an `INotification` with `BodyCodecRegistry::encode`, which converts the body
+/// with the codec registered for the `INotification`'s name, and turn it back into an
+/// `INotification` with `BodyCodecRegistry::decode`. In between, it can be written to and read
+/// from JSON or MessagePack, to be logged, stored or forwarded to another process.
+///
+/// See `BodyCodecRegistry`, `Notification`
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SerializedNotification {
+    /// The name of the `Notification`.
+    pub name: String,
+    /// The type of the `Notification`.
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub type_: Option<String>,
+    /// The body of the `Notification`, as encoded by its codec.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<Value>,
+}
+
+impl SerializedNotification {
+    /// Write the `SerializedNotification` as JSON.
+    ///
+    /// # Returns
+    /// The JSON text, or `Error::Serialization` if the body cannot be written.
+    pub fn to_json(&self) -> Result<String, Error> {
+        serde_json::to_string(self).map_err(|error| self.error(error))
+    }
+
+    /// Read a `SerializedNotification` from JSON.
+    ///
+    /// # Arguments
+    /// * `json` - The JSON text.
+    ///
+    /// # Returns
+    /// The `SerializedNotification`, or `Error::Serialization` if the text is not valid.
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        serde_json::from_str(json).map_err(|error| Error::Serialization { notification: String::new(), message: error.to_string() })
+    }
+
+    /// Write the `SerializedNotification` in the compact MessagePack binary format.
+    ///
+    /// # Returns
+    /// The encoded bytes, or `Error::Serialization` if the body cannot be written.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        rmp_serde::to_vec_named(self).map_err(|error| self.error(error))
+    }
+
+    /// Read a `SerializedNotification` from the compact MessagePack binary format.
+    ///
+    /// # Arguments
+    /// * `bytes` - The encoded bytes.
+    ///
+    /// # Returns
+    /// The `SerializedNotification`, or `Error::Serialization` if the bytes are not valid.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        rmp_serde::from_slice(bytes).map_err(|error| Error::Serialization { notification: String::new(), message: error.to_string() })
+    }
+
+    /// Describe a failure to serialize this `SerializedNotification`.
+    ///
+    /// # Arguments
+    /// * `error` - The underlying error.
+    fn error(&self, error: impl ToString) -> Error {
+        Error::Serialization { notification: self.name.clone(), message: error.to_string() }
+    }
+}
@@ -0,0 +1,154 @@
+use std::any::{type_name, Any};
+use std::sync::{Arc};
+use std::sync::atomic::{AtomicBool, Ordering};
+use crate::Error;
+use crate::interfaces::INotification;
+
+/// An `INotification` whose body is statically typed.
+///
+/// Senders construct a `TypedNotification` with a body of type `T` and send it with
+/// `IFacade::notify_observers`, or use `ITypedNotifier::send_typed_notification`. Receivers convert
+/// the `INotification` they are handed with `TypedNotification::<T>::try_from`, which reports a
+/// missing or mismatched body as an `Error`.
+///
+/// See `INotification`, `Notification`
+pub struct TypedNotification<T: Any + Send + Sync> {
+    /// The `Notification`'s name.
+    name: String,
+    /// The `Notification`'s body.
+    body: Arc<T>,
+    /// The `Notification`'s body, as exposed through `INotification::body`.
+    erased: Arc<dyn Any + Send + Sync>,
+    /// The `Notification`'s type.
+    type_: Option<String>,
+    /// Whether delivery of the `Notification` has been stopped.
+    stopped: AtomicBool,
+}
+
+impl<T: Any + Send + Sync> TypedNotification<T> {
+    /// Construct a new `TypedNotification` instance.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the `Notification`.
+    /// * `body` - The body of the `Notification`.
+    /// * `type_` - The type of the `Notification` (optional).
+    pub fn new(name: &str, body: T, type_: Option<&str>) -> Self {
+        let body = Arc::new(body);
+        Self {
+            name: name.into(),
+            erased: Arc::clone(&body) as Arc<dyn Any + Send + Sync>,
+            body,
+            type_: type_.map(|t| t.into()),
+            stopped: AtomicBool::new(false),
+        }
+    }
+
+    /// Get the typed body of the `Notification`.
+    ///
+    /// # Returns
+    /// The body of the `Notification`.
+    pub fn typed_body(&self) -> &Arc<T> {
+        &self.body
+    }
+
+    /// Set the typed body of the `Notification`.
+    ///
+    /// # Arguments
+    /// * `body` - The body of the `Notification`.
+    pub fn set_typed_body(&mut self, body: Arc<T>) {
+        self.erased = Arc::clone(&body) as Arc<dyn Any + Send + Sync>;
+        self.body = body;
+    }
+}
+
+impl<T: Any + Send + Sync> TryFrom<&dyn INotification> for TypedNotification<T> {
+    type Error = Error;
+
+    /// Convert an `INotification` into a `TypedNotification`.
+    ///
+    /// # Arguments
+    /// * `notification` - The `INotification` to convert.
+    ///
+    /// # Returns
+    /// A `TypedNotification` sharing the name, body and type of the `INotification`, or an
+    /// `Error` if the `INotification` has no body or its body is not a `T`.
+    fn try_from(notification: &dyn INotification) -> Result<Self, Self::Error> {
+        let body = notification.body_as::<T>()?;
+        Ok(Self {
+            name: notification.name().into(),
+            erased: Arc::clone(&body) as Arc<dyn Any + Send + Sync>,
+            body,
+            type_: notification.get_type().map(|t| t.into()),
+            stopped: AtomicBool::new(notification.is_propagation_stopped()),
+        })
+    }
+}
+
+impl<T: Any + Send + Sync> INotification for TypedNotification<T> {
+    /// Get the name of the `Notification`.
+    ///
+    /// # Returns
+    /// The name of the `Notification`.
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the body of the `Notification`.
+    ///
+    /// # Returns
+    /// The body of the `Notification`.
+    fn body(&self) -> Option<&Arc<dyn Any + Send + Sync>> {
+        Some(&self.erased)
+    }
+
+    /// Set the body of the `Notification`.
+    ///
+    /// A `TypedNotification` always has a body of type `T`; a missing body or a body of another
+    /// type is ignored. Use `set_typed_body` to have the compiler enforce the body type.
+    ///
+    /// # Arguments
+    /// * `body` - The body of the `Notification`.
+    fn set_body(&mut self, body: Option<Arc<dyn Any + Send + Sync>>) {
+        if let Some(body) = body.and_then(|body| body.downcast::<T>().ok()) {
+            self.set_typed_body(body);
+        }
+    }
+
+    /// Get the type of the `Notification`.
+    ///
+    /// # Returns
+    /// The type of the `Notification`.
+    fn get_type(&self) -> Option<&str> {
+        self.type_.as_deref()
+    }
+
+    /// Set the type of the `Notification`.
+    ///
+    /// # Arguments
+    /// * `type_` - The type of the `Notification`.
+    fn set_type(&mut self, type_: Option<String>) {
+        self.type_ = type_;
+    }
+
+    /// Convert the `TypedNotification` to a string representation.
+    ///
+    /// # Returns
+    /// A string containing the name, body type, and type of the `Notification`.
+    fn to_string(&self) -> String {
+        let type_ = self.type_.as_deref().unwrap_or("null");
+        format!("Notification Name: {}\nBody: {}\nType: {}", self.name, type_name::<T>(), type_)
+    }
+
+    /// Stop the `Notification` from being delivered to any further `Observer`s.
+    fn stop_propagation(&self) {
+        self.stopped.store(true, Ordering::Release);
+    }
+
+    /// Check if the `Notification` has been stopped from being delivered to further `Observer`s.
+    ///
+    /// # Returns
+    /// `true` if `stop_propagation` has been called, otherwise `false`.
+    fn is_propagation_stopped(&self) -> bool {
+        self.stopped.load(Ordering::Acquire)
+    }
+}
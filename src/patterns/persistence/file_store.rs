@@ -0,0 +1,189 @@
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use serde_json::Value;
+use crate::Error;
+use crate::interfaces::IStore;
+
+/// The number of temporary files created, used to give each write its own temporary file.
+static TEMP_FILES: AtomicUsize = AtomicUsize::new(0);
+
+/// The format a `FileStore` writes its files in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum StoreFormat {
+    /// Human readable JSON, in `.json` files.
+    #[default]
+    Json,
+    /// Compact MessagePack, in `.msgpack` files.
+    Binary,
+}
+
+impl StoreFormat {
+    /// Get the extension of the files written in this format.
+    ///
+    /// # Returns
+    /// The file extension, without the dot.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            StoreFormat::Json => "json",
+            StoreFormat::Binary => "msgpack",
+        }
+    }
+
+    /// Encode data in this format.
+    ///
+    /// # Arguments
+    /// * `data` - The data to encode.
+    ///
+    /// # Returns
+    /// The encoded bytes, or a description of the failure.
+    pub fn encode(&self, data: &Value) -> Result<Vec<u8>, String> {
+        match self {
+            StoreFormat::Json => serde_json::to_vec_pretty(data).map_err(|error| error.to_string()),
+            StoreFormat::Binary => rmp_serde::to_vec(data).map_err(|error| error.to_string()),
+        }
+    }
+
+    /// Decode data from this format.
+    ///
+    /// # Arguments
+    /// * `bytes` - The encoded bytes.
+    ///
+    /// # Returns
+    /// The decoded data, or a description of the failure.
+    pub fn decode(&self, bytes: &[u8]) -> Result<Value, String> {
+        match self {
+            StoreFormat::Json => serde_json::from_slice(bytes).map_err(|error| error.to_string()),
+            StoreFormat::Binary => rmp_serde::from_slice(bytes).map_err(|error| error.to_string()),
+        }
+    }
+}
+
+/// An `IStore` keeping each key's data in a file of a local directory.
+///
+/// Files are named after their key, with each byte of any character other than ASCII letters,
+/// digits, `-` and `_` escaped as `%` and two hexadecimal digits, so distinct keys never share a
+/// file, and the extension of the `StoreFormat`. Writes are atomic: the data is written to a
+/// temporary file of its own in the same directory, flushed to disk, then renamed over the
+/// previous file, so a crash never leaves a partially written file behind. The directory is
+/// created on the first save.
+///
+/// See `IStore`, `StoreFormat`
+pub struct FileStore {
+    /// The directory the files are kept in.
+    directory: PathBuf,
+    /// The format the files are written in.
+    format: StoreFormat,
+}
+
+impl FileStore {
+    /// Construct a new `FileStore` instance.
+    ///
+    /// # Arguments
+    /// * `directory` - The directory the files are kept in.
+    /// * `format` - The format the files are written in.
+    pub fn new(directory: impl Into<PathBuf>, format: StoreFormat) -> Self {
+        Self { directory: directory.into(), format }
+    }
+
+    /// Get the directory the files are kept in.
+    ///
+    /// # Returns
+    /// The directory path.
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    /// Get the format the files are written in.
+    ///
+    /// # Returns
+    /// The `StoreFormat`.
+    pub fn format(&self) -> StoreFormat {
+        self.format
+    }
+
+    /// Get the path of the file a key's data is kept in.
+    ///
+    /// # Arguments
+    /// * `key` - The key.
+    ///
+    /// # Returns
+    /// The file path.
+    pub fn path(&self, key: &str) -> PathBuf {
+        let name: String = key.bytes()
+            .map(|b| match b {
+                b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' | b'_' => char::from(b).to_string(),
+                _ => format!("%{:02X}", b),
+            })
+            .collect();
+        self.directory.join(format!("{}.{}", name, self.format.extension()))
+    }
+
+    /// Write bytes to a file atomically.
+    ///
+    /// # Arguments
+    /// * `path` - The file path.
+    /// * `bytes` - The bytes to write.
+    fn write_atomic(&self, path: &Path, bytes: &[u8]) -> io::Result<()> {
+        fs::create_dir_all(&self.directory)?;
+        let mut temp = path.as_os_str().to_owned();
+        temp.push(format!(".{}.{}.tmp", std::process::id(), TEMP_FILES.fetch_add(1, Ordering::Relaxed)));
+        let temp = PathBuf::from(temp);
+
+        let written = File::create(&temp)
+            .and_then(|mut file| file.write_all(bytes).and_then(|_| file.sync_all()))
+            .and_then(|_| fs::rename(&temp, path));
+        if written.is_err() {
+            let _ = fs::remove_file(&temp);
+        }
+        written
+    }
+}
+
+impl IStore for FileStore {
+    /// Load the data saved under a key.
+    ///
+    /// # Arguments
+    /// * `key` - The key the data was saved under.
+    ///
+    /// # Returns
+    /// The data, `None` if no file exists for the key, or `Error::Persistence` if the file cannot
+    /// be read or decoded.
+    fn load(&self, key: &str) -> Result<Option<Value>, Error> {
+        let error = |message: String| Error::Persistence { key: key.into(), message };
+        match fs::read(self.path(key)) {
+            Ok(bytes) => self.format.decode(&bytes).map(Some).map_err(error),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(error(e.to_string())),
+        }
+    }
+
+    /// Save data under a key, atomically replacing the file of the key.
+    ///
+    /// # Arguments
+    /// * `key` - The key to save the data under.
+    /// * `data` - The data to save.
+    ///
+    /// # Returns
+    /// `Error::Persistence` if the data cannot be encoded or written.
+    fn save(&self, key: &str, data: &Value) -> Result<(), Error> {
+        let error = |message: String| Error::Persistence { key: key.into(), message };
+        let bytes = self.format.encode(data).map_err(error)?;
+        self.write_atomic(&self.path(key), &bytes).map_err(|e| error(e.to_string()))
+    }
+
+    /// Delete the file of a key, if any.
+    ///
+    /// # Arguments
+    /// * `key` - The key the data was saved under.
+    ///
+    /// # Returns
+    /// `Error::Persistence` if the file exists but cannot be deleted.
+    fn remove(&self, key: &str) -> Result<(), Error> {
+        match fs::remove_file(self.path(key)) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(Error::Persistence { key: key.into(), message: e.to_string() }),
+            _ => Ok(()),
+        }
+    }
+}
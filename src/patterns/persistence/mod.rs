@@ -0,0 +1,2 @@
+mod file_store;
+pub use file_store::{FileStore, StoreFormat};
@@ -0,0 +1,259 @@
+use std::any::{type_name, Any};
+use std::collections::BTreeMap;
+use std::sync::{Arc, OnceLock};
+use crate::Error;
+use crate::interfaces::{IFacade, INotifier, IProxy};
+use crate::patterns::{CollectionItem, CollectionSummary, Proxy};
+
+/// The items tended by a `Collection`.
+pub(crate) trait Items: Clone + Default + Send + Sync + 'static {
+    /// Get the number of items.
+    ///
+    /// # Returns
+    /// The number of items.
+    fn count(&self) -> usize;
+}
+
+impl<T: Clone + Send + Sync + 'static> Items for Vec<T> {
+    /// Get the number of items.
+    ///
+    /// # Returns
+    /// The number of items.
+    fn count(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<K: Ord + Clone + Send + Sync + 'static, T: Clone + Send + Sync + 'static> Items for BTreeMap<K, T> {
+    /// Get the number of items.
+    ///
+    /// # Returns
+    /// The number of items.
+    fn count(&self) -> usize {
+        self.len()
+    }
+}
+
+/// The items of a `CollectionProxy` or a `KeyedCollectionProxy`, with the data object snapshot,
+/// the batches and the change notifications they share.
+///
+/// The owning proxy changes the items through `items_mut`, which drops the snapshot, then records
+/// each change with `item_added`, `item_updated` or `item_removed`, which send the item
+/// notification unless in a batch. `end_batch` sends the summary of the outermost batch.
+///
+/// See `CollectionProxy`, `KeyedCollectionProxy`
+pub(crate) struct Collection<C> {
+    /// The underlying `Proxy` instance used for `IProxy` and `INotifier` implementation.
+    proxy: Proxy,
+    /// The items.
+    items: C,
+    /// The data object, a copy of the items made when first requested after a change.
+    snapshot: OnceLock<Arc<dyn Any + Send + Sync>>,
+    /// The nesting depth of the current batch, or 0 outside of a batch.
+    batch_depth: usize,
+    /// The changes made within the current batch.
+    batch_summary: CollectionSummary,
+}
+
+impl<C: Items> Collection<C> {
+    /// Construct a new `Collection` instance.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the owning proxy (optional, defaults to `Proxy::NAME`).
+    /// * `items` - The initial items.
+    pub fn new(name: Option<&str>, items: C) -> Self {
+        Self {
+            proxy: Proxy::new(name, None),
+            items,
+            snapshot: OnceLock::new(),
+            batch_depth: 0,
+            batch_summary: CollectionSummary::default(),
+        }
+    }
+
+    /// Get the name of the owning proxy.
+    ///
+    /// # Returns
+    /// The name of the owning proxy.
+    pub fn name(&self) -> &str {
+        self.proxy.name()
+    }
+
+    /// Get the items.
+    ///
+    /// # Returns
+    /// The items.
+    pub fn items(&self) -> &C {
+        &self.items
+    }
+
+    /// Get the items to change them, dropping the data object snapshot.
+    ///
+    /// # Returns
+    /// The items.
+    pub fn items_mut(&mut self) -> &mut C {
+        self.snapshot.take();
+        &mut self.items
+    }
+
+    /// Get the data object.
+    ///
+    /// # Returns
+    /// A snapshot of the items, copied when first requested after a change.
+    pub fn data(&self) -> Option<&Arc<dyn Any + Send + Sync>> {
+        Some(self.snapshot.get_or_init(|| Arc::new(self.items.clone())))
+    }
+
+    /// Replace the items as a batch.
+    ///
+    /// # Arguments
+    /// * `data` - A `C` of the new items, or `None` to clear them.
+    /// * `notification` - The operation to name in the error.
+    /// * `changed` - The name of the summary notification.
+    ///
+    /// # Returns
+    /// `Error::BodyTypeMismatch` if `data` is not a `C`, in which case nothing changes.
+    pub fn try_set_data(&mut self, data: Option<Arc<dyn Any + Send + Sync>>, notification: &str, changed: &str) -> Result<(), Error> {
+        let items = match data {
+            Some(data) => data.downcast_ref::<C>().cloned()
+                .ok_or_else(|| Error::BodyTypeMismatch { notification: notification.into(), expected: type_name::<C>() })?,
+            None => C::default(),
+        };
+        self.begin_batch();
+        self.batch_summary.removed += self.items.count();
+        self.batch_summary.added += items.count();
+        *self.items_mut() = items;
+        self.end_batch(changed);
+        Ok(())
+    }
+
+    /// Record an added item, and send its notification unless in a batch.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the notification.
+    /// * `key` - The index or key of the item.
+    /// * `item` - The item.
+    pub fn item_added<K: Send + Sync + 'static, T: Send + Sync + 'static>(&mut self, name: &str, key: K, item: T) {
+        self.batch_summary.added += 1;
+        self.item_changed(name, key, item);
+    }
+
+    /// Record an updated item, and send its notification unless in a batch.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the notification.
+    /// * `key` - The index or key of the item.
+    /// * `item` - The new item.
+    pub fn item_updated<K: Send + Sync + 'static, T: Send + Sync + 'static>(&mut self, name: &str, key: K, item: T) {
+        self.batch_summary.updated += 1;
+        self.item_changed(name, key, item);
+    }
+
+    /// Record a removed item, and send its notification unless in a batch.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the notification.
+    /// * `key` - The index or key the item was at.
+    /// * `item` - The removed item.
+    pub fn item_removed<K: Send + Sync + 'static, T: Send + Sync + 'static>(&mut self, name: &str, key: K, item: T) {
+        self.batch_summary.removed += 1;
+        self.item_changed(name, key, item);
+    }
+
+    /// Record items removed within a batch, which sends no item notification.
+    ///
+    /// # Arguments
+    /// * `count` - The number of items removed.
+    pub fn items_removed(&mut self, count: usize) {
+        debug_assert!(self.in_batch(), "Collection::items_removed called outside of a batch");
+        self.batch_summary.removed += count;
+    }
+
+    /// Start a batch, possibly nested within another.
+    pub fn begin_batch(&mut self) {
+        if self.batch_depth == 0 {
+            self.batch_summary = CollectionSummary::default();
+        }
+        self.batch_depth += 1;
+    }
+
+    /// End a batch, and send the summary if it was the outermost one and anything changed.
+    ///
+    /// # Arguments
+    /// * `changed` - The name of the summary notification.
+    pub fn end_batch(&mut self, changed: &str) {
+        self.batch_depth -= 1;
+        if self.batch_depth == 0 {
+            let summary = CollectionSummary { len: self.items.count(), ..std::mem::take(&mut self.batch_summary) };
+            if summary.added + summary.updated + summary.removed > 0 {
+                self.notify(changed, Arc::new(summary));
+            }
+        }
+    }
+
+    /// Check if item notifications are being replaced by a summary.
+    ///
+    /// # Returns
+    /// `true` within a batch, otherwise `false`.
+    pub fn in_batch(&self) -> bool {
+        self.batch_depth > 0
+    }
+
+    /// Send an item notification unless in a batch.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the notification.
+    /// * `key` - The index or key of the item.
+    /// * `item` - The item.
+    fn item_changed<K: Send + Sync + 'static, T: Send + Sync + 'static>(&self, name: &str, key: K, item: T) {
+        if self.batch_depth == 0 {
+            self.notify(name, Arc::new(CollectionItem { key, item }));
+        }
+    }
+
+    /// Send a notification, if the proxy is registered with a core.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the notification.
+    /// * `body` - The body of the notification.
+    fn notify(&self, name: &str, body: Arc<dyn Any + Send + Sync>) {
+        if self.key().is_empty() { return }
+        self.send_notification(name, Some(body), Some(self.proxy.name()));
+    }
+}
+
+impl<C: Items> INotifier for Collection<C> {
+    /// Get the Multiton key of the owning proxy.
+    ///
+    /// # Returns
+    /// The Multiton key of the owning proxy.
+    fn key(&self) -> &str {
+        self.proxy.key()
+    }
+
+    /// Get the `IFacade` instance associated with the owning proxy.
+    ///
+    /// # Returns
+    /// The `IFacade` instance.
+    fn facade(&self) -> Arc<dyn IFacade> {
+        self.proxy.facade()
+    }
+
+    /// Initialize the owning proxy.
+    ///
+    /// # Arguments
+    /// * `key` - The Multiton key for the owning proxy.
+    fn initialize_notifier(&mut self, key: &str) {
+        self.proxy.initialize_notifier(key);
+    }
+
+    /// Send a `Notification`.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the `Notification` to send.
+    /// * `body` - The body of the `Notification` (optional).
+    /// * `type_` - The type of the `Notification` (optional).
+    fn send_notification(&self, name: &str, body: Option<Arc<dyn Any + Send + Sync>>, type_: Option<&str>) {
+        self.proxy.send_notification(name, body, type_);
+    }
+}
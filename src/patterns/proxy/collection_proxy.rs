@@ -0,0 +1,312 @@
+use std::any::Any;
+use std::sync::Arc;
+use crate::Error;
+use crate::interfaces::{IFacade, INotifier, IProxy};
+use crate::patterns::proxy::collection::Collection;
+
+/// The body of the item notifications sent by a `CollectionProxy` or a `KeyedCollectionProxy`.
+///
+/// See `CollectionProxy`, `KeyedCollectionProxy`
+#[derive(Clone, Debug, PartialEq)]
+pub struct CollectionItem<T, K = usize> {
+    /// The index or key of the item in the collection; for a removed item, the one it was at.
+    pub key: K,
+    /// The added or updated item, or the removed one.
+    pub item: T,
+}
+
+/// The body of the summary notification sent by a `CollectionProxy` or a
+/// `KeyedCollectionProxy` after a batch operation.
+///
+/// See `CollectionProxy`, `KeyedCollectionProxy`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CollectionSummary {
+    /// The number of items added.
+    pub added: usize,
+    /// The number of items updated.
+    pub updated: usize,
+    /// The number of items removed.
+    pub removed: usize,
+    /// The number of items in the collection after the operation.
+    pub len: usize,
+}
+
+/// An `IProxy` tending a list of `T` items, announcing the changes of each item.
+///
+/// `add`, `update` and `remove` send `ITEM_ADDED`, `ITEM_UPDATED` and `ITEM_REMOVED`, whose body
+/// is a `CollectionItem` holding the index and the item. Batch operations, `add_all`,
+/// `remove_where`, `clear` and any operations within `batch`, send a single `CHANGED`
+/// notification instead, whose body is a `CollectionSummary`. The type of every notification is
+/// the name of the `CollectionProxy`, and none is sent before it is registered with a core.
+///
+/// The data object is a `Vec<T>` snapshot of the items, copied when first requested after a
+/// change. Setting data of another type leaves the items unchanged; `try_set_data` reports it.
+///
+/// The notifications are sent while the caller holds the proxy's lock, so observers should read
+/// the items from the notification bodies rather than retrieve the proxy.
+///
+/// See `Proxy`, `CollectionItem`, `CollectionSummary`, `KeyedCollectionProxy`
+pub struct CollectionProxy<T> {
+    /// The items, with the batch and notification state, used for `IProxy` and `INotifier`
+    /// implementation.
+    collection: Collection<Vec<T>>,
+}
+
+impl<T: Clone + Send + Sync + 'static> CollectionProxy<T> {
+    /// Notification sent when an item is added.
+    pub const ITEM_ADDED: &'static str = "CollectionProxy/notes/itemAdded";
+    /// Notification sent when an item is updated.
+    pub const ITEM_UPDATED: &'static str = "CollectionProxy/notes/itemUpdated";
+    /// Notification sent when an item is removed.
+    pub const ITEM_REMOVED: &'static str = "CollectionProxy/notes/itemRemoved";
+    /// Notification sent after a batch operation.
+    pub const CHANGED: &'static str = "CollectionProxy/notes/changed";
+
+    /// Construct a new `CollectionProxy` instance.
+    ///
+    /// # Arguments
+    /// * `name` - The name this `Proxy` will be registered with (optional, defaults to `Proxy::NAME`).
+    /// * `items` - The initial items.
+    pub fn new(name: Option<&str>, items: Vec<T>) -> Self {
+        Self {
+            collection: Collection::new(name, items),
+        }
+    }
+
+    /// Get the items.
+    ///
+    /// # Returns
+    /// The items, in order.
+    pub fn items(&self) -> &[T] {
+        self.collection.items()
+    }
+
+    /// Get an item.
+    ///
+    /// # Arguments
+    /// * `index` - The index of the item.
+    ///
+    /// # Returns
+    /// The item, or `None` if `index` is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.collection.items().get(index)
+    }
+
+    /// Get the number of items.
+    ///
+    /// # Returns
+    /// The number of items.
+    pub fn len(&self) -> usize {
+        self.collection.items().len()
+    }
+
+    /// Check if there are no items.
+    ///
+    /// # Returns
+    /// `true` if there are no items, otherwise `false`.
+    pub fn is_empty(&self) -> bool {
+        self.collection.items().is_empty()
+    }
+
+    /// Find the first item matching a predicate.
+    ///
+    /// # Arguments
+    /// * `predicate` - The predicate to match.
+    ///
+    /// # Returns
+    /// The index of the first matching item, or `None` if no item matches.
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<usize> {
+        self.collection.items().iter().position(predicate)
+    }
+
+    /// Find the items matching a predicate.
+    ///
+    /// # Arguments
+    /// * `predicate` - The predicate to match.
+    ///
+    /// # Returns
+    /// The matching items, in order.
+    pub fn filter(&self, predicate: impl Fn(&T) -> bool) -> Vec<&T> {
+        self.collection.items().iter().filter(|item| predicate(item)).collect()
+    }
+
+    /// Add an item at the end, and send `ITEM_ADDED`.
+    ///
+    /// # Arguments
+    /// * `item` - The item to add.
+    ///
+    /// # Returns
+    /// The index of the added item.
+    pub fn add(&mut self, item: T) -> usize {
+        let items = self.collection.items_mut();
+        items.push(item.clone());
+        let index = items.len() - 1;
+        self.collection.item_added(Self::ITEM_ADDED, index, item);
+        index
+    }
+
+    /// Replace an item, and send `ITEM_UPDATED`.
+    ///
+    /// # Arguments
+    /// * `index` - The index of the item.
+    /// * `item` - The new item.
+    ///
+    /// # Returns
+    /// The previous item, or `None` if `index` is out of bounds, in which case nothing changes.
+    pub fn update(&mut self, index: usize, item: T) -> Option<T> {
+        if index >= self.len() { return None }
+        let previous = std::mem::replace(&mut self.collection.items_mut()[index], item.clone());
+        self.collection.item_updated(Self::ITEM_UPDATED, index, item);
+        Some(previous)
+    }
+
+    /// Remove an item, shifting the following items, and send `ITEM_REMOVED`.
+    ///
+    /// # Arguments
+    /// * `index` - The index of the item.
+    ///
+    /// # Returns
+    /// The removed item, or `None` if `index` is out of bounds, in which case nothing changes.
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.len() { return None }
+        let item = self.collection.items_mut().remove(index);
+        self.collection.item_removed(Self::ITEM_REMOVED, index, item.clone());
+        Some(item)
+    }
+
+    /// Add items at the end, and send a single `CHANGED`.
+    ///
+    /// # Arguments
+    /// * `items` - The items to add.
+    pub fn add_all(&mut self, items: impl IntoIterator<Item = T>) {
+        self.batch(|proxy| {
+            for item in items {
+                proxy.add(item);
+            }
+        });
+    }
+
+    /// Remove the items matching a predicate, and send a single `CHANGED`.
+    ///
+    /// # Arguments
+    /// * `predicate` - The predicate to match.
+    ///
+    /// # Returns
+    /// The removed items, in order.
+    pub fn remove_where(&mut self, predicate: impl Fn(&T) -> bool) -> Vec<T> {
+        let mut removed = Vec::new();
+        self.batch(|proxy| {
+            removed = proxy.collection.items_mut().extract_if(.., |item| predicate(item)).collect();
+            proxy.collection.items_removed(removed.len());
+        });
+        removed
+    }
+
+    /// Remove all items, and send a single `CHANGED`.
+    pub fn clear(&mut self) {
+        self.remove_where(|_| true);
+    }
+
+    /// Run a closure as a batch.
+    ///
+    /// The item notifications of the operations within the closure are replaced by a single
+    /// `CHANGED`, sent when the outermost batch ends if anything changed.
+    ///
+    /// # Arguments
+    /// * `f` - The closure updating the `CollectionProxy`.
+    pub fn batch(&mut self, f: impl FnOnce(&mut Self)) {
+        self.collection.begin_batch();
+        f(self);
+        self.collection.end_batch(Self::CHANGED);
+    }
+
+    /// Check if item notifications are being replaced by a summary.
+    ///
+    /// # Returns
+    /// `true` within a batch, otherwise `false`.
+    pub fn in_batch(&self) -> bool {
+        self.collection.in_batch()
+    }
+
+    /// Replace the items, and send a single `CHANGED`.
+    ///
+    /// # Arguments
+    /// * `data` - A `Vec<T>` of the new items, or `None` to clear them.
+    ///
+    /// # Returns
+    /// `Error::BodyTypeMismatch` if `data` is not a `Vec<T>`, in which case nothing changes.
+    pub fn try_set_data(&mut self, data: Option<Arc<dyn Any + Send + Sync>>) -> Result<(), Error> {
+        self.collection.try_set_data(data, "CollectionProxy::set_data", Self::CHANGED)
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> IProxy for CollectionProxy<T> {
+    /// Get the `CollectionProxy` instance's name.
+    ///
+    /// # Returns
+    /// The name of the `CollectionProxy` instance.
+    fn name(&self) -> &str {
+        self.collection.name()
+    }
+
+    /// Get the `CollectionProxy`'s data object.
+    ///
+    /// # Returns
+    /// A `Vec<T>` snapshot of the items.
+    fn data(&self) -> Option<&Arc<dyn Any + Send + Sync>> {
+        self.collection.data()
+    }
+
+    /// Replace the items, and send a single `CHANGED`.
+    ///
+    /// # Arguments
+    /// * `data` - A `Vec<T>` of the new items, or `None` to clear them; data of another type is
+    ///   ignored, see `try_set_data`.
+    fn set_data(&mut self, data: Option<Arc<dyn Any + Send + Sync>>) {
+        let _ = self.try_set_data(data);
+    }
+
+    /// Get the `CollectionProxy` as a dynamic `Any` type.
+    ///
+    /// # Returns
+    /// The `CollectionProxy` instance as a mutable `Any` reference.
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> INotifier for CollectionProxy<T> {
+    /// Get the Multiton key for this `CollectionProxy`.
+    ///
+    /// # Returns
+    /// The Multiton key of the `CollectionProxy`.
+    fn key(&self) -> &str {
+        self.collection.key()
+    }
+
+    /// Get the `IFacade` instance associated with this `CollectionProxy`.
+    ///
+    /// # Returns
+    /// The `IFacade` instance.
+    fn facade(&self) -> Arc<dyn IFacade> {
+        self.collection.facade()
+    }
+
+    /// Initialize this `CollectionProxy` instance.
+    ///
+    /// # Arguments
+    /// * `key` - The Multiton key for this `CollectionProxy`.
+    fn initialize_notifier(&mut self, key: &str) {
+        self.collection.initialize_notifier(key);
+    }
+
+    /// Send a `Notification`.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the `Notification` to send.
+    /// * `body` - The body of the `Notification` (optional).
+    /// * `type_` - The type of the `Notification` (optional).
+    fn send_notification(&self, name: &str, body: Option<Arc<dyn Any + Send + Sync>>, type_: Option<&str>) {
+        self.collection.send_notification(name, body, type_);
+    }
+}
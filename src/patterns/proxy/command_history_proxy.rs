@@ -0,0 +1,374 @@
+use std::any::Any;
+use std::collections::VecDeque;
+use std::sync::{Arc, RwLock};
+use crate::interfaces::{CommandFactory, ICommand, IFacade, INotification, INotifier, IProxy, IUndoableCommand, UndoableCommandFactory};
+use crate::patterns::{Proxy, SimpleCommand};
+
+/// The state of a `CommandHistoryProxy`, sent as the body of its `CHANGED` notification.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CommandHistoryState {
+    /// The number of commands that can be undone.
+    pub undo_count: usize,
+    /// The number of commands that can be redone.
+    pub redo_count: usize,
+}
+
+/// An `IProxy` keeping the executed `IUndoableCommand`s of a Core in bounded undo and redo stacks.
+///
+/// Commands registered through `CommandHistoryProxy::recording` are pushed onto the undo stack
+/// once executed, clearing the redo stack. `undo` moves the last command to the redo stack and
+/// `redo` moves it back. When the undo stack is full, its oldest command is dropped.
+///
+/// `undo` and `redo` run the command while the caller holds the proxy's lock, so the command
+/// must not retrieve the `CommandHistoryProxy`, nor send notifications whose handlers do.
+/// `undo_registered` and `redo_registered` run it with the lock released instead.
+///
+/// Each change sends a `CHANGED` notification whose type is `PUSHED`, `UNDONE`, `REDONE` or
+/// `CLEARED`, and whose body is the new `CommandHistoryState`. The notification is sent while
+/// the caller holds the proxy's lock, so observers should read the state from the body rather
+/// than retrieve the proxy.
+///
+/// See `IUndoableCommand`, `UndoableCommand`, `IModel::register_proxy`
+pub struct CommandHistoryProxy {
+    /// The underlying `Proxy` instance used for `IProxy` and `INotifier` implementation.
+    proxy: Proxy,
+    /// The maximum number of commands kept on the undo stack.
+    capacity: usize,
+    /// The commands that can be undone, oldest first.
+    undo_stack: VecDeque<Box<dyn IUndoableCommand + Send + Sync>>,
+    /// The commands that can be redone, oldest first.
+    redo_stack: Vec<Box<dyn IUndoableCommand + Send + Sync>>,
+}
+
+impl CommandHistoryProxy {
+    /// The name of the `CommandHistoryProxy`.
+    pub const NAME: &'static str = "CommandHistoryProxy";
+
+    /// Notification sent when the undo or redo stack changes.
+    pub const CHANGED: &'static str = "CommandHistoryProxy/notes/changed";
+
+    /// Notification type sent when a command is pushed onto the undo stack.
+    pub const PUSHED: &'static str = "pushed";
+
+    /// Notification type sent when a command is undone.
+    pub const UNDONE: &'static str = "undone";
+
+    /// Notification type sent when a command is redone.
+    pub const REDONE: &'static str = "redone";
+
+    /// Notification type sent when the stacks are cleared.
+    pub const CLEARED: &'static str = "cleared";
+
+    /// Construct a new `CommandHistoryProxy` instance.
+    ///
+    /// # Arguments
+    /// * `capacity` - The maximum number of commands kept on the undo stack.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            proxy: Proxy::new(Some(Self::NAME), None),
+            capacity,
+            undo_stack: VecDeque::new(),
+            redo_stack: Vec::new(),
+        }
+    }
+
+    /// Wrap an `IUndoableCommand` factory so executed commands are pushed onto the history.
+    ///
+    /// The returned factory creates commands that execute the `IUndoableCommand`, then push it
+    /// onto the `CommandHistoryProxy` registered with the Core, if any.
+    ///
+    /// # Arguments
+    /// * `factory` - The factory creating the `IUndoableCommand`s.
+    ///
+    /// # Returns
+    /// A `CommandFactory` to register with the `IController`.
+    pub fn recording(factory: UndoableCommandFactory) -> CommandFactory {
+        Arc::new(move || Box::new(RecordingCommand { command: SimpleCommand::new(), factory: Arc::clone(&factory) }))
+    }
+
+    /// Get the maximum number of commands kept on the undo stack.
+    ///
+    /// # Returns
+    /// The capacity of the undo stack.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Get the state of the undo and redo stacks.
+    ///
+    /// # Returns
+    /// The number of commands that can be undone and redone.
+    pub fn state(&self) -> CommandHistoryState {
+        CommandHistoryState { undo_count: self.undo_stack.len(), redo_count: self.redo_stack.len() }
+    }
+
+    /// Check if a command can be undone.
+    ///
+    /// # Returns
+    /// `true` if the undo stack is not empty, otherwise `false`.
+    pub fn can_undo(&self) -> bool {
+        !self.undo_stack.is_empty()
+    }
+
+    /// Check if a command can be redone.
+    ///
+    /// # Returns
+    /// `true` if the redo stack is not empty, otherwise `false`.
+    pub fn can_redo(&self) -> bool {
+        !self.redo_stack.is_empty()
+    }
+
+    /// Push an executed command onto the undo stack, clearing the redo stack.
+    ///
+    /// # Arguments
+    /// * `command` - The executed command.
+    pub fn push(&mut self, command: Box<dyn IUndoableCommand + Send + Sync>) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.undo_stack.len() == self.capacity {
+            self.undo_stack.pop_front();
+        }
+        self.undo_stack.push_back(command);
+        self.redo_stack.clear();
+        self.changed(Self::PUSHED);
+    }
+
+    /// Undo the last command and move it to the redo stack.
+    ///
+    /// # Returns
+    /// `true` if a command was undone, `false` if the undo stack is empty.
+    pub fn undo(&mut self) -> bool {
+        let Some(mut command) = self.undo_stack.pop_back() else {
+            return false;
+        };
+        command.undo();
+        self.redo_stack.push(command);
+        self.changed(Self::UNDONE);
+        true
+    }
+
+    /// Redo the last undone command and move it back to the undo stack.
+    ///
+    /// # Returns
+    /// `true` if a command was redone, `false` if the redo stack is empty.
+    pub fn redo(&mut self) -> bool {
+        let Some(mut command) = self.redo_stack.pop() else {
+            return false;
+        };
+        command.redo();
+        self.undo_stack.push_back(command);
+        self.changed(Self::REDONE);
+        true
+    }
+
+    /// Undo the last command of the `CommandHistoryProxy` registered with a Core.
+    ///
+    /// The command is taken off the undo stack and moved to the redo stack under the proxy's
+    /// lock, but undone with the lock released, so it may retrieve the `CommandHistoryProxy`.
+    ///
+    /// # Arguments
+    /// * `facade` - The `IFacade` of the Core.
+    ///
+    /// # Returns
+    /// `true` if a command was undone, `false` if no `CommandHistoryProxy` is registered or its
+    /// undo stack is empty.
+    pub fn undo_registered(facade: &Arc<dyn IFacade>) -> bool {
+        let Some(proxy) = facade.retrieve_proxy(Self::NAME) else { return false };
+        let Some(mut command) = Self::with(&proxy, |history| history.undo_stack.pop_back()).flatten() else {
+            return false;
+        };
+        command.undo();
+        Self::with(&proxy, |history| {
+            history.redo_stack.push(command);
+            history.changed(Self::UNDONE);
+        });
+        true
+    }
+
+    /// Redo the last undone command of the `CommandHistoryProxy` registered with a Core.
+    ///
+    /// The command is taken off the redo stack and moved back to the undo stack under the
+    /// proxy's lock, but redone with the lock released, so it may retrieve the
+    /// `CommandHistoryProxy`.
+    ///
+    /// # Arguments
+    /// * `facade` - The `IFacade` of the Core.
+    ///
+    /// # Returns
+    /// `true` if a command was redone, `false` if no `CommandHistoryProxy` is registered or its
+    /// redo stack is empty.
+    pub fn redo_registered(facade: &Arc<dyn IFacade>) -> bool {
+        let Some(proxy) = facade.retrieve_proxy(Self::NAME) else { return false };
+        let Some(mut command) = Self::with(&proxy, |history| history.redo_stack.pop()).flatten() else {
+            return false;
+        };
+        command.redo();
+        Self::with(&proxy, |history| {
+            history.undo_stack.push_back(command);
+            history.changed(Self::REDONE);
+        });
+        true
+    }
+
+    /// Run a closure against a registered `CommandHistoryProxy`, holding its lock.
+    ///
+    /// # Arguments
+    /// * `proxy` - The registered proxy.
+    /// * `f` - The closure to run.
+    ///
+    /// # Returns
+    /// The result of the closure, or `None` if the lock is poisoned or the proxy is not a
+    /// `CommandHistoryProxy`.
+    fn with<R>(proxy: &Arc<RwLock<dyn IProxy>>, f: impl FnOnce(&mut Self) -> R) -> Option<R> {
+        let mut proxy = proxy.write().ok()?;
+        proxy.as_any().downcast_mut::<Self>().map(f)
+    }
+
+    /// Drop every command from the undo and redo stacks.
+    pub fn clear(&mut self) {
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+        self.changed(Self::CLEARED);
+    }
+
+    /// Send the `CHANGED` notification, if the proxy is registered with a Core.
+    ///
+    /// # Arguments
+    /// * `type_` - The kind of change.
+    fn changed(&self, type_: &str) {
+        if !self.key().is_empty() {
+            self.send_notification(Self::CHANGED, Some(Arc::new(self.state())), Some(type_));
+        }
+    }
+}
+
+impl IProxy for CommandHistoryProxy {
+    /// Get the `CommandHistoryProxy` instance's name.
+    ///
+    /// # Returns
+    /// The name of the `CommandHistoryProxy` instance.
+    fn name(&self) -> &str {
+        self.proxy.name()
+    }
+
+    /// Get the `CommandHistoryProxy`'s data object.
+    ///
+    /// # Returns
+    /// The data object.
+    fn data(&self) -> Option<&Arc<dyn Any + Send + Sync>> {
+        self.proxy.data()
+    }
+
+    /// Set the `CommandHistoryProxy`'s data object.
+    ///
+    /// # Arguments
+    /// * `data` - The data object this `CommandHistoryProxy` will tend.
+    fn set_data(&mut self, data: Option<Arc<dyn Any + Send + Sync>>) {
+        self.proxy.set_data(data);
+    }
+
+    /// Get the `CommandHistoryProxy` as a dynamic `Any` type.
+    ///
+    /// # Returns
+    /// The `CommandHistoryProxy` instance as a mutable `Any` reference.
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+impl INotifier for CommandHistoryProxy {
+    /// Get the Multiton key for this `CommandHistoryProxy`.
+    ///
+    /// # Returns
+    /// The Multiton key of the `CommandHistoryProxy`.
+    fn key(&self) -> &str {
+        self.proxy.key()
+    }
+
+    /// Get the `IFacade` instance associated with this `CommandHistoryProxy`.
+    ///
+    /// # Returns
+    /// The `IFacade` instance.
+    fn facade(&self) -> Arc<dyn IFacade> {
+        self.proxy.facade()
+    }
+
+    /// Initialize this `CommandHistoryProxy` instance.
+    ///
+    /// # Arguments
+    /// * `key` - The Multiton key for this `CommandHistoryProxy`.
+    fn initialize_notifier(&mut self, key: &str) {
+        self.proxy.initialize_notifier(key);
+    }
+
+    /// Send a `Notification`.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the `Notification` to send.
+    /// * `body` - The body of the `Notification` (optional).
+    /// * `type_` - The type of the `Notification` (optional).
+    fn send_notification(&self, name: &str, body: Option<Arc<dyn Any + Send + Sync>>, type_: Option<&str>) {
+        self.proxy.send_notification(name, body, type_);
+    }
+}
+
+/// An `ICommand` executing an `IUndoableCommand`, then pushing it onto the Core's history.
+struct RecordingCommand {
+    /// The underlying `SimpleCommand` instance used for `INotifier` implementation.
+    command: SimpleCommand,
+    /// The factory creating the `IUndoableCommand` to execute.
+    factory: UndoableCommandFactory,
+}
+
+impl ICommand for RecordingCommand {
+    /// Execute the `IUndoableCommand`, then push it onto the `CommandHistoryProxy`.
+    ///
+    /// # Arguments
+    /// * `notification` - The `Notification` object that triggered the execution of this `Command`.
+    fn execute(&mut self, notification: &Arc<dyn INotification>) {
+        let mut command = (self.factory)();
+        command.initialize_notifier(self.key());
+        command.execute(notification);
+
+        if let Some(proxy) = self.facade().retrieve_proxy(CommandHistoryProxy::NAME) {
+            CommandHistoryProxy::with(&proxy, |history| history.push(command));
+        }
+    }
+}
+
+impl INotifier for RecordingCommand {
+    /// Get the Multiton key for this `RecordingCommand`.
+    ///
+    /// # Returns
+    /// The Multiton key of the `RecordingCommand`.
+    fn key(&self) -> &str {
+        self.command.key()
+    }
+
+    /// Get the `IFacade` instance associated with this `RecordingCommand`.
+    ///
+    /// # Returns
+    /// The `IFacade` instance.
+    fn facade(&self) -> Arc<dyn IFacade> {
+        self.command.facade()
+    }
+
+    /// Initialize this `RecordingCommand` instance.
+    ///
+    /// # Arguments
+    /// * `key` - The Multiton key for this `RecordingCommand`.
+    fn initialize_notifier(&mut self, key: &str) {
+        self.command.initialize_notifier(key);
+    }
+
+    /// Send a `Notification`.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the `Notification` to send.
+    /// * `body` - The body of the `Notification` (optional).
+    /// * `type_` - The type of the `Notification` (optional).
+    fn send_notification(&self, name: &str, body: Option<Arc<dyn Any + Send + Sync>>, type_: Option<&str>) {
+        self.command.send_notification(name, body, type_);
+    }
+}
@@ -0,0 +1,274 @@
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use crate::Error;
+use crate::interfaces::{IFacade, INotifier, IProxy};
+use crate::patterns::proxy::collection::Collection;
+
+/// An `IProxy` tending a map of `T` items by `K` key, announcing the changes of each item.
+///
+/// `insert` sends `ITEM_ADDED` for a new key and `ITEM_UPDATED` for an existing one, and `remove`
+/// sends `ITEM_REMOVED`; their body is a `CollectionItem<T, K>` holding the key and the item.
+/// Batch operations, `insert_all`, `remove_where`, `clear` and any operations within `batch`,
+/// send a single `CHANGED` notification instead, whose body is a `CollectionSummary`. The type of
+/// every notification is the name of the `KeyedCollectionProxy`, and none is sent before it is
+/// registered with a core.
+///
+/// The items are kept sorted by key. The data object is a `BTreeMap<K, T>` snapshot of the
+/// items, copied when first requested after a change. Setting data of another type leaves the
+/// items unchanged; `try_set_data` reports it.
+///
+/// The notifications are sent while the caller holds the proxy's lock, so observers should read
+/// the items from the notification bodies rather than retrieve the proxy.
+///
+/// See `Proxy`, `CollectionItem`, `CollectionSummary`, `CollectionProxy`
+pub struct KeyedCollectionProxy<K, T> {
+    /// The items by key, with the batch and notification state, used for `IProxy` and
+    /// `INotifier` implementation.
+    collection: Collection<BTreeMap<K, T>>,
+}
+
+impl<K: Ord + Clone + Send + Sync + 'static, T: Clone + Send + Sync + 'static> KeyedCollectionProxy<K, T> {
+    /// Notification sent when an item is added.
+    pub const ITEM_ADDED: &'static str = "KeyedCollectionProxy/notes/itemAdded";
+    /// Notification sent when an item is updated.
+    pub const ITEM_UPDATED: &'static str = "KeyedCollectionProxy/notes/itemUpdated";
+    /// Notification sent when an item is removed.
+    pub const ITEM_REMOVED: &'static str = "KeyedCollectionProxy/notes/itemRemoved";
+    /// Notification sent after a batch operation.
+    pub const CHANGED: &'static str = "KeyedCollectionProxy/notes/changed";
+
+    /// Construct a new `KeyedCollectionProxy` instance.
+    ///
+    /// # Arguments
+    /// * `name` - The name this `Proxy` will be registered with (optional, defaults to `Proxy::NAME`).
+    /// * `items` - The initial items, by key.
+    pub fn new(name: Option<&str>, items: BTreeMap<K, T>) -> Self {
+        Self {
+            collection: Collection::new(name, items),
+        }
+    }
+
+    /// Get the items.
+    ///
+    /// # Returns
+    /// The items, by key.
+    pub fn items(&self) -> &BTreeMap<K, T> {
+        self.collection.items()
+    }
+
+    /// Get an item.
+    ///
+    /// # Arguments
+    /// * `key` - The key of the item.
+    ///
+    /// # Returns
+    /// The item, or `None` if no item has the key.
+    pub fn get(&self, key: &K) -> Option<&T> {
+        self.collection.items().get(key)
+    }
+
+    /// Check if an item has a key.
+    ///
+    /// # Arguments
+    /// * `key` - The key of the item.
+    ///
+    /// # Returns
+    /// `true` if an item has the key, otherwise `false`.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.collection.items().contains_key(key)
+    }
+
+    /// Get the number of items.
+    ///
+    /// # Returns
+    /// The number of items.
+    pub fn len(&self) -> usize {
+        self.collection.items().len()
+    }
+
+    /// Check if there are no items.
+    ///
+    /// # Returns
+    /// `true` if there are no items, otherwise `false`.
+    pub fn is_empty(&self) -> bool {
+        self.collection.items().is_empty()
+    }
+
+    /// Find the items matching a predicate.
+    ///
+    /// # Arguments
+    /// * `predicate` - The predicate to match, given the key and the item.
+    ///
+    /// # Returns
+    /// The keys and matching items, sorted by key.
+    pub fn filter(&self, predicate: impl Fn(&K, &T) -> bool) -> Vec<(&K, &T)> {
+        self.collection.items().iter().filter(|(key, item)| predicate(key, item)).collect()
+    }
+
+    /// Add or replace an item, and send `ITEM_ADDED` or `ITEM_UPDATED`.
+    ///
+    /// # Arguments
+    /// * `key` - The key of the item.
+    /// * `item` - The item.
+    ///
+    /// # Returns
+    /// The previous item with the key, or `None` if the item was added.
+    pub fn insert(&mut self, key: K, item: T) -> Option<T> {
+        let previous = self.collection.items_mut().insert(key.clone(), item.clone());
+        match previous {
+            Some(_) => self.collection.item_updated(Self::ITEM_UPDATED, key, item),
+            None => self.collection.item_added(Self::ITEM_ADDED, key, item),
+        }
+        previous
+    }
+
+    /// Remove an item, and send `ITEM_REMOVED`.
+    ///
+    /// # Arguments
+    /// * `key` - The key of the item.
+    ///
+    /// # Returns
+    /// The removed item, or `None` if no item has the key, in which case nothing changes.
+    pub fn remove(&mut self, key: &K) -> Option<T> {
+        let item = self.collection.items_mut().remove(key)?;
+        self.collection.item_removed(Self::ITEM_REMOVED, key.clone(), item.clone());
+        Some(item)
+    }
+
+    /// Add or replace items, and send a single `CHANGED`.
+    ///
+    /// # Arguments
+    /// * `items` - The keys and items.
+    pub fn insert_all(&mut self, items: impl IntoIterator<Item = (K, T)>) {
+        self.batch(|proxy| {
+            for (key, item) in items {
+                proxy.insert(key, item);
+            }
+        });
+    }
+
+    /// Remove the items matching a predicate, and send a single `CHANGED`.
+    ///
+    /// # Arguments
+    /// * `predicate` - The predicate to match, given the key and the item.
+    ///
+    /// # Returns
+    /// The keys and removed items, sorted by key.
+    pub fn remove_where(&mut self, predicate: impl Fn(&K, &T) -> bool) -> Vec<(K, T)> {
+        let mut removed = Vec::new();
+        self.batch(|proxy| {
+            removed = proxy.collection.items_mut().extract_if(.., |key, item| predicate(key, item)).collect();
+            proxy.collection.items_removed(removed.len());
+        });
+        removed
+    }
+
+    /// Remove all items, and send a single `CHANGED`.
+    pub fn clear(&mut self) {
+        self.remove_where(|_, _| true);
+    }
+
+    /// Run a closure as a batch.
+    ///
+    /// The item notifications of the operations within the closure are replaced by a single
+    /// `CHANGED`, sent when the outermost batch ends if anything changed.
+    ///
+    /// # Arguments
+    /// * `f` - The closure updating the `KeyedCollectionProxy`.
+    pub fn batch(&mut self, f: impl FnOnce(&mut Self)) {
+        self.collection.begin_batch();
+        f(self);
+        self.collection.end_batch(Self::CHANGED);
+    }
+
+    /// Check if item notifications are being replaced by a summary.
+    ///
+    /// # Returns
+    /// `true` within a batch, otherwise `false`.
+    pub fn in_batch(&self) -> bool {
+        self.collection.in_batch()
+    }
+
+    /// Replace the items, and send a single `CHANGED`.
+    ///
+    /// # Arguments
+    /// * `data` - A `BTreeMap<K, T>` of the new items, or `None` to clear them.
+    ///
+    /// # Returns
+    /// `Error::BodyTypeMismatch` if `data` is not a `BTreeMap<K, T>`, in which case nothing changes.
+    pub fn try_set_data(&mut self, data: Option<Arc<dyn Any + Send + Sync>>) -> Result<(), Error> {
+        self.collection.try_set_data(data, "KeyedCollectionProxy::set_data", Self::CHANGED)
+    }
+}
+
+impl<K: Ord + Clone + Send + Sync + 'static, T: Clone + Send + Sync + 'static> IProxy for KeyedCollectionProxy<K, T> {
+    /// Get the `KeyedCollectionProxy` instance's name.
+    ///
+    /// # Returns
+    /// The name of the `KeyedCollectionProxy` instance.
+    fn name(&self) -> &str {
+        self.collection.name()
+    }
+
+    /// Get the `KeyedCollectionProxy`'s data object.
+    ///
+    /// # Returns
+    /// A `BTreeMap<K, T>` snapshot of the items.
+    fn data(&self) -> Option<&Arc<dyn Any + Send + Sync>> {
+        self.collection.data()
+    }
+
+    /// Replace the items, and send a single `CHANGED`.
+    ///
+    /// # Arguments
+    /// * `data` - A `BTreeMap<K, T>` of the new items, or `None` to clear them; data of another
+    ///   type is ignored, see `try_set_data`.
+    fn set_data(&mut self, data: Option<Arc<dyn Any + Send + Sync>>) {
+        let _ = self.try_set_data(data);
+    }
+
+    /// Get the `KeyedCollectionProxy` as a dynamic `Any` type.
+    ///
+    /// # Returns
+    /// The `KeyedCollectionProxy` instance as a mutable `Any` reference.
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+impl<K: Ord + Clone + Send + Sync + 'static, T: Clone + Send + Sync + 'static> INotifier for KeyedCollectionProxy<K, T> {
+    /// Get the Multiton key for this `KeyedCollectionProxy`.
+    ///
+    /// # Returns
+    /// The Multiton key of the `KeyedCollectionProxy`.
+    fn key(&self) -> &str {
+        self.collection.key()
+    }
+
+    /// Get the `IFacade` instance associated with this `KeyedCollectionProxy`.
+    ///
+    /// # Returns
+    /// The `IFacade` instance.
+    fn facade(&self) -> Arc<dyn IFacade> {
+        self.collection.facade()
+    }
+
+    /// Initialize this `KeyedCollectionProxy` instance.
+    ///
+    /// # Arguments
+    /// * `key` - The Multiton key for this `KeyedCollectionProxy`.
+    fn initialize_notifier(&mut self, key: &str) {
+        self.collection.initialize_notifier(key);
+    }
+
+    /// Send a `Notification`.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the `Notification` to send.
+    /// * `body` - The body of the `Notification` (optional).
+    /// * `type_` - The type of the `Notification` (optional).
+    fn send_notification(&self, name: &str, body: Option<Arc<dyn Any + Send + Sync>>, type_: Option<&str>) {
+        self.collection.send_notification(name, body, type_);
+    }
+}
@@ -1,2 +1,24 @@
 mod proxy;
 pub use proxy::Proxy;
+
+mod collection;
+
+mod collection_proxy;
+pub use collection_proxy::{CollectionItem, CollectionProxy, CollectionSummary};
+
+mod command_history_proxy;
+pub use command_history_proxy::{CommandHistoryProxy, CommandHistoryState};
+
+mod keyed_collection_proxy;
+pub use keyed_collection_proxy::KeyedCollectionProxy;
+
+mod observable_proxy;
+pub use observable_proxy::{DataChange, ObservableProxy};
+
+#[cfg(feature = "persistence")]
+mod persistent_proxy;
+#[cfg(feature = "persistence")]
+pub use persistent_proxy::PersistentProxy;
+
+mod typed_proxy;
+pub use typed_proxy::{ProxyReadGuard, ProxyWriteGuard, TypedProxy};
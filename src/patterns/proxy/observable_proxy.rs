@@ -0,0 +1,223 @@
+use std::any::{type_name, Any};
+use std::sync::Arc;
+use crate::Error;
+use crate::interfaces::{IFacade, INotifier, IProxy};
+use crate::patterns::Proxy;
+
+/// The body of the notification sent by an `ObservableProxy` when its data changes.
+///
+/// See `ObservableProxy`
+#[derive(Clone, Default)]
+pub struct DataChange {
+    /// The data object before the change.
+    pub old: Option<Arc<dyn Any + Send + Sync>>,
+    /// The data object after the change.
+    pub new: Option<Arc<dyn Any + Send + Sync>>,
+}
+
+impl DataChange {
+    /// Get the data object before the change as a given type.
+    ///
+    /// # Returns
+    /// The old data object, or an `Error` if there was none or it is not a `T`.
+    pub fn old_as<T: Any + Send + Sync>(&self) -> Result<Arc<T>, Error> {
+        Self::downcast(&self.old, "DataChange::old")
+    }
+
+    /// Get the data object after the change as a given type.
+    ///
+    /// # Returns
+    /// The new data object, or an `Error` if there is none or it is not a `T`.
+    pub fn new_as<T: Any + Send + Sync>(&self) -> Result<Arc<T>, Error> {
+        Self::downcast(&self.new, "DataChange::new")
+    }
+
+    /// Downcast a data object.
+    ///
+    /// # Arguments
+    /// * `data` - The data object.
+    /// * `name` - The name of the data object, reported in errors.
+    fn downcast<T: Any + Send + Sync>(data: &Option<Arc<dyn Any + Send + Sync>>, name: &str) -> Result<Arc<T>, Error> {
+        data.clone()
+            .ok_or_else(|| Error::MissingBody { notification: name.into() })
+            .and_then(|data| data.downcast::<T>()
+                .map_err(|_| Error::BodyTypeMismatch { notification: name.into(), expected: type_name::<T>() }))
+    }
+}
+
+/// An `IProxy` announcing the changes of its data object.
+///
+/// Each call to `set_data` sends a notification, `DATA_CHANGED` unless configured otherwise,
+/// whose body is a `DataChange` holding the old and new data objects, and whose type is the name
+/// of the `ObservableProxy`. No notification is sent before the `ObservableProxy` is registered
+/// with a core.
+///
+/// Between `begin_batch` and the matching `end_batch`, or within `batch`, updates are coalesced:
+/// a single notification is sent when the outermost batch ends, from the data object before the
+/// batch to the data object after it.
+///
+/// The notification is sent while the caller holds the proxy's lock, so observers should read
+/// the data from the `DataChange` rather than retrieve the proxy.
+///
+/// See `Proxy`, `DataChange`
+pub struct ObservableProxy {
+    /// The underlying `Proxy` instance used for `IProxy` and `INotifier` implementation.
+    proxy: Proxy,
+    /// The name of the notification sent when the data object changes.
+    notification_name: String,
+    /// The nesting depth of the current batch, or 0 outside of a batch.
+    batch_depth: usize,
+    /// The data object before the current batch, if it has changed within the batch.
+    batch_old: Option<Option<Arc<dyn Any + Send + Sync>>>,
+}
+
+impl ObservableProxy {
+    /// Notification sent when the data object changes, unless configured otherwise.
+    pub const DATA_CHANGED: &'static str = "ObservableProxy/notes/dataChanged";
+
+    /// Construct a new `ObservableProxy` instance.
+    ///
+    /// # Arguments
+    /// * `name` - The name this `Proxy` will be registered with (optional, defaults to `Proxy::NAME`).
+    /// * `data` - The data object (optional).
+    /// * `notification_name` - The name of the notification sent when the data object changes (optional, defaults to `DATA_CHANGED`).
+    pub fn new(name: Option<&str>, data: Option<Arc<dyn Any + Send + Sync>>, notification_name: Option<&str>) -> Self {
+        Self {
+            proxy: Proxy::new(name, data),
+            notification_name: notification_name.unwrap_or(Self::DATA_CHANGED).into(),
+            batch_depth: 0,
+            batch_old: None,
+        }
+    }
+
+    /// Get the name of the notification sent when the data object changes.
+    ///
+    /// # Returns
+    /// The notification name.
+    pub fn notification_name(&self) -> &str {
+        &self.notification_name
+    }
+
+    /// Start coalescing updates until the matching `end_batch`.
+    ///
+    /// Batches may be nested; only the outermost one sends a notification.
+    pub fn begin_batch(&mut self) {
+        self.batch_depth += 1;
+    }
+
+    /// End a batch started with `begin_batch`.
+    ///
+    /// If this ends the outermost batch and the data object was set within it, sends a single
+    /// notification from the data object before the batch to the current one.
+    pub fn end_batch(&mut self) {
+        if self.batch_depth == 0 { return }
+        self.batch_depth -= 1;
+        if self.batch_depth == 0 && let Some(old) = self.batch_old.take() {
+            self.data_changed(old);
+        }
+    }
+
+    /// Run a closure as a batch.
+    ///
+    /// # Arguments
+    /// * `f` - The closure updating the `ObservableProxy`.
+    pub fn batch(&mut self, f: impl FnOnce(&mut Self)) {
+        self.begin_batch();
+        f(self);
+        self.end_batch();
+    }
+
+    /// Check if updates are being coalesced.
+    ///
+    /// # Returns
+    /// `true` within a batch, otherwise `false`.
+    pub fn in_batch(&self) -> bool {
+        self.batch_depth > 0
+    }
+
+    /// Send the data changed notification, if the proxy is registered with a core.
+    ///
+    /// # Arguments
+    /// * `old` - The data object before the change.
+    fn data_changed(&self, old: Option<Arc<dyn Any + Send + Sync>>) {
+        if self.key().is_empty() { return }
+        let change = DataChange { old, new: self.proxy.data().cloned() };
+        self.send_notification(&self.notification_name, Some(Arc::new(change)), Some(self.proxy.name()));
+    }
+}
+
+impl IProxy for ObservableProxy {
+    /// Get the `ObservableProxy` instance's name.
+    ///
+    /// # Returns
+    /// The name of the `ObservableProxy` instance.
+    fn name(&self) -> &str {
+        self.proxy.name()
+    }
+
+    /// Get the `ObservableProxy`'s data object.
+    ///
+    /// # Returns
+    /// The data object.
+    fn data(&self) -> Option<&Arc<dyn Any + Send + Sync>> {
+        self.proxy.data()
+    }
+
+    /// Set the `ObservableProxy`'s data object and announce the change.
+    ///
+    /// # Arguments
+    /// * `data` - The data object this `ObservableProxy` will tend.
+    fn set_data(&mut self, data: Option<Arc<dyn Any + Send + Sync>>) {
+        let old = self.proxy.data().cloned();
+        self.proxy.set_data(data);
+        if self.batch_depth > 0 {
+            self.batch_old.get_or_insert(old);
+        } else {
+            self.data_changed(old);
+        }
+    }
+
+    /// Get the `ObservableProxy` as a dynamic `Any` type.
+    ///
+    /// # Returns
+    /// The `ObservableProxy` instance as a mutable `Any` reference.
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+impl INotifier for ObservableProxy {
+    /// Get the Multiton key for this `ObservableProxy`.
+    ///
+    /// # Returns
+    /// The Multiton key of the `ObservableProxy`.
+    fn key(&self) -> &str {
+        self.proxy.key()
+    }
+
+    /// Get the `IFacade` instance associated with this `ObservableProxy`.
+    ///
+    /// # Returns
+    /// The `IFacade` instance.
+    fn facade(&self) -> Arc<dyn IFacade> {
+        self.proxy.facade()
+    }
+
+    /// Initialize this `ObservableProxy` instance.
+    ///
+    /// # Arguments
+    /// * `key` - The Multiton key for this `ObservableProxy`.
+    fn initialize_notifier(&mut self, key: &str) {
+        self.proxy.initialize_notifier(key);
+    }
+
+    /// Send a `Notification`.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the `Notification` to send.
+    /// * `body` - The body of the `Notification` (optional).
+    /// * `type_` - The type of the `Notification` (optional).
+    fn send_notification(&self, name: &str, body: Option<Arc<dyn Any + Send + Sync>>, type_: Option<&str>) {
+        self.proxy.send_notification(name, body, type_);
+    }
+}
@@ -0,0 +1,158 @@
+use std::any::{type_name, Any};
+use std::marker::PhantomData;
+use std::sync::Arc;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use crate::Error;
+use crate::interfaces::{IFacade, INotifier, IPersistentProxy, IProxy};
+use crate::patterns::Proxy;
+
+/// An `IPersistentProxy` whose data object is a serializable `T`.
+///
+/// Register it with a core whose `IModel` has an `IStore` to have its data restored on
+/// registration and saved on removal, without writing any save or load code.
+///
+/// See `IPersistentProxy`, `IStore`, `Proxy`
+pub struct PersistentProxy<T> {
+    /// The underlying `Proxy` instance used for `IProxy` and `INotifier` implementation.
+    proxy: Proxy,
+    /// The type of the data object.
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T: Serialize + DeserializeOwned + Send + Sync + 'static> PersistentProxy<T> {
+    /// Construct a new `PersistentProxy` instance.
+    ///
+    /// # Arguments
+    /// * `name` - The name this `Proxy` will be registered with, and its data saved under.
+    /// * `data` - The data object used until data is restored (optional).
+    pub fn new(name: &str, data: Option<T>) -> Self {
+        Self {
+            proxy: Proxy::new(Some(name), data.map(|data| Arc::new(data) as Arc<dyn Any + Send + Sync>)),
+            marker: PhantomData,
+        }
+    }
+
+    /// Get the data object.
+    ///
+    /// # Returns
+    /// The data object, or `None` if there is none or it is not a `T`.
+    pub fn value(&self) -> Option<Arc<T>> {
+        self.proxy.data().cloned().and_then(|data| data.downcast::<T>().ok())
+    }
+
+    /// Set the data object.
+    ///
+    /// # Arguments
+    /// * `value` - The data object (optional).
+    pub fn set_value(&mut self, value: Option<T>) {
+        self.proxy.set_data(value.map(|value| Arc::new(value) as Arc<dyn Any + Send + Sync>));
+    }
+}
+
+impl<T: Serialize + DeserializeOwned + Send + Sync + 'static> IPersistentProxy for PersistentProxy<T> {
+    /// Convert the data object to its serializable form.
+    ///
+    /// # Returns
+    /// The serializable data, `None` if there is no data object, or `Error::Persistence` if the
+    /// data object is not a `T` or cannot be serialized.
+    fn save_data(&self) -> Result<Option<Value>, Error> {
+        let Some(data) = self.proxy.data() else { return Ok(None) };
+        let error = |message: String| Error::Persistence { key: self.persistence_key(), message };
+        let data = data.downcast_ref::<T>().ok_or_else(|| error(format!("data is not of type {}", type_name::<T>())))?;
+        serde_json::to_value(data).map(Some).map_err(|e| error(e.to_string()))
+    }
+
+    /// Replace the data object with one converted back from its serializable form.
+    ///
+    /// # Arguments
+    /// * `data` - The serializable data.
+    ///
+    /// # Returns
+    /// `Error::Persistence` if the data is not a valid `T`.
+    fn restore_data(&mut self, data: Value) -> Result<(), Error> {
+        let value = serde_json::from_value::<T>(data)
+            .map_err(|e| Error::Persistence { key: self.persistence_key(), message: e.to_string() })?;
+        self.set_value(Some(value));
+        Ok(())
+    }
+}
+
+impl<T: Serialize + DeserializeOwned + Send + Sync + 'static> IProxy for PersistentProxy<T> {
+    /// Get the `PersistentProxy` instance's name.
+    ///
+    /// # Returns
+    /// The name of the `PersistentProxy` instance.
+    fn name(&self) -> &str {
+        self.proxy.name()
+    }
+
+    /// Get the `PersistentProxy`'s data object.
+    ///
+    /// # Returns
+    /// The data object.
+    fn data(&self) -> Option<&Arc<dyn Any + Send + Sync>> {
+        self.proxy.data()
+    }
+
+    /// Set the `PersistentProxy`'s data object.
+    ///
+    /// # Arguments
+    /// * `data` - The data object this `PersistentProxy` will tend, which should be a `T`.
+    fn set_data(&mut self, data: Option<Arc<dyn Any + Send + Sync>>) {
+        self.proxy.set_data(data);
+    }
+
+    /// Get the `PersistentProxy` as a dynamic `Any` type.
+    ///
+    /// # Returns
+    /// The `PersistentProxy` instance as a mutable `Any` reference.
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    /// Get the `PersistentProxy` as an `IPersistentProxy`.
+    ///
+    /// # Returns
+    /// The `PersistentProxy` instance.
+    fn as_persistent(&mut self) -> Option<&mut dyn IPersistentProxy> {
+        Some(self)
+    }
+}
+
+impl<T: Serialize + DeserializeOwned + Send + Sync + 'static> INotifier for PersistentProxy<T> {
+    /// Get the Multiton key for this `PersistentProxy`.
+    ///
+    /// # Returns
+    /// The Multiton key of the `PersistentProxy`.
+    fn key(&self) -> &str {
+        self.proxy.key()
+    }
+
+    /// Get the `IFacade` instance associated with this `PersistentProxy`.
+    ///
+    /// # Returns
+    /// The `IFacade` instance.
+    fn facade(&self) -> Arc<dyn IFacade> {
+        self.proxy.facade()
+    }
+
+    /// Initialize this `PersistentProxy` instance.
+    ///
+    /// # Arguments
+    /// * `key` - The Multiton key for this `PersistentProxy`.
+    fn initialize_notifier(&mut self, key: &str) {
+        self.proxy.initialize_notifier(key);
+    }
+
+    /// Send a `Notification`.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the `Notification` to send.
+    /// * `body` - The body of the `Notification` (optional).
+    /// * `type_` - The type of the `Notification` (optional).
+    fn send_notification(&self, name: &str, body: Option<Arc<dyn Any + Send + Sync>>, type_: Option<&str>) {
+        self.proxy.send_notification(name, body, type_);
+    }
+}
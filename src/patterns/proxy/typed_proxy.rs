@@ -0,0 +1,131 @@
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use crate::Error;
+use crate::interfaces::{IFacade, IModel, IProxy};
+
+/// A registered `IProxy` known to be of the concrete type `T`.
+///
+/// Returned by `retrieve_proxy_as` on `IModel` and `IFacade`, it locks the `IProxy` for reading
+/// or writing and hands out guards dereferencing to `T`, so read-only access does not need the
+/// write lock that `IProxy::as_any` requires.
+///
+/// See `IModel`, `IFacade`, `IProxy`
+pub struct TypedProxy<T: IProxy> {
+    /// The registered `IProxy`.
+    proxy: Arc<RwLock<dyn IProxy>>,
+    /// The concrete type of the `IProxy`.
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T: IProxy> TypedProxy<T> {
+    /// Wrap a registered `IProxy`, if it is a `T`.
+    ///
+    /// # Arguments
+    /// * `proxy` - The registered `IProxy`.
+    ///
+    /// # Returns
+    /// The `TypedProxy`, or `None` if the `IProxy` is not a `T` or its lock is poisoned.
+    pub fn new(proxy: Arc<RwLock<dyn IProxy>>) -> Option<Self> {
+        let is_t = proxy.read().ok()?.as_any_ref().is::<T>();
+        is_t.then_some(Self { proxy, marker: PhantomData })
+    }
+
+    /// Get the registered `IProxy`.
+    ///
+    /// # Returns
+    /// The `IProxy` as registered with the `IModel`.
+    pub fn proxy(&self) -> &Arc<RwLock<dyn IProxy>> {
+        &self.proxy
+    }
+
+    /// Lock the `IProxy` for reading, blocking until no writer holds it.
+    ///
+    /// # Returns
+    /// A guard dereferencing to `T`, or `Error::LockPoisoned` if the lock is poisoned.
+    pub fn read(&self) -> Result<ProxyReadGuard<'_, T>, Error> {
+        self.proxy.read()
+            .map(|guard| ProxyReadGuard { guard, marker: PhantomData })
+            .map_err(|_| Error::LockPoisoned { lock: "Proxy" })
+    }
+
+    /// Lock the `IProxy` for writing, blocking until no reader or writer holds it.
+    ///
+    /// # Returns
+    /// A guard dereferencing to `T`, or `Error::LockPoisoned` if the lock is poisoned.
+    pub fn write(&self) -> Result<ProxyWriteGuard<'_, T>, Error> {
+        self.proxy.write()
+            .map(|guard| ProxyWriteGuard { guard, marker: PhantomData })
+            .map_err(|_| Error::LockPoisoned { lock: "Proxy" })
+    }
+}
+
+impl<T: IProxy> Clone for TypedProxy<T> {
+    fn clone(&self) -> Self {
+        Self { proxy: Arc::clone(&self.proxy), marker: PhantomData }
+    }
+}
+
+/// A read guard of a `TypedProxy`, dereferencing to the concrete `IProxy` type.
+pub struct ProxyReadGuard<'a, T: IProxy> {
+    /// The read guard of the `IProxy`.
+    guard: RwLockReadGuard<'a, dyn IProxy>,
+    /// The concrete type of the `IProxy`.
+    marker: PhantomData<&'a T>,
+}
+
+impl<T: IProxy> Deref for ProxyReadGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.guard.downcast_ref::<T>().expect("TypedProxy holds a proxy of type T")
+    }
+}
+
+/// A write guard of a `TypedProxy`, dereferencing to the concrete `IProxy` type.
+pub struct ProxyWriteGuard<'a, T: IProxy> {
+    /// The write guard of the `IProxy`.
+    guard: RwLockWriteGuard<'a, dyn IProxy>,
+    /// The concrete type of the `IProxy`.
+    marker: PhantomData<&'a mut T>,
+}
+
+impl<T: IProxy> Deref for ProxyWriteGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.guard.downcast_ref::<T>().expect("TypedProxy holds a proxy of type T")
+    }
+}
+
+impl<T: IProxy> DerefMut for ProxyWriteGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.guard.downcast_mut::<T>().expect("TypedProxy holds a proxy of type T")
+    }
+}
+
+impl dyn IModel {
+    /// Retrieve a `Proxy` instance from the `IModel` as a given concrete type.
+    ///
+    /// # Arguments
+    /// * `proxy_name` - The name of the `Proxy` instance to retrieve.
+    ///
+    /// # Returns
+    /// The `Proxy` instance, or `None` if none is registered with the given name or it is not a `T`.
+    pub fn retrieve_proxy_as<T: IProxy>(&self, proxy_name: &str) -> Option<TypedProxy<T>> {
+        self.retrieve_proxy(proxy_name).and_then(TypedProxy::new)
+    }
+}
+
+impl dyn IFacade {
+    /// Retrieve a `Proxy` instance from the `Model` as a given concrete type.
+    ///
+    /// # Arguments
+    /// * `proxy_name` - The name of the `Proxy` instance to retrieve.
+    ///
+    /// # Returns
+    /// The `Proxy` instance, or `None` if none is registered with the given name or it is not a `T`.
+    pub fn retrieve_proxy_as<T: IProxy>(&self, proxy_name: &str) -> Option<TypedProxy<T>> {
+        self.retrieve_proxy(proxy_name).and_then(TypedProxy::new)
+    }
+}
@@ -0,0 +1,248 @@
+use std::any::Any;
+use std::sync::{Arc, Weak};
+use std::thread;
+use crate::interfaces::{IFacade, IMediator, INotification, INotifier};
+use crate::patterns::{BodyCodecRegistry, Facade, Mediator, Notification};
+use crate::utilities::bridge::BridgeStream;
+
+/// Bridge Mediator.
+///
+/// An `IMediator` connecting its core to a core in another process over a `BridgeStream`. Each
+/// end of the connection registers a `BridgeMediator` with its core.
+///
+/// The `BridgeMediator` is interested in the forwarded notification names given to it: it
+/// encodes each such `Notification` with its `BodyCodecRegistry` and writes it to the stream.
+/// Once registered, it reads the `Notification`s forwarded by the other end on a background
+/// thread, decodes them with the same registry, and injects them into its core with
+/// `IFacade::notify_observers`. Injected `Notification`s are never forwarded back, so both ends
+/// may forward the same names.
+///
+/// `Notification`s that cannot be encoded, for instance because no codec is registered for a
+/// body, or written, are reported with a `SEND_FAILED` `Notification`. Forwarded
+/// `Notification`s that cannot be read or decoded are reported with a `RECEIVE_FAILED`
+/// `Notification`. The body of both is the `Error` and their type is the name of the
+/// `BridgeMediator`, so interceptors and observers of the core can log them; neither is ever
+/// forwarded.
+/// Removing the `BridgeMediator` shuts the stream down, which stops the
+/// background threads at both ends.
+///
+/// See `BridgeStream`, `BodyCodecRegistry`, `Mediator`
+pub struct BridgeMediator {
+    /// The underlying `Mediator` instance.
+    mediator: Mediator,
+    /// The stream forwarded `Notification`s are written to.
+    stream: BridgeStream,
+    /// The names of the `Notification`s forwarded to the other end.
+    forwarded: Vec<String>,
+    /// The codecs of the `Notification` bodies.
+    codecs: Arc<BodyCodecRegistry>,
+}
+
+impl BridgeMediator {
+    /// The default name for a `BridgeMediator` instance.
+    pub const NAME: &'static str = "BridgeMediator";
+    /// Notification sent when a `Notification` cannot be forwarded to the other end.
+    pub const SEND_FAILED: &'static str = "BridgeMediator/notes/sendFailed";
+    /// Notification sent when a `Notification` forwarded by the other end cannot be received.
+    pub const RECEIVE_FAILED: &'static str = "BridgeMediator/notes/receiveFailed";
+
+    /// Construct a new `BridgeMediator` instance.
+    ///
+    /// # Arguments
+    /// * `name` - The name this `Mediator` will be registered with (optional, defaults to `NAME`).
+    /// * `stream` - The connection to the other end.
+    /// * `forwarded` - The names of the `Notification`s to forward to the other end, other than
+    ///   `SEND_FAILED` and `RECEIVE_FAILED`.
+    /// * `codecs` - The codecs of the `Notification` bodies, registered alike at both ends.
+    pub fn new(name: Option<&str>, stream: impl Into<BridgeStream>, forwarded: &[&str], codecs: Arc<BodyCodecRegistry>) -> Self {
+        Self {
+            mediator: Mediator::new(Some(name.unwrap_or(Self::NAME)), None),
+            stream: stream.into(),
+            forwarded: forwarded.iter()
+                .filter(|name| ![Self::SEND_FAILED, Self::RECEIVE_FAILED].contains(name))
+                .map(|name| name.to_string())
+                .collect(),
+            codecs,
+        }
+    }
+
+    /// Read the `Notification`s forwarded by the other end and inject them into a core.
+    ///
+    /// Returns when the stream is closed or the core is removed. A `Notification` that cannot be
+    /// read or decoded is reported with `RECEIVE_FAILED`.
+    ///
+    /// # Arguments
+    /// * `key` - The Multiton key of the core.
+    /// * `name` - The name of the `BridgeMediator`.
+    /// * `stream` - The stream to read from.
+    /// * `codecs` - The codecs of the `Notification` bodies.
+    fn receive(key: String, name: String, mut stream: BridgeStream, codecs: Arc<BodyCodecRegistry>) {
+        while let Some(serialized) = stream.read_notification() {
+            let Some(facade) = Facade::try_get_instance(&key) else { break };
+            let notification = match serialized.and_then(|serialized| codecs.decode(serialized)) {
+                Ok(notification) => Notification::new(notification.name(), notification.body().cloned(), notification.get_type()),
+                Err(error) => Notification::new(Self::RECEIVE_FAILED, Some(Arc::new(error)), Some(&name)),
+            };
+            facade.notify_observers(&(Arc::new(RemoteNotification(notification)) as Arc<dyn INotification>));
+        }
+    }
+}
+
+impl IMediator for BridgeMediator {
+    /// Get the `BridgeMediator` instance's name.
+    ///
+    /// # Returns
+    /// The name of the `BridgeMediator` instance.
+    fn name(&self) -> &str {
+        self.mediator.name()
+    }
+
+    /// Get the `BridgeMediator`'s view component.
+    ///
+    /// # Returns
+    /// The view component associated with the `BridgeMediator`.
+    fn component(&self) -> Option<&Weak<dyn Any + Send + Sync>> {
+        self.mediator.component()
+    }
+
+    /// Set the `BridgeMediator`'s view component.
+    ///
+    /// # Arguments
+    /// * `component` - The view component.
+    fn set_component(&mut self, component: Option<Weak<dyn Any + Send + Sync>>) {
+        self.mediator.set_component(component);
+    }
+
+    /// List `Notification` interests.
+    ///
+    /// # Returns
+    /// The names of the `Notification`s forwarded to the other end.
+    fn list_notification_interests(&self) -> Vec<String> {
+        self.forwarded.clone()
+    }
+
+    /// Forward a `Notification` to the other end, unless it was received from it.
+    ///
+    /// A `Notification` that cannot be encoded or written is reported with `SEND_FAILED`.
+    ///
+    /// # Arguments
+    /// * `notification` - The `Notification` to be handled.
+    fn handle_notification(&mut self, notification: &Arc<dyn INotification>) {
+        if (notification.as_ref() as &dyn Any).is::<RemoteNotification>() { return }
+        let sent = self.codecs.encode(notification.as_ref())
+            .and_then(|serialized| self.stream.write_notification(&serialized));
+        if let Err(error) = sent {
+            self.send_notification(Self::SEND_FAILED, Some(Arc::new(error)), Some(self.name()));
+        }
+    }
+
+    /// Start receiving the `Notification`s forwarded by the other end.
+    fn on_register(&mut self) {
+        if let Ok(stream) = self.stream.try_clone() {
+            let key = self.key().to_string();
+            let name = self.name().to_string();
+            let codecs = Arc::clone(&self.codecs);
+            thread::spawn(move || Self::receive(key, name, stream, codecs));
+        }
+    }
+
+    /// Shut the stream down, ending the connection at both ends.
+    fn on_remove(&mut self) {
+        self.stream.shutdown();
+    }
+
+    /// Get the `BridgeMediator` as a dynamic `Any` type.
+    ///
+    /// # Returns
+    /// The `BridgeMediator` instance as a mutable `Any` reference.
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+impl INotifier for BridgeMediator {
+    /// Get the Multiton key for this `BridgeMediator`.
+    ///
+    /// # Returns
+    /// The Multiton key of the `BridgeMediator`.
+    fn key(&self) -> &str {
+        self.mediator.key()
+    }
+
+    /// Get the `IFacade` instance associated with this `BridgeMediator`.
+    ///
+    /// # Returns
+    /// The `IFacade` instance.
+    fn facade(&self) -> Arc<dyn IFacade> {
+        self.mediator.facade()
+    }
+
+    /// Initialize this `BridgeMediator` instance.
+    ///
+    /// # Arguments
+    /// * `key` - The Multiton key for this `BridgeMediator`.
+    fn initialize_notifier(&mut self, key: &str) {
+        self.mediator.initialize_notifier(key);
+    }
+
+    /// Send a `Notification`.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the `Notification` to send.
+    /// * `body` - The body of the `Notification` (optional).
+    /// * `type_` - The type of the `Notification` (optional).
+    fn send_notification(&self, name: &str, body: Option<Arc<dyn Any + Send + Sync>>, type_: Option<&str>) {
+        self.mediator.send_notification(name, body, type_);
+    }
+}
+
+/// A `Notification` received from the other end of a bridge, marked so it is not forwarded back.
+struct RemoteNotification(Notification);
+
+impl INotification for RemoteNotification {
+    /// Get the name of the `Notification`.
+    fn name(&self) -> &str {
+        self.0.name()
+    }
+
+    /// Get the body of the `Notification`.
+    fn body(&self) -> Option<&Arc<dyn Any + Send + Sync>> {
+        self.0.body()
+    }
+
+    /// Set the body of the `Notification`.
+    ///
+    /// # Arguments
+    /// * `body` - The body of the `Notification`.
+    fn set_body(&mut self, body: Option<Arc<dyn Any + Send + Sync>>) {
+        self.0.set_body(body);
+    }
+
+    /// Get the type of the `Notification`.
+    fn get_type(&self) -> Option<&str> {
+        self.0.get_type()
+    }
+
+    /// Set the type of the `Notification`.
+    ///
+    /// # Arguments
+    /// * `type_` - The type of the `Notification`.
+    fn set_type(&mut self, type_: Option<String>) {
+        self.0.set_type(type_);
+    }
+
+    /// Convert the `Notification` to a string representation.
+    fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Stop the `Notification` from being delivered to any further `Observer`s.
+    fn stop_propagation(&self) {
+        self.0.stop_propagation();
+    }
+
+    /// Check if the `Notification` has been stopped from being delivered to further `Observer`s.
+    fn is_propagation_stopped(&self) -> bool {
+        self.0.is_propagation_stopped()
+    }
+}
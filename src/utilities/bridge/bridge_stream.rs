@@ -0,0 +1,126 @@
+use std::io::{self, Read, Write};
+use std::net::{Shutdown, TcpStream};
+#[cfg(unix)]
+use std::os::unix::net::UnixStream;
+use crate::Error;
+use crate::patterns::SerializedNotification;
+
+/// The largest frame a `BridgeStream` accepts, guarding against corrupt length prefixes.
+const MAX_FRAME_LEN: usize = 64 * 1024 * 1024;
+
+/// A local socket connecting two `BridgeMediator`s.
+///
+/// Notifications travel as frames: a 4-byte big-endian length followed by the
+/// `SerializedNotification` in MessagePack.
+///
+/// See `BridgeMediator`, `SerializedNotification`
+pub enum BridgeStream {
+    /// A TCP connection, typically over the loopback interface.
+    Tcp(TcpStream),
+    /// A Unix domain socket connection.
+    #[cfg(unix)]
+    Unix(UnixStream),
+}
+
+impl BridgeStream {
+    /// Create a new handle to the same socket.
+    ///
+    /// # Returns
+    /// The new `BridgeStream`, or the I/O error reported by the operating system.
+    pub fn try_clone(&self) -> io::Result<Self> {
+        match self {
+            BridgeStream::Tcp(stream) => stream.try_clone().map(BridgeStream::Tcp),
+            #[cfg(unix)]
+            BridgeStream::Unix(stream) => stream.try_clone().map(BridgeStream::Unix),
+        }
+    }
+
+    /// Shut down both directions of the socket, ending the reads of every handle to it.
+    pub fn shutdown(&self) {
+        let _ = match self {
+            BridgeStream::Tcp(stream) => stream.shutdown(Shutdown::Both),
+            #[cfg(unix)]
+            BridgeStream::Unix(stream) => stream.shutdown(Shutdown::Both),
+        };
+    }
+
+    /// Write a `SerializedNotification` as one frame.
+    ///
+    /// # Arguments
+    /// * `notification` - The `SerializedNotification` to write.
+    ///
+    /// # Returns
+    /// `Error::Serialization` if the `SerializedNotification` cannot be encoded or written.
+    pub fn write_notification(&mut self, notification: &SerializedNotification) -> Result<(), Error> {
+        let bytes = notification.to_bytes()?;
+        let error = |error: io::Error| Error::Serialization { notification: notification.name.clone(), message: error.to_string() };
+        let len = u32::try_from(bytes.len())
+            .map_err(|_| error(io::Error::new(io::ErrorKind::InvalidInput, "frame too large")))?;
+
+        let mut frame = Vec::with_capacity(4 + bytes.len());
+        frame.extend_from_slice(&len.to_be_bytes());
+        frame.extend_from_slice(&bytes);
+        self.write_all(&frame).and_then(|_| self.flush()).map_err(error)
+    }
+
+    /// Read one frame as a `SerializedNotification`, blocking until it arrives.
+    ///
+    /// # Returns
+    /// The `SerializedNotification`, `None` once the socket is closed or a frame is too large to
+    /// be valid, or `Error::Serialization` if the frame cannot be decoded.
+    pub fn read_notification(&mut self) -> Option<Result<SerializedNotification, Error>> {
+        let mut len = [0u8; 4];
+        self.read_exact(&mut len).ok()?;
+        let len = u32::from_be_bytes(len) as usize;
+        if len > MAX_FRAME_LEN {
+            self.shutdown();
+            return None;
+        }
+
+        let mut bytes = vec![0u8; len];
+        self.read_exact(&mut bytes).ok()?;
+        Some(SerializedNotification::from_bytes(&bytes))
+    }
+}
+
+impl Read for BridgeStream {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self {
+            BridgeStream::Tcp(stream) => stream.read(buf),
+            #[cfg(unix)]
+            BridgeStream::Unix(stream) => stream.read(buf),
+        }
+    }
+}
+
+impl Write for BridgeStream {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            BridgeStream::Tcp(stream) => stream.write(buf),
+            #[cfg(unix)]
+            BridgeStream::Unix(stream) => stream.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            BridgeStream::Tcp(stream) => stream.flush(),
+            #[cfg(unix)]
+            BridgeStream::Unix(stream) => stream.flush(),
+        }
+    }
+}
+
+impl From<TcpStream> for BridgeStream {
+    fn from(stream: TcpStream) -> Self {
+        let _ = stream.set_nodelay(true);
+        BridgeStream::Tcp(stream)
+    }
+}
+
+#[cfg(unix)]
+impl From<UnixStream> for BridgeStream {
+    fn from(stream: UnixStream) -> Self {
+        BridgeStream::Unix(stream)
+    }
+}
@@ -0,0 +1,5 @@
+mod bridge_stream;
+pub use bridge_stream::BridgeStream;
+
+mod bridge_mediator;
+pub use bridge_mediator::BridgeMediator;
@@ -0,0 +1,214 @@
+use std::any::Any;
+use std::sync::{Arc, RwLock};
+use crate::Error;
+use crate::core::View;
+use crate::interfaces::{DispatchMode, IFacade, INotifier};
+use crate::patterns::Notifier;
+use crate::utilities::fsm::{State, StateMachine, Transition};
+
+/// A declarative description of a `State`.
+///
+/// See `FsmDefinition`
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StateDefinition {
+    /// The name of the `State`.
+    pub name: String,
+    /// The notification to send when entering the `State` (optional).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub entering: Option<String>,
+    /// The notification to send when exiting the `State` (optional).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub exiting: Option<String>,
+    /// The notification to send when the `State` has been entered (optional).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub changed: Option<String>,
+    /// The transitions of the `State`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub transitions: Vec<Transition>,
+}
+
+impl StateDefinition {
+    /// Construct a new `StateDefinition` instance, without transitions.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the `State`.
+    /// * `entering` - The notification to send when entering the `State` (optional).
+    /// * `exiting` - The notification to send when exiting the `State` (optional).
+    /// * `changed` - The notification to send when the `State` has been entered (optional).
+    pub fn new(name: &str, entering: Option<&str>, exiting: Option<&str>, changed: Option<&str>) -> Self {
+        Self {
+            name: name.into(),
+            entering: entering.map(|e| e.into()),
+            exiting: exiting.map(|e| e.into()),
+            changed: changed.map(|c| c.into()),
+            transitions: Vec::new(),
+        }
+    }
+
+    /// Define a transition.
+    ///
+    /// # Arguments
+    /// * `action` - The name of the action that triggers the transition.
+    /// * `target` - The name of the target `State`.
+    ///
+    /// # Returns
+    /// The `StateDefinition`, to chain further transitions.
+    pub fn transition(mut self, action: &str, target: &str) -> Self {
+        self.transitions.push(Transition::new(action, target));
+        self
+    }
+}
+
+/// A declarative description of a Finite State Machine.
+///
+/// Definitions own their data, so they can be built in code or, with the `serde` feature,
+/// loaded at runtime from a description such as JSON:
+///
+/// ```
+/// use puremvc::utilities::fsm::{FsmDefinition, StateDefinition};
+///
+/// let door = FsmDefinition::new(Some("CLOSED"), vec![
+///     StateDefinition::new("OPENED", None, None, Some("opened")).transition("close", "CLOSED"),
+///     StateDefinition::new("CLOSED", None, None, Some("closed")).transition("open", "OPENED"),
+/// ]);
+/// ```
+///
+/// See `FsmInjector`, `StateDefinition`
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FsmDefinition {
+    /// The name of the initial `State` (optional).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub initial: Option<String>,
+    /// The `State`s of the Finite State Machine.
+    pub states: Vec<StateDefinition>,
+}
+
+impl FsmDefinition {
+    /// Construct a new `FsmDefinition` instance.
+    ///
+    /// # Arguments
+    /// * `initial` - The name of the initial `State` (optional).
+    /// * `states` - The `State`s of the Finite State Machine.
+    pub fn new(initial: Option<&str>, states: Vec<StateDefinition>) -> Self {
+        Self {
+            initial: initial.map(|i| i.into()),
+            states,
+        }
+    }
+}
+
+/// Creates and registers a `StateMachine` described by an `FsmDefinition`.
+///
+/// Once initialized with the Multiton key of a core, call `inject` to build the `StateMachine`
+/// and register it with the core's `IView`, which enters the initial `State`.
+///
+/// See `FsmDefinition`, `StateMachine`
+pub struct FsmInjector {
+    /// The underlying `INotifier` instance used for `IFacade` access.
+    notifier: Box<dyn INotifier + Send + Sync>,
+    /// The definition of the Finite State Machine.
+    definition: FsmDefinition,
+}
+
+impl FsmInjector {
+    /// Construct a new `FsmInjector` instance.
+    ///
+    /// # Arguments
+    /// * `definition` - The definition of the Finite State Machine.
+    pub fn new(definition: FsmDefinition) -> Self {
+        Self {
+            notifier: Box::new(Notifier::new()),
+            definition
+        }
+    }
+
+    /// Create the `State`s described by the definition.
+    ///
+    /// # Returns
+    /// The `State`s, in definition order.
+    pub fn states(&self) -> Vec<State> {
+        self.definition.states.iter()
+            .map(|definition| {
+                let mut state = State::new(&definition.name, definition.entering.as_deref(), definition.exiting.as_deref(), definition.changed.as_deref());
+                for transition in &definition.transitions {
+                    state.define_transition(transition.action(), transition.target());
+                }
+                state
+            })
+            .collect()
+    }
+
+    /// Create a `StateMachine` holding the `State`s described by the definition.
+    ///
+    /// # Returns
+    /// The `StateMachine`, not yet registered.
+    pub fn create_state_machine(&self) -> StateMachine {
+        let mut state_machine = StateMachine::new();
+        for state in self.states() {
+            let initial = self.definition.initial.as_deref() == Some(state.name());
+            state_machine.register_state(state, initial);
+        }
+        state_machine
+    }
+
+    /// Create the `StateMachine` and register it with the core's `IView`.
+    ///
+    /// Registration enters the initial `State`, if the definition names one. Nothing is injected
+    /// if the core's `IView` is not in `DispatchMode::Immediate`; use `try_inject` to find out.
+    pub fn inject(&self) {
+        let _ = self.try_inject();
+    }
+
+    /// Create the `StateMachine` and register it with the core's `IView`, failing if the
+    /// `StateMachine` cannot work in the core.
+    ///
+    /// # Returns
+    /// `Error::UnsupportedDispatchMode` if the core's `IView` is not in `DispatchMode::Immediate`,
+    /// as guards could not cancel transitions.
+    pub fn try_inject(&self) -> Result<(), Error> {
+        let mode = View::get_instance(self.key(), View::new).dispatch_mode();
+        if mode != DispatchMode::Immediate {
+            return Err(Error::UnsupportedDispatchMode { kind: "StateMachine", mode });
+        }
+        self.facade().register_mediator(Arc::new(RwLock::new(self.create_state_machine())));
+        Ok(())
+    }
+}
+
+impl INotifier for FsmInjector {
+    /// Get the Multiton key for this `FsmInjector`.
+    ///
+    /// # Returns
+    /// The Multiton key of the `FsmInjector`.
+    fn key(&self) -> &str {
+        self.notifier.key()
+    }
+
+    /// Get the `IFacade` instance associated with this `FsmInjector`.
+    ///
+    /// # Returns
+    /// The `IFacade` instance.
+    fn facade(&self) -> Arc<dyn IFacade> {
+        self.notifier.facade()
+    }
+
+    /// Initialize this `FsmInjector` instance.
+    ///
+    /// # Arguments
+    /// * `key` - The Multiton key for this `FsmInjector`.
+    fn initialize_notifier(&mut self, key: &str) {
+        self.notifier.initialize_notifier(key);
+    }
+
+    /// Send a `Notification`.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the `Notification` to send.
+    /// * `body` - The body of the `Notification` (optional).
+    /// * `type_` - The type of the `Notification` (optional).
+    fn send_notification(&self, name: &str, body: Option<Arc<dyn Any + Send + Sync>>, type_: Option<&str>) {
+        self.notifier.send_notification(name, body, type_);
+    }
+}
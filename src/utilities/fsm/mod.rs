@@ -0,0 +1,13 @@
+mod transition;
+pub use transition::Transition;
+
+mod state;
+pub use state::State;
+
+mod state_machine;
+pub use state_machine::StateMachine;
+
+mod fsm_injector;
+pub use fsm_injector::FsmInjector;
+pub use fsm_injector::FsmDefinition;
+pub use fsm_injector::StateDefinition;
@@ -0,0 +1,113 @@
+use crate::utilities::fsm::Transition;
+
+/// Defines a `State`.
+///
+/// A `State` has a name, optional `entering`, `exiting` and `changed` notification names, and a
+/// list of `Transition`s mapping action names to target states.
+///
+/// See `Transition`, `StateMachine`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct State {
+    /// The name of the `State`.
+    name: String,
+    /// The notification to send when entering the `State`.
+    entering: Option<String>,
+    /// The notification to send when exiting the `State`.
+    exiting: Option<String>,
+    /// The notification to send when the `State` has been entered.
+    changed: Option<String>,
+    /// The transitions defined for this `State`.
+    transitions: Vec<Transition>,
+}
+
+impl State {
+    /// Construct a new `State` instance.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the `State`.
+    /// * `entering` - The notification to send when entering the `State` (optional).
+    /// * `exiting` - The notification to send when exiting the `State` (optional).
+    /// * `changed` - The notification to send when the `State` has been entered (optional).
+    pub fn new(name: &str, entering: Option<&str>, exiting: Option<&str>, changed: Option<&str>) -> Self {
+        Self {
+            name: name.into(),
+            entering: entering.map(|e| e.into()),
+            exiting: exiting.map(|e| e.into()),
+            changed: changed.map(|c| c.into()),
+            transitions: Vec::new(),
+        }
+    }
+
+    /// Get the name of the `State`.
+    ///
+    /// # Returns
+    /// The name of the `State`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the notification sent when entering the `State`.
+    ///
+    /// # Returns
+    /// The entering notification name, if any.
+    pub fn entering(&self) -> Option<&str> {
+        self.entering.as_deref()
+    }
+
+    /// Get the notification sent when exiting the `State`.
+    ///
+    /// # Returns
+    /// The exiting notification name, if any.
+    pub fn exiting(&self) -> Option<&str> {
+        self.exiting.as_deref()
+    }
+
+    /// Get the notification sent when the `State` has been entered.
+    ///
+    /// # Returns
+    /// The changed notification name, if any.
+    pub fn changed(&self) -> Option<&str> {
+        self.changed.as_deref()
+    }
+
+    /// Get the transitions defined for this `State`.
+    ///
+    /// # Returns
+    /// The transitions of this `State`.
+    pub fn transitions(&self) -> &[Transition] {
+        &self.transitions
+    }
+
+    /// Define a transition.
+    ///
+    /// Replaces any transition previously defined for the same action.
+    ///
+    /// # Arguments
+    /// * `action` - The name of the action that triggers the transition.
+    /// * `target` - The name of the target `State`.
+    pub fn define_transition(&mut self, action: &str, target: &str) {
+        self.remove_transition(action);
+        self.transitions.push(Transition::new(action, target));
+    }
+
+    /// Remove a previously defined transition.
+    ///
+    /// # Arguments
+    /// * `action` - The name of the action whose transition should be removed.
+    pub fn remove_transition(&mut self, action: &str) {
+        self.transitions.retain(|transition| transition.action() != action);
+    }
+
+    /// Get the target `State` name for a given action.
+    ///
+    /// # Arguments
+    /// * `action` - The name of the action.
+    ///
+    /// # Returns
+    /// The name of the target `State`, if a transition is defined for the action.
+    pub fn target(&self, action: &str) -> Option<&str> {
+        self.transitions.iter()
+            .find(|transition| transition.action() == action)
+            .map(|transition| transition.target())
+    }
+}
@@ -0,0 +1,373 @@
+use std::any::Any;
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, Weak};
+use crate::core::View;
+use crate::interfaces::{IFacade, IMediator, INotification, INotifier};
+use crate::patterns::{Mediator, Observer};
+use crate::utilities::fsm::State;
+
+/// A Finite State Machine implemented as an `IMediator`.
+///
+/// The `StateMachine` holds a set of named `State`s and responds to `ACTION` notifications, whose
+/// type is the action name and whose body is optional transition data. When the current `State`
+/// defines a transition for the action, the `StateMachine`:
+///
+/// - Sends the current `State`'s `exiting` notification, with the data as body and the target
+///   state name as type.
+/// - Sends the target `State`'s `entering` notification, with the data as body.
+/// - Makes the target `State` current.
+/// - Sends the target `State`'s `changed` notification, with the data as body.
+/// - Sends `CHANGED`, with the new current `State` as body and its name as type.
+///
+/// Any handler of the `exiting` or `entering` notifications may send `CANCEL` to veto the
+/// transition, in which case the current `State` stays unchanged and no further notifications
+/// are sent. `CANCEL` is observed separately from the `StateMachine`'s notification interests,
+/// so guards may send it while the `StateMachine` is still handling the `ACTION`.
+///
+/// `ACTION` is observed separately too. An `ACTION` sent while a transition is in progress, for
+/// instance by a command or mediator reacting to a `changed` or `entering` notification, is
+/// queued and performed once the transition ends, in the order the actions were sent, rather
+/// than re-entering the `StateMachine`'s lock.
+///
+/// Guards rely on the `exiting` and `entering` notifications being delivered before the
+/// `StateMachine` checks for `CANCEL`, so the `StateMachine` requires its core's `IView` to be
+/// in `DispatchMode::Immediate`. In `DispatchMode::Deferred` those notifications would only be
+/// delivered once the transition has completed, so `CANCEL` would be ignored; `FsmInjector`
+/// refuses to inject a `StateMachine` into such a core.
+///
+/// See `State`, `Transition`, `FsmInjector`
+pub struct StateMachine {
+    /// The underlying `Mediator` instance.
+    mediator: Mediator,
+    /// Mapping of `State` names to `State` instances.
+    states: HashMap<String, State>,
+    /// The name of the initial `State`.
+    initial: Option<String>,
+    /// The name of the current `State`.
+    current: Option<String>,
+    /// Set by the `CANCEL` observer to veto the transition in progress.
+    canceled: Arc<AtomicBool>,
+    /// The actions waiting for the transition in progress to end.
+    actions: Arc<Mutex<ActionQueue>>,
+}
+
+/// The actions sent to a `StateMachine` while a transition is in progress.
+#[derive(Default)]
+struct ActionQueue {
+    /// The action names and transition data, in the order they were sent.
+    pending: VecDeque<(String, Option<Arc<dyn Any + Send + Sync>>)>,
+    /// Whether a transition is in progress.
+    transitioning: bool,
+}
+
+impl StateMachine {
+    /// The name of the `StateMachine` mediator.
+    pub const NAME: &'static str = "StateMachine";
+
+    /// Action notification name. The notification type is the action name.
+    pub const ACTION: &'static str = "StateMachine/notes/action";
+
+    /// Changed notification name. Sent after every completed transition.
+    pub const CHANGED: &'static str = "StateMachine/notes/changed";
+
+    /// Cancel notification name. Sent by `exiting` or `entering` handlers to veto a transition.
+    pub const CANCEL: &'static str = "StateMachine/notes/cancel";
+
+    /// Construct a new `StateMachine` instance.
+    pub fn new() -> Self {
+        Self {
+            mediator: Mediator::new(Some(Self::NAME), None),
+            states: HashMap::new(),
+            initial: None,
+            current: None,
+            canceled: Arc::new(AtomicBool::new(false)),
+            actions: Arc::new(Mutex::new(ActionQueue::default())),
+        }
+    }
+
+    /// Register a `State` with the `StateMachine`.
+    ///
+    /// # Arguments
+    /// * `state` - The `State` to register.
+    /// * `initial` - Whether this is the initial `State`, entered when the `StateMachine` is registered.
+    pub fn register_state(&mut self, state: State, initial: bool) {
+        if initial {
+            self.initial = Some(state.name().into());
+        }
+        self.states.insert(state.name().into(), state);
+    }
+
+    /// Remove a `State` from the `StateMachine`.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the `State` to remove.
+    ///
+    /// # Returns
+    /// The removed `State`, if any.
+    pub fn remove_state(&mut self, name: &str) -> Option<State> {
+        self.states.remove(name)
+    }
+
+    /// Retrieve a registered `State`.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the `State`.
+    ///
+    /// # Returns
+    /// The `State` registered with the given name, if any.
+    pub fn retrieve_state(&self, name: &str) -> Option<&State> {
+        self.states.get(name)
+    }
+
+    /// Get the current `State`.
+    ///
+    /// # Returns
+    /// The current `State`, if the `StateMachine` has entered one.
+    pub fn current_state(&self) -> Option<&State> {
+        self.current.as_ref().and_then(|name| self.states.get(name))
+    }
+
+    /// Transition to the given `State`.
+    ///
+    /// Sends the `exiting`, `entering` and `changed` notifications along the way, honoring
+    /// `CANCEL` from `exiting` and `entering` handlers. Actions sent meanwhile are performed
+    /// once the transition ends.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the target `State`.
+    /// * `data` - Data passed as the body of the transition notifications (optional).
+    ///
+    /// # Returns
+    /// `true` if the transition completed, `false` if the target is unknown or the transition was canceled.
+    pub fn transition_to(&mut self, name: &str, data: Option<Arc<dyn Any + Send + Sync>>) -> bool {
+        let outermost = self.actions.lock()
+            .map(|mut actions| !std::mem::replace(&mut actions.transitioning, true))
+            .unwrap_or(false);
+        let completed = self.transition(name, data);
+        if outermost {
+            self.perform_pending_actions();
+        }
+        completed
+    }
+
+    /// Queue an action, then perform the pending actions unless a transition is in progress.
+    ///
+    /// # Arguments
+    /// * `action` - The name of the action.
+    /// * `data` - Data passed as the body of the transition notifications (optional).
+    pub fn perform_action(&mut self, action: &str, data: Option<Arc<dyn Any + Send + Sync>>) {
+        if let Ok(mut actions) = self.actions.lock() {
+            actions.pending.push_back((action.into(), data));
+        }
+        self.resume_actions();
+    }
+
+    /// Perform the queued actions, unless a transition is in progress and will perform them.
+    fn resume_actions(&mut self) {
+        let idle = self.actions.lock()
+            .map(|mut actions| !std::mem::replace(&mut actions.transitioning, true))
+            .unwrap_or(false);
+        if idle {
+            self.perform_pending_actions();
+        }
+    }
+
+    /// Perform the queued actions in turn, until none is left.
+    ///
+    /// Called with `transitioning` set, which is cleared once the queue is empty.
+    fn perform_pending_actions(&mut self) {
+        loop {
+            let next = self.actions.lock().ok().and_then(|mut actions| {
+                let next = actions.pending.pop_front();
+                actions.transitioning = next.is_some();
+                next
+            });
+            let Some((action, data)) = next else { break };
+
+            let target = self.current_state().and_then(|current| current.target(&action)).map(String::from);
+            if let Some(target) = target {
+                self.transition(&target, data);
+            }
+        }
+    }
+
+    /// Transition to the given `State`, sending the transition notifications.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the target `State`.
+    /// * `data` - Data passed as the body of the transition notifications (optional).
+    ///
+    /// # Returns
+    /// `true` if the transition completed, `false` if the target is unknown or the transition was canceled.
+    fn transition(&mut self, name: &str, data: Option<Arc<dyn Any + Send + Sync>>) -> bool {
+        let Some(next) = self.states.get(name).cloned() else { return false };
+        self.canceled.store(false, Ordering::SeqCst);
+
+        if let Some(exiting) = self.current_state().and_then(|current| current.exiting()).map(String::from) {
+            self.send_notification(&exiting, data.clone(), Some(next.name()));
+            if self.canceled.swap(false, Ordering::SeqCst) { return false }
+        }
+
+        if let Some(entering) = next.entering() {
+            self.send_notification(entering, data.clone(), None);
+            if self.canceled.swap(false, Ordering::SeqCst) { return false }
+        }
+
+        self.current = Some(next.name().into());
+
+        if let Some(changed) = next.changed() {
+            self.send_notification(changed, data, None);
+        }
+        self.send_notification(Self::CHANGED, Some(Arc::new(next.clone())), Some(next.name()));
+        true
+    }
+}
+
+impl Default for StateMachine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IMediator for StateMachine {
+    /// Get the `StateMachine` instance's name.
+    ///
+    /// # Returns
+    /// The name of the `StateMachine` instance.
+    fn name(&self) -> &str {
+        self.mediator.name()
+    }
+
+    /// Get the `StateMachine`'s view component.
+    ///
+    /// # Returns
+    /// The view component associated with the `StateMachine`.
+    fn component(&self) -> Option<&Weak<dyn Any + Send + Sync>> {
+        self.mediator.component()
+    }
+
+    /// Set the `StateMachine`'s view component.
+    ///
+    /// # Arguments
+    /// * `component` - The view component.
+    fn set_component(&mut self, component: Option<Weak<dyn Any + Send + Sync>>) {
+        self.mediator.set_component(component);
+    }
+
+    /// List `Notification` interests.
+    ///
+    /// # Returns
+    /// No notification names, as `ACTION` is observed separately so that actions sent during a
+    /// transition are queued instead of re-entering the `StateMachine`'s lock.
+    fn list_notification_interests(&self) -> Vec<String> {
+        vec![]
+    }
+
+    /// Handle an `ACTION` notification.
+    ///
+    /// Performs the action named by the notification type, passing the notification body along
+    /// as transition data.
+    ///
+    /// # Arguments
+    /// * `notification` - The `Notification` to be handled.
+    fn handle_notification(&mut self, notification: &Arc<dyn INotification>) {
+        if notification.name() != Self::ACTION { return }
+        if let Some(action) = notification.get_type() {
+            self.perform_action(action, notification.body().cloned());
+        }
+    }
+
+    /// Called by the `IView` when the `StateMachine` is registered.
+    ///
+    /// Starts observing `CANCEL` and `ACTION`, and enters the initial `State`, if any.
+    fn on_register(&mut self) {
+        let view = View::get_instance(self.key(), View::new);
+        let notify = {
+            let canceled = Arc::clone(&self.canceled);
+            Arc::new(move |_: &Arc<dyn INotification>| canceled.store(true, Ordering::SeqCst))
+        };
+        let context: Arc<dyn Any + Send + Sync> = Arc::clone(&self.canceled) as Arc<dyn Any + Send + Sync>;
+        view.register_observer(Self::CANCEL, Arc::new(Observer::new(Some(notify), Some(context))));
+
+        let notify = {
+            let actions = Arc::clone(&self.actions);
+            let (key, name) = (self.key().to_string(), self.name().to_string());
+            Arc::new(move |notification: &Arc<dyn INotification>| {
+                let Some(action) = notification.get_type() else { return };
+                let idle = actions.lock()
+                    .map(|mut actions| {
+                        actions.pending.push_back((action.into(), notification.body().cloned()));
+                        !actions.transitioning
+                    })
+                    .unwrap_or(false);
+                if !idle { return }
+
+                let mediator = View::instance(&key).and_then(|view| view.retrieve_mediator(&name));
+                if let Some(mediator) = mediator && let Ok(mut mediator) = mediator.write()
+                    && let Some(state_machine) = mediator.as_any().downcast_mut::<StateMachine>() {
+                    state_machine.resume_actions();
+                }
+            })
+        };
+        let context: Arc<dyn Any + Send + Sync> = Arc::clone(&self.actions) as Arc<dyn Any + Send + Sync>;
+        view.register_observer(Self::ACTION, Arc::new(Observer::new(Some(notify), Some(context))));
+
+        if let Some(initial) = self.initial.clone() {
+            self.transition_to(&initial, None);
+        }
+    }
+
+    /// Called by the `IView` when the `StateMachine` is removed.
+    ///
+    /// Stops observing `CANCEL` and `ACTION`.
+    fn on_remove(&mut self) {
+        let view = View::get_instance(self.key(), View::new);
+        view.remove_observer(Self::CANCEL, Arc::clone(&self.canceled) as Arc<dyn Any + Send + Sync>);
+        view.remove_observer(Self::ACTION, Arc::clone(&self.actions) as Arc<dyn Any + Send + Sync>);
+    }
+
+    /// Get the `StateMachine` as a dynamic `Any` type.
+    ///
+    /// # Returns
+    /// The `StateMachine` instance as a mutable `Any` reference.
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+impl INotifier for StateMachine {
+    /// Get the Multiton key for this `StateMachine`.
+    ///
+    /// # Returns
+    /// The Multiton key of the `StateMachine`.
+    fn key(&self) -> &str {
+        self.mediator.key()
+    }
+
+    /// Get the `IFacade` instance associated with this `StateMachine`.
+    ///
+    /// # Returns
+    /// The `IFacade` instance.
+    fn facade(&self) -> Arc<dyn IFacade> {
+        self.mediator.facade()
+    }
+
+    /// Initialize this `StateMachine` instance.
+    ///
+    /// # Arguments
+    /// * `key` - The Multiton key for this `StateMachine`.
+    fn initialize_notifier(&mut self, key: &str) {
+        self.mediator.initialize_notifier(key);
+    }
+
+    /// Send a `Notification`.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the `Notification` to send.
+    /// * `body` - The body of the `Notification` (optional).
+    /// * `type_` - The type of the `Notification` (optional).
+    fn send_notification(&self, name: &str, body: Option<Arc<dyn Any + Send + Sync>>, type_: Option<&str>) {
+        self.mediator.send_notification(name, body, type_);
+    }
+}
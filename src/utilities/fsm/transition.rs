@@ -0,0 +1,44 @@
+/// A transition between `State`s.
+///
+/// Maps an action name to the name of the target `State` the `StateMachine` moves to when that
+/// action is triggered in the owning `State`.
+///
+/// See `State`, `StateMachine`
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Transition {
+    /// The name of the action that triggers this transition.
+    action: String,
+    /// The name of the target `State`.
+    target: String,
+}
+
+impl Transition {
+    /// Construct a new `Transition` instance.
+    ///
+    /// # Arguments
+    /// * `action` - The name of the action that triggers this transition.
+    /// * `target` - The name of the target `State`.
+    pub fn new(action: &str, target: &str) -> Self {
+        Self {
+            action: action.into(),
+            target: target.into(),
+        }
+    }
+
+    /// Get the name of the action that triggers this transition.
+    ///
+    /// # Returns
+    /// The action name.
+    pub fn action(&self) -> &str {
+        &self.action
+    }
+
+    /// Get the name of the target `State`.
+    ///
+    /// # Returns
+    /// The target state name.
+    pub fn target(&self) -> &str {
+        &self.target
+    }
+}
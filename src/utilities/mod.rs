@@ -0,0 +1,4 @@
+#[cfg(feature = "bridge")]
+pub mod bridge;
+pub mod fsm;
+pub mod pipes;
@@ -0,0 +1,41 @@
+use std::any::Any;
+use std::sync::{Arc, RwLock};
+use crate::utilities::pipes::interfaces::IPipeMessage;
+
+/// The trait definition for a PureMVC Pipes `IPipeFitting`.
+///
+/// An `IPipeFitting` can be connected to other `IPipeFitting`s, forming a Pipeline. `IPipeMessage`s
+/// are written to one end of a Pipeline by some client code. The messages are then transferred in
+/// synchronous fashion from one fitting to the next.
+///
+/// See `IPipeMessage`, `Pipe`, `TeeSplit`, `TeeMerge`, `Filter`, `Queue`, `PipeListener`
+pub trait IPipeFitting: Any + Send + Sync {
+    /// Connect another `IPipeFitting` to the output of this fitting.
+    ///
+    /// # Arguments
+    /// * `output` - The `IPipeFitting` to connect to the output of this fitting.
+    ///
+    /// # Returns
+    /// `true` if the output was connected, otherwise `false`.
+    fn connect(&mut self, output: Arc<RwLock<dyn IPipeFitting>>) -> bool;
+
+    /// Disconnect the output `IPipeFitting` of this fitting.
+    ///
+    /// This disconnects the output fitting, returning a reference to it. If you were splicing
+    /// another fitting into a pipeline, you need to keep (at least briefly) a reference to both
+    /// sides of the pipeline in order to connect them to the input and output of whatever
+    /// fitting you're splicing in.
+    ///
+    /// # Returns
+    /// The disconnected output `IPipeFitting`, if any.
+    fn disconnect(&mut self) -> Option<Arc<RwLock<dyn IPipeFitting>>>;
+
+    /// Write an `IPipeMessage` to this fitting.
+    ///
+    /// # Arguments
+    /// * `message` - The `IPipeMessage` to write.
+    ///
+    /// # Returns
+    /// `true` if the message was written successfully, otherwise `false`.
+    fn write(&mut self, message: Arc<dyn IPipeMessage>) -> bool;
+}
@@ -0,0 +1,62 @@
+use std::any::Any;
+use std::sync::Arc;
+
+/// The trait definition for a PureMVC Pipes `IPipeMessage`.
+///
+/// `IPipeMessage`s are objects written into a Pipeline, composed of `IPipeFitting`s. The message
+/// is passed from one fitting to the next in synchronous fashion.
+///
+/// Depending on type, messages may be handled differently by the fittings: normal messages are
+/// passed along, while control messages (see `FilterControlMessage`, `QueueControlMessage`) are
+/// interpreted by the fitting they are addressed to.
+///
+/// See `IPipeFitting`, `Message`
+pub trait IPipeMessage: Any + Send + Sync {
+    /// Get the type of this message.
+    ///
+    /// # Returns
+    /// The type of the message.
+    fn get_type(&self) -> &str;
+
+    /// Set the type of this message.
+    ///
+    /// # Arguments
+    /// * `type_` - The type of the message.
+    fn set_type(&mut self, type_: &str);
+
+    /// Get the priority of this message.
+    ///
+    /// # Returns
+    /// The priority of the message, where lower values are more urgent.
+    fn priority(&self) -> i32;
+
+    /// Set the priority of this message.
+    ///
+    /// # Arguments
+    /// * `priority` - The priority of the message.
+    fn set_priority(&mut self, priority: i32);
+
+    /// Get the header of this message.
+    ///
+    /// # Returns
+    /// The header of the message.
+    fn header(&self) -> Option<&Arc<dyn Any + Send + Sync>>;
+
+    /// Set the header of this message.
+    ///
+    /// # Arguments
+    /// * `header` - The header of the message.
+    fn set_header(&mut self, header: Option<Arc<dyn Any + Send + Sync>>);
+
+    /// Get the body of this message.
+    ///
+    /// # Returns
+    /// The body of the message.
+    fn body(&self) -> Option<&Arc<dyn Any + Send + Sync>>;
+
+    /// Set the body of this message.
+    ///
+    /// # Arguments
+    /// * `body` - The body of the message.
+    fn set_body(&mut self, body: Option<Arc<dyn Any + Send + Sync>>);
+}
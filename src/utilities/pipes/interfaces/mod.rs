@@ -0,0 +1,5 @@
+mod i_pipe_fitting;
+mod i_pipe_message;
+
+pub use i_pipe_fitting::IPipeFitting;
+pub use i_pipe_message::IPipeMessage;
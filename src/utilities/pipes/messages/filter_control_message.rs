@@ -0,0 +1,146 @@
+use std::any::Any;
+use std::sync::Arc;
+use crate::utilities::pipes::interfaces::IPipeMessage;
+use crate::utilities::pipes::messages::Message;
+use crate::utilities::pipes::plumbing::FilterFn;
+
+/// A `Filter` control message.
+///
+/// Control messages are addressed to a `Filter` by name. A `Filter` that receives a control
+/// message addressed to it acts on it and does not pass it along; all other fittings pass it
+/// downstream untouched.
+///
+/// See `Filter`, `Message`
+pub struct FilterControlMessage {
+    /// The underlying `Message` instance.
+    message: Message,
+    /// The name of the `Filter` this message is addressed to.
+    name: String,
+    /// The filter function to set on the target `Filter` (optional).
+    filter: Option<FilterFn>,
+    /// The filter parameters to set on the target `Filter` (optional).
+    params: Option<Arc<dyn Any + Send + Sync>>,
+}
+
+impl FilterControlMessage {
+    /// The base URI for filter control message types.
+    pub const BASE: &'static str = "http://puremvc.org/namespaces/pipes/messages/filter-control/";
+
+    /// Set filter parameters.
+    pub const SET_PARAMS: &'static str = "http://puremvc.org/namespaces/pipes/messages/filter-control/setparams";
+
+    /// Set filter function.
+    pub const SET_FILTER: &'static str = "http://puremvc.org/namespaces/pipes/messages/filter-control/setfilter";
+
+    /// Toggle to filter bypass mode.
+    pub const BYPASS: &'static str = "http://puremvc.org/namespaces/pipes/messages/filter-control/bypass";
+
+    /// Toggle to filtering mode. (default behavior).
+    pub const FILTER: &'static str = "http://puremvc.org/namespaces/pipes/messages/filter-control/filter";
+
+    /// Construct a new `FilterControlMessage` instance.
+    ///
+    /// # Arguments
+    /// * `type_` - The type of the control message.
+    /// * `name` - The name of the `Filter` this message is addressed to.
+    /// * `filter` - The filter function to set (optional).
+    /// * `params` - The filter parameters to set (optional).
+    pub fn new(type_: &str, name: &str, filter: Option<FilterFn>, params: Option<Arc<dyn Any + Send + Sync>>) -> Self {
+        Self {
+            message: Message::new(type_, None, None, None),
+            name: name.into(),
+            filter,
+            params,
+        }
+    }
+
+    /// Get the name of the target `Filter`.
+    ///
+    /// # Returns
+    /// The name of the `Filter` this message is addressed to.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the filter function carried by this message.
+    ///
+    /// # Returns
+    /// The filter function, if any.
+    pub fn filter(&self) -> Option<&FilterFn> {
+        self.filter.as_ref()
+    }
+
+    /// Get the filter parameters carried by this message.
+    ///
+    /// # Returns
+    /// The filter parameters, if any.
+    pub fn params(&self) -> Option<&Arc<dyn Any + Send + Sync>> {
+        self.params.as_ref()
+    }
+}
+
+impl IPipeMessage for FilterControlMessage {
+    /// Get the type of this message.
+    ///
+    /// # Returns
+    /// The type of the message.
+    fn get_type(&self) -> &str {
+        self.message.get_type()
+    }
+
+    /// Set the type of this message.
+    ///
+    /// # Arguments
+    /// * `type_` - The type of the message.
+    fn set_type(&mut self, type_: &str) {
+        self.message.set_type(type_);
+    }
+
+    /// Get the priority of this message.
+    ///
+    /// # Returns
+    /// The priority of the message.
+    fn priority(&self) -> i32 {
+        self.message.priority()
+    }
+
+    /// Set the priority of this message.
+    ///
+    /// # Arguments
+    /// * `priority` - The priority of the message.
+    fn set_priority(&mut self, priority: i32) {
+        self.message.set_priority(priority);
+    }
+
+    /// Get the header of this message.
+    ///
+    /// # Returns
+    /// The header of the message.
+    fn header(&self) -> Option<&Arc<dyn Any + Send + Sync>> {
+        self.message.header()
+    }
+
+    /// Set the header of this message.
+    ///
+    /// # Arguments
+    /// * `header` - The header of the message.
+    fn set_header(&mut self, header: Option<Arc<dyn Any + Send + Sync>>) {
+        self.message.set_header(header);
+    }
+
+    /// Get the body of this message.
+    ///
+    /// # Returns
+    /// The body of the message.
+    fn body(&self) -> Option<&Arc<dyn Any + Send + Sync>> {
+        self.message.body()
+    }
+
+    /// Set the body of this message.
+    ///
+    /// # Arguments
+    /// * `body` - The body of the message.
+    fn set_body(&mut self, body: Option<Arc<dyn Any + Send + Sync>>) {
+        self.message.set_body(body);
+    }
+}
@@ -0,0 +1,120 @@
+use std::any::Any;
+use std::sync::Arc;
+use crate::utilities::pipes::interfaces::IPipeMessage;
+
+/// A base `IPipeMessage` implementation.
+///
+/// Messages travelling through a Pipeline can be filtered, and queued. In a queue, they may be
+/// sorted by priority. Based on type, they may be used as control messages to modify the
+/// behavior of filter or queue fittings connected to the pipeline into which they are written.
+///
+/// See `IPipeMessage`, `FilterControlMessage`, `QueueControlMessage`
+pub struct Message {
+    /// The type of the message.
+    type_: String,
+    /// The header of the message.
+    header: Option<Arc<dyn Any + Send + Sync>>,
+    /// The body of the message.
+    body: Option<Arc<dyn Any + Send + Sync>>,
+    /// The priority of the message.
+    priority: i32,
+}
+
+impl Message {
+    /// The base URI for all message types.
+    pub const BASE: &'static str = "http://puremvc.org/namespaces/pipes/messages/";
+
+    /// Normal message type.
+    pub const NORMAL: &'static str = "http://puremvc.org/namespaces/pipes/messages/normal/";
+
+    /// High priority messages can be sorted to the front of the queue.
+    pub const PRIORITY_HIGH: i32 = 1;
+
+    /// Medium priority messages are the default.
+    pub const PRIORITY_MED: i32 = 5;
+
+    /// Low priority messages can be sorted to the back of the queue.
+    pub const PRIORITY_LOW: i32 = 10;
+
+    /// Construct a new `Message` instance.
+    ///
+    /// # Arguments
+    /// * `type_` - The type of the message.
+    /// * `header` - The header of the message (optional).
+    /// * `body` - The body of the message (optional).
+    /// * `priority` - The priority of the message (optional, defaults to `PRIORITY_MED`).
+    pub fn new(type_: &str, header: Option<Arc<dyn Any + Send + Sync>>, body: Option<Arc<dyn Any + Send + Sync>>, priority: Option<i32>) -> Self {
+        Self {
+            type_: type_.into(),
+            header,
+            body,
+            priority: priority.unwrap_or(Self::PRIORITY_MED),
+        }
+    }
+}
+
+impl IPipeMessage for Message {
+    /// Get the type of this message.
+    ///
+    /// # Returns
+    /// The type of the message.
+    fn get_type(&self) -> &str {
+        &self.type_
+    }
+
+    /// Set the type of this message.
+    ///
+    /// # Arguments
+    /// * `type_` - The type of the message.
+    fn set_type(&mut self, type_: &str) {
+        self.type_ = type_.into();
+    }
+
+    /// Get the priority of this message.
+    ///
+    /// # Returns
+    /// The priority of the message.
+    fn priority(&self) -> i32 {
+        self.priority
+    }
+
+    /// Set the priority of this message.
+    ///
+    /// # Arguments
+    /// * `priority` - The priority of the message.
+    fn set_priority(&mut self, priority: i32) {
+        self.priority = priority;
+    }
+
+    /// Get the header of this message.
+    ///
+    /// # Returns
+    /// The header of the message.
+    fn header(&self) -> Option<&Arc<dyn Any + Send + Sync>> {
+        self.header.as_ref()
+    }
+
+    /// Set the header of this message.
+    ///
+    /// # Arguments
+    /// * `header` - The header of the message.
+    fn set_header(&mut self, header: Option<Arc<dyn Any + Send + Sync>>) {
+        self.header = header;
+    }
+
+    /// Get the body of this message.
+    ///
+    /// # Returns
+    /// The body of the message.
+    fn body(&self) -> Option<&Arc<dyn Any + Send + Sync>> {
+        self.body.as_ref()
+    }
+
+    /// Set the body of this message.
+    ///
+    /// # Arguments
+    /// * `body` - The body of the message.
+    fn set_body(&mut self, body: Option<Arc<dyn Any + Send + Sync>>) {
+        self.body = body;
+    }
+}
@@ -0,0 +1,8 @@
+mod message;
+pub use message::Message;
+
+mod filter_control_message;
+pub use filter_control_message::FilterControlMessage;
+
+mod queue_control_message;
+pub use queue_control_message::QueueControlMessage;
@@ -0,0 +1,105 @@
+use std::any::Any;
+use std::sync::Arc;
+use crate::utilities::pipes::interfaces::IPipeMessage;
+use crate::utilities::pipes::messages::Message;
+
+/// A `Queue` control message.
+///
+/// Writing a `QueueControlMessage` into a pipeline containing a `Queue` changes the way the
+/// `Queue` treats the messages it holds.
+///
+/// See `Queue`, `Message`
+pub struct QueueControlMessage {
+    /// The underlying `Message` instance.
+    message: Message,
+}
+
+impl QueueControlMessage {
+    /// The base URI for queue control message types.
+    pub const BASE: &'static str = "http://puremvc.org/namespaces/pipes/messages/queue/";
+
+    /// Flush the queue.
+    pub const FLUSH: &'static str = "http://puremvc.org/namespaces/pipes/messages/queue/flush";
+
+    /// Toggle to sort-by-priority operation mode.
+    pub const SORT: &'static str = "http://puremvc.org/namespaces/pipes/messages/queue/sort";
+
+    /// Toggle to FIFO operation mode (default behavior).
+    pub const FIFO: &'static str = "http://puremvc.org/namespaces/pipes/messages/queue/fifo";
+
+    /// Construct a new `QueueControlMessage` instance.
+    ///
+    /// # Arguments
+    /// * `type_` - The type of the control message (`FLUSH`, `SORT` or `FIFO`).
+    pub fn new(type_: &str) -> Self {
+        Self {
+            message: Message::new(type_, None, None, None)
+        }
+    }
+}
+
+impl IPipeMessage for QueueControlMessage {
+    /// Get the type of this message.
+    ///
+    /// # Returns
+    /// The type of the message.
+    fn get_type(&self) -> &str {
+        self.message.get_type()
+    }
+
+    /// Set the type of this message.
+    ///
+    /// # Arguments
+    /// * `type_` - The type of the message.
+    fn set_type(&mut self, type_: &str) {
+        self.message.set_type(type_);
+    }
+
+    /// Get the priority of this message.
+    ///
+    /// # Returns
+    /// The priority of the message.
+    fn priority(&self) -> i32 {
+        self.message.priority()
+    }
+
+    /// Set the priority of this message.
+    ///
+    /// # Arguments
+    /// * `priority` - The priority of the message.
+    fn set_priority(&mut self, priority: i32) {
+        self.message.set_priority(priority);
+    }
+
+    /// Get the header of this message.
+    ///
+    /// # Returns
+    /// The header of the message.
+    fn header(&self) -> Option<&Arc<dyn Any + Send + Sync>> {
+        self.message.header()
+    }
+
+    /// Set the header of this message.
+    ///
+    /// # Arguments
+    /// * `header` - The header of the message.
+    fn set_header(&mut self, header: Option<Arc<dyn Any + Send + Sync>>) {
+        self.message.set_header(header);
+    }
+
+    /// Get the body of this message.
+    ///
+    /// # Returns
+    /// The body of the message.
+    fn body(&self) -> Option<&Arc<dyn Any + Send + Sync>> {
+        self.message.body()
+    }
+
+    /// Set the body of this message.
+    ///
+    /// # Arguments
+    /// * `body` - The body of the message.
+    fn set_body(&mut self, body: Option<Arc<dyn Any + Send + Sync>>) {
+        self.message.set_body(body);
+    }
+}
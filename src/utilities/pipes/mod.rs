@@ -0,0 +1,3 @@
+pub mod interfaces;
+pub mod messages;
+pub mod plumbing;
@@ -0,0 +1,132 @@
+use std::any::Any;
+use std::sync::{Arc, RwLock};
+use crate::utilities::pipes::interfaces::{IPipeFitting, IPipeMessage};
+use crate::utilities::pipes::messages::FilterControlMessage;
+use crate::utilities::pipes::plumbing::Pipe;
+
+/// A filter function.
+///
+/// Receives the message and the current filter parameters, and returns the (possibly modified)
+/// message to pass along, or `None` to stop the message at the `Filter`.
+pub type FilterFn = Arc<dyn Fn(Arc<dyn IPipeMessage>, Option<&Arc<dyn Any + Send + Sync>>) -> Option<Arc<dyn IPipeMessage>> + Send + Sync>;
+
+/// Pipe Filter.
+///
+/// Filters may modify the contents of messages before writing them to their output pipe fitting.
+/// They may also have their parameters and filter function passed to them by control message,
+/// as well as having their bypass/filter operation mode toggled via control message.
+///
+/// See `IPipeFitting`, `FilterControlMessage`
+pub struct Filter {
+    /// The underlying `Pipe` instance holding the output fitting.
+    pipe: Pipe,
+    /// The name of this `Filter`, used to address control messages.
+    name: String,
+    /// The operation mode, either `FilterControlMessage::FILTER` or `FilterControlMessage::BYPASS`.
+    mode: String,
+    /// The filter function.
+    filter: FilterFn,
+    /// The filter parameters.
+    params: Option<Arc<dyn Any + Send + Sync>>,
+}
+
+impl Filter {
+    /// Construct a new `Filter` instance.
+    ///
+    /// # Arguments
+    /// * `name` - The name of this `Filter`.
+    /// * `output` - The output fitting to connect (optional).
+    /// * `filter` - The filter function (optional, defaults to passing every message along).
+    /// * `params` - The filter parameters (optional).
+    pub fn new(name: &str, output: Option<Arc<RwLock<dyn IPipeFitting>>>, filter: Option<FilterFn>, params: Option<Arc<dyn Any + Send + Sync>>) -> Self {
+        Self {
+            pipe: Pipe::new(output),
+            name: name.into(),
+            mode: FilterControlMessage::FILTER.into(),
+            filter: filter.unwrap_or_else(|| Arc::new(|message, _| Some(message))),
+            params,
+        }
+    }
+
+    /// Get the name of this `Filter`.
+    ///
+    /// # Returns
+    /// The name of the `Filter`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the operation mode of this `Filter`.
+    ///
+    /// # Returns
+    /// Either `FilterControlMessage::FILTER` or `FilterControlMessage::BYPASS`.
+    pub fn mode(&self) -> &str {
+        &self.mode
+    }
+
+    /// Act on a control message addressed to this `Filter`.
+    ///
+    /// # Arguments
+    /// * `control` - The control message.
+    fn apply(&mut self, control: &FilterControlMessage) {
+        match control.get_type() {
+            FilterControlMessage::SET_PARAMS => self.params = control.params().cloned(),
+            FilterControlMessage::SET_FILTER => {
+                if let Some(filter) = control.filter() {
+                    self.filter = Arc::clone(filter);
+                }
+            }
+            FilterControlMessage::BYPASS | FilterControlMessage::FILTER => self.mode = control.get_type().into(),
+            _ => {}
+        }
+    }
+}
+
+impl IPipeFitting for Filter {
+    /// Connect the output fitting.
+    ///
+    /// # Arguments
+    /// * `output` - The `IPipeFitting` to connect to the output.
+    ///
+    /// # Returns
+    /// `true` if no other fitting was already connected, otherwise `false`.
+    fn connect(&mut self, output: Arc<RwLock<dyn IPipeFitting>>) -> bool {
+        self.pipe.connect(output)
+    }
+
+    /// Disconnect the output `IPipeFitting`.
+    ///
+    /// # Returns
+    /// The disconnected output `IPipeFitting`, if any.
+    fn disconnect(&mut self) -> Option<Arc<RwLock<dyn IPipeFitting>>> {
+        self.pipe.disconnect()
+    }
+
+    /// Handle the incoming message.
+    ///
+    /// If the message is a `FilterControlMessage` addressed to this `Filter`, it is acted upon and
+    /// not passed along. Control messages addressed to other filters are passed along untouched.
+    /// Other messages are run through the filter function unless the `Filter` is in bypass mode.
+    ///
+    /// # Arguments
+    /// * `message` - The `IPipeMessage` to write.
+    ///
+    /// # Returns
+    /// `true` if the message was handled or passed along successfully, `false` if it was filtered
+    /// out or could not be written to the output.
+    fn write(&mut self, message: Arc<dyn IPipeMessage>) -> bool {
+        let any: &dyn Any = &*message;
+        match any.downcast_ref::<FilterControlMessage>() {
+            Some(control) if control.name() == self.name => {
+                self.apply(control);
+                true
+            }
+            Some(_) => self.pipe.write(message),
+            None if self.mode == FilterControlMessage::BYPASS => self.pipe.write(message),
+            None => match (self.filter)(message, self.params.as_ref()) {
+                Some(message) => self.pipe.write(message),
+                None => false
+            }
+        }
+    }
+}
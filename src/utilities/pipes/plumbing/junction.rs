@@ -0,0 +1,160 @@
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use crate::utilities::pipes::interfaces::{IPipeFitting, IPipeMessage};
+use crate::utilities::pipes::plumbing::PipeListener;
+
+/// Pipe Junction.
+///
+/// Manages pipes attached to a core. A `Junction` keeps named input and output pipes, lets
+/// listeners be attached to input pipes, and writes messages to output pipes by name.
+///
+/// See `JunctionMediator`, `IPipeFitting`
+pub struct Junction {
+    /// Mapping of pipe names to pipe fittings.
+    pipes: HashMap<String, Arc<RwLock<dyn IPipeFitting>>>,
+    /// Mapping of pipe names to pipe types (`INPUT` or `OUTPUT`).
+    pipe_types: HashMap<String, String>,
+}
+
+impl Junction {
+    /// Input pipe type.
+    pub const INPUT: &'static str = "input";
+
+    /// Output pipe type.
+    pub const OUTPUT: &'static str = "output";
+
+    /// Standard input pipe name.
+    pub const STDIN: &'static str = "standardInput";
+
+    /// Standard output pipe name.
+    pub const STDOUT: &'static str = "standardOutput";
+
+    /// Standard log pipe name.
+    pub const STDLOG: &'static str = "standardLog";
+
+    /// Standard shell pipe name.
+    pub const STDSHELL: &'static str = "standardShell";
+
+    /// Construct a new `Junction` instance.
+    pub fn new() -> Self {
+        Self {
+            pipes: HashMap::new(),
+            pipe_types: HashMap::new(),
+        }
+    }
+
+    /// Register a pipe with the `Junction`.
+    ///
+    /// Pipes are registered by unique name and type, which must be either `Junction::INPUT` or
+    /// `Junction::OUTPUT`.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the pipe.
+    /// * `type_` - The type of the pipe.
+    /// * `pipe` - The pipe fitting.
+    ///
+    /// # Returns
+    /// `true` if the pipe was registered, `false` if a pipe with this name already exists.
+    pub fn register_pipe(&mut self, name: &str, type_: &str, pipe: Arc<RwLock<dyn IPipeFitting>>) -> bool {
+        if self.pipes.contains_key(name) { return false }
+        self.pipes.insert(name.into(), pipe);
+        self.pipe_types.insert(name.into(), type_.into());
+        true
+    }
+
+    /// Check if a pipe is registered with the given name.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the pipe.
+    ///
+    /// # Returns
+    /// `true` if a pipe is registered with the given name, otherwise `false`.
+    pub fn has_pipe(&self, name: &str) -> bool {
+        self.pipes.contains_key(name)
+    }
+
+    /// Check if an input pipe is registered with the given name.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the pipe.
+    ///
+    /// # Returns
+    /// `true` if an input pipe is registered with the given name, otherwise `false`.
+    pub fn has_input_pipe(&self, name: &str) -> bool {
+        self.pipe_types.get(name).is_some_and(|type_| type_ == Self::INPUT)
+    }
+
+    /// Check if an output pipe is registered with the given name.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the pipe.
+    ///
+    /// # Returns
+    /// `true` if an output pipe is registered with the given name, otherwise `false`.
+    pub fn has_output_pipe(&self, name: &str) -> bool {
+        self.pipe_types.get(name).is_some_and(|type_| type_ == Self::OUTPUT)
+    }
+
+    /// Retrieve a pipe by name.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the pipe.
+    ///
+    /// # Returns
+    /// The pipe fitting registered with the given name, if any.
+    pub fn retrieve_pipe(&self, name: &str) -> Option<Arc<RwLock<dyn IPipeFitting>>> {
+        self.pipes.get(name).cloned()
+    }
+
+    /// Remove a pipe by name.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the pipe.
+    ///
+    /// # Returns
+    /// The pipe fitting that was removed, if any.
+    pub fn remove_pipe(&mut self, name: &str) -> Option<Arc<RwLock<dyn IPipeFitting>>> {
+        self.pipe_types.remove(name);
+        self.pipes.remove(name)
+    }
+
+    /// Add a listener to an input pipe.
+    ///
+    /// Connects a `PipeListener` wrapping the callback to the output of the named input pipe.
+    ///
+    /// # Arguments
+    /// * `input_pipe_name` - The name of the input pipe.
+    /// * `listener` - The callback invoked for every message arriving on the input pipe.
+    ///
+    /// # Returns
+    /// `true` if the listener was connected, otherwise `false`.
+    pub fn add_pipe_listener(&self, input_pipe_name: &str, listener: Arc<dyn Fn(Arc<dyn IPipeMessage>) + Send + Sync>) -> bool {
+        if !self.has_input_pipe(input_pipe_name) { return false }
+        self.pipes.get(input_pipe_name)
+            .and_then(|pipe| pipe.write().ok())
+            .map(|mut pipe| pipe.connect(Arc::new(RwLock::new(PipeListener::new(listener)))))
+            .unwrap_or(false)
+    }
+
+    /// Send a message on an output pipe.
+    ///
+    /// # Arguments
+    /// * `output_pipe_name` - The name of the output pipe.
+    /// * `message` - The `IPipeMessage` to send.
+    ///
+    /// # Returns
+    /// `true` if the message was written successfully, otherwise `false`.
+    pub fn send_message(&self, output_pipe_name: &str, message: Arc<dyn IPipeMessage>) -> bool {
+        if !self.has_output_pipe(output_pipe_name) { return false }
+        self.pipes.get(output_pipe_name)
+            .and_then(|pipe| pipe.write().ok())
+            .map(|mut pipe| pipe.write(message))
+            .unwrap_or(false)
+    }
+}
+
+impl Default for Junction {
+    fn default() -> Self {
+        Self::new()
+    }
+}
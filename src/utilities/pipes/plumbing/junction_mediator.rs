@@ -0,0 +1,226 @@
+use std::any::Any;
+use std::sync::{Arc, RwLock, Weak};
+use crate::interfaces::{IFacade, IMediator, INotification, INotifier};
+use crate::patterns::{Facade, Mediator, Notification};
+use crate::utilities::pipes::interfaces::{IPipeFitting, IPipeMessage};
+use crate::utilities::pipes::plumbing::Junction;
+
+/// Junction Mediator.
+///
+/// A base `IMediator` implementation that manages the `Junction` of a core. Cores are connected
+/// by sending `ACCEPT_INPUT_PIPE` and `ACCEPT_OUTPUT_PIPE` notifications to each core, carrying
+/// the shared pipe fitting (`Arc<RwLock<dyn IPipeFitting>>`) as the body and the pipe name as
+/// the type.
+///
+/// Messages arriving on an accepted input pipe are delivered straight to this `Mediator` (as
+/// registered in its core's `IView`) as a `PIPE_MESSAGE` notification, carrying the
+/// `Arc<dyn IPipeMessage>` as the body and the input pipe name as the type. They are not broadcast
+/// to the rest of the core.
+///
+/// Your implementation should wrap a `JunctionMediator`, delegate `ACCEPT_INPUT_PIPE` and
+/// `ACCEPT_OUTPUT_PIPE` to it, and handle `PIPE_MESSAGE` itself.
+///
+/// Pipe messages are delivered synchronously. A message that travels back into a core whose
+/// `JunctionMediator` is still handling the notification that sent it would block on that
+/// `Mediator`; place a `Queue` in such pipelines.
+///
+/// See `Junction`, `IPipeFitting`, `IPipeMessage`, `Mediator`
+pub struct JunctionMediator {
+    /// The underlying `Mediator` instance.
+    mediator: Mediator,
+    /// The `Junction` managed by this `Mediator`.
+    junction: Junction,
+}
+
+impl JunctionMediator {
+    /// Accept input pipe notification name.
+    pub const ACCEPT_INPUT_PIPE: &'static str = "acceptInputPipe";
+
+    /// Accept output pipe notification name.
+    pub const ACCEPT_OUTPUT_PIPE: &'static str = "acceptOutputPipe";
+
+    /// Pipe message notification name.
+    pub const PIPE_MESSAGE: &'static str = "pipeMessage";
+
+    /// Construct a new `JunctionMediator` instance.
+    ///
+    /// # Arguments
+    /// * `name` - The name this `Mediator` will be registered with (optional, defaults to `Mediator::NAME`).
+    /// * `junction` - The `Junction` managed by this `Mediator`.
+    pub fn new(name: Option<&str>, junction: Junction) -> Self {
+        Self {
+            mediator: Mediator::new(name, None),
+            junction
+        }
+    }
+
+    /// Get the `Junction` managed by this `Mediator`.
+    ///
+    /// # Returns
+    /// A reference to the `Junction`.
+    pub fn junction(&self) -> &Junction {
+        &self.junction
+    }
+
+    /// Get the `Junction` managed by this `Mediator` for modification.
+    ///
+    /// # Returns
+    /// A mutable reference to the `Junction`.
+    pub fn junction_mut(&mut self) -> &mut Junction {
+        &mut self.junction
+    }
+
+    /// Accept an input pipe.
+    ///
+    /// Registers the pipe as an input pipe and listens to it. If an input pipe with this name is
+    /// already registered, the new pipe is connected to it instead, merging both into one input.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the input pipe.
+    /// * `pipe` - The pipe fitting.
+    ///
+    /// # Returns
+    /// `true` if the pipe was accepted, otherwise `false`.
+    pub fn accept_input_pipe(&mut self, name: &str, pipe: Arc<RwLock<dyn IPipeFitting>>) -> bool {
+        if let Some(existing) = self.junction.retrieve_pipe(name) {
+            return self.junction.has_input_pipe(name) && pipe.write().ok()
+                .map(|mut pipe| pipe.connect(existing))
+                .unwrap_or(false)
+        }
+
+        if !self.junction.register_pipe(name, Junction::INPUT, pipe) { return false }
+
+        let listener = {
+            let key = self.key().to_string();
+            let mediator_name = self.name().to_string();
+            let pipe_name = name.to_string();
+            Arc::new(move |message: Arc<dyn IPipeMessage>| {
+                let Some(facade) = Facade::try_get_instance(&key) else { return };
+                let mediator = facade.retrieve_mediator(&mediator_name);
+                if let Some(mediator) = mediator && let Ok(mut mediator) = mediator.write() {
+                    let notification = Notification::new(Self::PIPE_MESSAGE, Some(Arc::new(message)), Some(&pipe_name));
+                    mediator.handle_notification(&(Arc::new(notification) as Arc<dyn INotification>));
+                }
+            })
+        };
+        self.junction.add_pipe_listener(name, listener)
+    }
+
+    /// Accept an output pipe.
+    ///
+    /// Registers the pipe as an output pipe. If an output pipe with this name is already
+    /// registered (typically a `TeeSplit`), the new pipe is connected to its output instead.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the output pipe.
+    /// * `pipe` - The pipe fitting.
+    ///
+    /// # Returns
+    /// `true` if the pipe was accepted, otherwise `false`.
+    pub fn accept_output_pipe(&mut self, name: &str, pipe: Arc<RwLock<dyn IPipeFitting>>) -> bool {
+        match self.junction.retrieve_pipe(name) {
+            Some(existing) => self.junction.has_output_pipe(name) && existing.write().ok()
+                .map(|mut existing| existing.connect(pipe))
+                .unwrap_or(false),
+            None => self.junction.register_pipe(name, Junction::OUTPUT, pipe)
+        }
+    }
+}
+
+impl IMediator for JunctionMediator {
+    /// Get the `JunctionMediator` instance's name.
+    ///
+    /// # Returns
+    /// The name of the `JunctionMediator` instance.
+    fn name(&self) -> &str {
+        self.mediator.name()
+    }
+
+    /// Get the `JunctionMediator`'s view component.
+    ///
+    /// # Returns
+    /// The view component associated with the `JunctionMediator`.
+    fn component(&self) -> Option<&Weak<dyn Any + Send + Sync>> {
+        self.mediator.component()
+    }
+
+    /// Set the `JunctionMediator`'s view component.
+    ///
+    /// # Arguments
+    /// * `component` - The view component.
+    fn set_component(&mut self, component: Option<Weak<dyn Any + Send + Sync>>) {
+        self.mediator.set_component(component);
+    }
+
+    /// List `Notification` interests.
+    ///
+    /// # Returns
+    /// The `ACCEPT_INPUT_PIPE` and `ACCEPT_OUTPUT_PIPE` notification names.
+    fn list_notification_interests(&self) -> Vec<String> {
+        vec![Self::ACCEPT_INPUT_PIPE.into(), Self::ACCEPT_OUTPUT_PIPE.into()]
+    }
+
+    /// Handle `ACCEPT_INPUT_PIPE` and `ACCEPT_OUTPUT_PIPE` notifications.
+    ///
+    /// The notification type is the pipe name, and the body is the pipe fitting.
+    ///
+    /// # Arguments
+    /// * `notification` - The `Notification` to be handled.
+    fn handle_notification(&mut self, notification: &Arc<dyn INotification>) {
+        let pipe = notification.body()
+            .and_then(|body| body.downcast_ref::<Arc<RwLock<dyn IPipeFitting>>>())
+            .cloned();
+
+        if let (Some(name), Some(pipe)) = (notification.get_type(), pipe) {
+            match notification.name() {
+                Self::ACCEPT_INPUT_PIPE => { self.accept_input_pipe(name, pipe); }
+                Self::ACCEPT_OUTPUT_PIPE => { self.accept_output_pipe(name, pipe); }
+                _ => {}
+            }
+        }
+    }
+
+    /// Get the `JunctionMediator` as a dynamic `Any` type.
+    ///
+    /// # Returns
+    /// The `JunctionMediator` instance as a mutable `Any` reference.
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+impl INotifier for JunctionMediator {
+    /// Get the Multiton key for this `JunctionMediator`.
+    ///
+    /// # Returns
+    /// The Multiton key of the `JunctionMediator`.
+    fn key(&self) -> &str {
+        self.mediator.key()
+    }
+
+    /// Get the `IFacade` instance associated with this `JunctionMediator`.
+    ///
+    /// # Returns
+    /// The `IFacade` instance.
+    fn facade(&self) -> Arc<dyn IFacade> {
+        self.mediator.facade()
+    }
+
+    /// Initialize this `JunctionMediator` instance.
+    ///
+    /// # Arguments
+    /// * `key` - The Multiton key for this `JunctionMediator`.
+    fn initialize_notifier(&mut self, key: &str) {
+        self.mediator.initialize_notifier(key);
+    }
+
+    /// Send a `Notification`.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the `Notification` to send.
+    /// * `body` - The body of the `Notification` (optional).
+    /// * `type_` - The type of the `Notification` (optional).
+    fn send_notification(&self, name: &str, body: Option<Arc<dyn Any + Send + Sync>>, type_: Option<&str>) {
+        self.mediator.send_notification(name, body, type_);
+    }
+}
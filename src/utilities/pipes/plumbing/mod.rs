@@ -0,0 +1,24 @@
+mod pipe;
+pub use pipe::Pipe;
+
+mod pipe_listener;
+pub use pipe_listener::PipeListener;
+
+mod tee_split;
+pub use tee_split::TeeSplit;
+
+mod tee_merge;
+pub use tee_merge::TeeMerge;
+
+mod filter;
+pub use filter::Filter;
+pub use filter::FilterFn;
+
+mod queue;
+pub use queue::Queue;
+
+mod junction;
+pub use junction::Junction;
+
+mod junction_mediator;
+pub use junction_mediator::JunctionMediator;
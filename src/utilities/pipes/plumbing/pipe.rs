@@ -0,0 +1,65 @@
+use std::sync::{Arc, RwLock};
+use crate::utilities::pipes::interfaces::{IPipeFitting, IPipeMessage};
+
+/// A base `IPipeFitting` implementation.
+///
+/// This is the most basic `IPipeFitting`, simply allowing the connection of an output fitting
+/// and writing of a message to that output.
+///
+/// See `IPipeFitting`, `IPipeMessage`
+pub struct Pipe {
+    /// The output fitting of this `Pipe`.
+    output: Option<Arc<RwLock<dyn IPipeFitting>>>,
+}
+
+impl Pipe {
+    /// Construct a new `Pipe` instance.
+    ///
+    /// # Arguments
+    /// * `output` - The output fitting to connect (optional).
+    pub fn new(output: Option<Arc<RwLock<dyn IPipeFitting>>>) -> Self {
+        Self {
+            output
+        }
+    }
+}
+
+impl IPipeFitting for Pipe {
+    /// Connect another `IPipeFitting` to the output.
+    ///
+    /// Fittings connect to and write to other fittings in a one way, synchronous chain, as water
+    /// typically flows one direction through a physical pipe.
+    ///
+    /// # Arguments
+    /// * `output` - The `IPipeFitting` to connect to the output.
+    ///
+    /// # Returns
+    /// `true` if no other fitting was already connected, otherwise `false`.
+    fn connect(&mut self, output: Arc<RwLock<dyn IPipeFitting>>) -> bool {
+        if self.output.is_some() { return false }
+        self.output = Some(output);
+        true
+    }
+
+    /// Disconnect the output `IPipeFitting`.
+    ///
+    /// # Returns
+    /// The disconnected output `IPipeFitting`, if any.
+    fn disconnect(&mut self) -> Option<Arc<RwLock<dyn IPipeFitting>>> {
+        self.output.take()
+    }
+
+    /// Write the message to the connected output.
+    ///
+    /// # Arguments
+    /// * `message` - The `IPipeMessage` to write.
+    ///
+    /// # Returns
+    /// `true` if the output fitting accepted the message, otherwise `false`.
+    fn write(&mut self, message: Arc<dyn IPipeMessage>) -> bool {
+        self.output.as_ref()
+            .and_then(|output| output.write().ok())
+            .map(|mut output| output.write(message))
+            .unwrap_or(false)
+    }
+}
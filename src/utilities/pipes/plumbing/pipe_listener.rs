@@ -0,0 +1,55 @@
+use std::sync::{Arc, RwLock};
+use crate::utilities::pipes::interfaces::{IPipeFitting, IPipeMessage};
+
+/// Allows a callback to be attached to a pipeline.
+///
+/// A `PipeListener` is a terminal fitting: it cannot be connected to an output, and every message
+/// written to it is handed to its listener callback.
+///
+/// See `IPipeFitting`, `Junction`
+pub struct PipeListener {
+    /// The callback invoked for every message written to this fitting.
+    listener: Arc<dyn Fn(Arc<dyn IPipeMessage>) + Send + Sync>,
+}
+
+impl PipeListener {
+    /// Construct a new `PipeListener` instance.
+    ///
+    /// # Arguments
+    /// * `listener` - The callback invoked for every message written to this fitting.
+    pub fn new(listener: Arc<dyn Fn(Arc<dyn IPipeMessage>) + Send + Sync>) -> Self {
+        Self {
+            listener
+        }
+    }
+}
+
+impl IPipeFitting for PipeListener {
+    /// Can't connect anything beyond this.
+    ///
+    /// # Returns
+    /// Always `false`.
+    fn connect(&mut self, _output: Arc<RwLock<dyn IPipeFitting>>) -> bool {
+        false
+    }
+
+    /// Can't disconnect since you can't connect, either.
+    ///
+    /// # Returns
+    /// Always `None`.
+    fn disconnect(&mut self) -> Option<Arc<RwLock<dyn IPipeFitting>>> {
+        None
+    }
+
+    /// Write the message to the listener.
+    ///
+    /// # Arguments
+    /// * `message` - The `IPipeMessage` to hand to the listener callback.
+    ///
+    /// # Returns
+    /// Always `true`.
+    fn write(&mut self, message: Arc<dyn IPipeMessage>) -> bool {
+        (self.listener)(message);
+        true
+    }
+}
@@ -0,0 +1,122 @@
+use std::any::Any;
+use std::sync::{Arc, RwLock};
+use crate::utilities::pipes::interfaces::{IPipeFitting, IPipeMessage};
+use crate::utilities::pipes::messages::QueueControlMessage;
+use crate::utilities::pipes::plumbing::Pipe;
+
+/// Pipe Queue.
+///
+/// The `Queue` always stores inbound messages until you send it a `QueueControlMessage::FLUSH`
+/// control message, at which point it writes its buffer to the output pipe fitting. The `Queue`
+/// can be sent a `QueueControlMessage::SORT` control message to go into sort-by-priority mode or
+/// a `QueueControlMessage::FIFO` control message to cancel sort mode and return the default mode
+/// of operation, FIFO.
+///
+/// Queues decouple the writer of a pipeline from its readers, which also makes them the fitting
+/// of choice when a message written by one core could otherwise travel synchronously back into
+/// a `Mediator` that is still handling the notification that caused the write.
+///
+/// See `IPipeFitting`, `QueueControlMessage`
+pub struct Queue {
+    /// The underlying `Pipe` instance holding the output fitting.
+    pipe: Pipe,
+    /// The operation mode, either `QueueControlMessage::FIFO` or `QueueControlMessage::SORT`.
+    mode: String,
+    /// The stored messages.
+    messages: Vec<Arc<dyn IPipeMessage>>,
+}
+
+impl Queue {
+    /// Construct a new `Queue` instance.
+    ///
+    /// # Arguments
+    /// * `output` - The output fitting to connect (optional).
+    pub fn new(output: Option<Arc<RwLock<dyn IPipeFitting>>>) -> Self {
+        Self {
+            pipe: Pipe::new(output),
+            mode: QueueControlMessage::FIFO.into(),
+            messages: Vec::new(),
+        }
+    }
+
+    /// Get the number of messages currently stored.
+    ///
+    /// # Returns
+    /// The number of stored messages.
+    pub fn len(&self) -> usize {
+        self.messages.len()
+    }
+
+    /// Check if the `Queue` holds no messages.
+    ///
+    /// # Returns
+    /// `true` if no messages are stored, otherwise `false`.
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+
+    /// Flush the stored messages to the output.
+    ///
+    /// In sort mode, messages are written in priority order; messages with equal priority keep
+    /// their arrival order.
+    ///
+    /// # Returns
+    /// `true` if every message was written successfully, otherwise `false`.
+    fn flush(&mut self) -> bool {
+        if self.mode == QueueControlMessage::SORT {
+            self.messages.sort_by_key(|message| message.priority());
+        }
+        let mut success = true;
+        for message in std::mem::take(&mut self.messages) {
+            success &= self.pipe.write(message);
+        }
+        success
+    }
+}
+
+impl IPipeFitting for Queue {
+    /// Connect the output fitting.
+    ///
+    /// # Arguments
+    /// * `output` - The `IPipeFitting` to connect to the output.
+    ///
+    /// # Returns
+    /// `true` if no other fitting was already connected, otherwise `false`.
+    fn connect(&mut self, output: Arc<RwLock<dyn IPipeFitting>>) -> bool {
+        self.pipe.connect(output)
+    }
+
+    /// Disconnect the output `IPipeFitting`.
+    ///
+    /// # Returns
+    /// The disconnected output `IPipeFitting`, if any.
+    fn disconnect(&mut self) -> Option<Arc<RwLock<dyn IPipeFitting>>> {
+        self.pipe.disconnect()
+    }
+
+    /// Handle the incoming message.
+    ///
+    /// `QueueControlMessage`s change the mode or flush the `Queue`; every other message is stored.
+    ///
+    /// # Arguments
+    /// * `message` - The `IPipeMessage` to write.
+    ///
+    /// # Returns
+    /// `true` unless a flush failed to write one of the stored messages.
+    fn write(&mut self, message: Arc<dyn IPipeMessage>) -> bool {
+        let any: &dyn Any = &*message;
+        if any.downcast_ref::<QueueControlMessage>().is_none() {
+            self.messages.push(message);
+            return true
+        }
+
+        match message.get_type() {
+            QueueControlMessage::FLUSH => self.flush(),
+            QueueControlMessage::SORT | QueueControlMessage::FIFO => {
+                self.mode = message.get_type().into();
+                true
+            }
+            _ => true
+        }
+    }
+}
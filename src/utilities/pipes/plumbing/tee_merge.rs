@@ -0,0 +1,80 @@
+use std::sync::{Arc, RwLock};
+use crate::utilities::pipes::interfaces::{IPipeFitting, IPipeMessage};
+use crate::utilities::pipes::plumbing::Pipe;
+
+/// Merging Pipe Tee.
+///
+/// Writes the messages from multiple input pipelines into a single output pipe fitting.
+///
+/// See `IPipeFitting`, `TeeSplit`
+pub struct TeeMerge {
+    /// The underlying `Pipe` instance holding the output fitting.
+    pipe: Pipe,
+}
+
+impl TeeMerge {
+    /// Construct a new `TeeMerge` instance.
+    ///
+    /// Connect input fittings with `TeeMerge::connect_input` once the `TeeMerge` has been wrapped
+    /// for sharing.
+    pub fn new() -> Self {
+        Self {
+            pipe: Pipe::new(None)
+        }
+    }
+
+    /// Connect an input fitting to a `TeeMerge`.
+    ///
+    /// `TeeMerge` allows any number of input fittings, each of which is connected with its output
+    /// pointing at the `TeeMerge`.
+    ///
+    /// # Arguments
+    /// * `merge` - The `TeeMerge` to connect the input to.
+    /// * `input` - The input fitting to connect.
+    ///
+    /// # Returns
+    /// `true` if the input fitting accepted the connection, otherwise `false`.
+    pub fn connect_input(merge: &Arc<RwLock<TeeMerge>>, input: &Arc<RwLock<dyn IPipeFitting>>) -> bool {
+        input.write().ok()
+            .map(|mut input| input.connect(Arc::clone(merge) as Arc<RwLock<dyn IPipeFitting>>))
+            .unwrap_or(false)
+    }
+}
+
+impl Default for TeeMerge {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IPipeFitting for TeeMerge {
+    /// Connect the output fitting.
+    ///
+    /// # Arguments
+    /// * `output` - The `IPipeFitting` to connect to the output.
+    ///
+    /// # Returns
+    /// `true` if no other fitting was already connected, otherwise `false`.
+    fn connect(&mut self, output: Arc<RwLock<dyn IPipeFitting>>) -> bool {
+        self.pipe.connect(output)
+    }
+
+    /// Disconnect the output `IPipeFitting`.
+    ///
+    /// # Returns
+    /// The disconnected output `IPipeFitting`, if any.
+    fn disconnect(&mut self) -> Option<Arc<RwLock<dyn IPipeFitting>>> {
+        self.pipe.disconnect()
+    }
+
+    /// Write the message to the connected output.
+    ///
+    /// # Arguments
+    /// * `message` - The `IPipeMessage` to write.
+    ///
+    /// # Returns
+    /// `true` if the output fitting accepted the message, otherwise `false`.
+    fn write(&mut self, message: Arc<dyn IPipeMessage>) -> bool {
+        self.pipe.write(message)
+    }
+}
@@ -0,0 +1,77 @@
+use std::sync::{Arc, RwLock};
+use crate::utilities::pipes::interfaces::{IPipeFitting, IPipeMessage};
+
+/// Splitting Pipe Tee.
+///
+/// Writes input messages to multiple output pipe fittings.
+///
+/// See `IPipeFitting`, `TeeMerge`
+pub struct TeeSplit {
+    /// The output fittings of this `TeeSplit`.
+    outputs: Vec<Arc<RwLock<dyn IPipeFitting>>>,
+}
+
+impl TeeSplit {
+    /// Construct a new `TeeSplit` instance.
+    ///
+    /// # Arguments
+    /// * `outputs` - The output fittings to connect.
+    pub fn new(outputs: Vec<Arc<RwLock<dyn IPipeFitting>>>) -> Self {
+        Self {
+            outputs
+        }
+    }
+
+    /// Disconnect a given output fitting.
+    ///
+    /// # Arguments
+    /// * `target` - The output fitting to disconnect.
+    ///
+    /// # Returns
+    /// The disconnected output fitting, or `None` if it was not connected.
+    pub fn disconnect_fitting(&mut self, target: &Arc<RwLock<dyn IPipeFitting>>) -> Option<Arc<RwLock<dyn IPipeFitting>>> {
+        self.outputs.iter()
+            .position(|output| Arc::ptr_eq(output, target))
+            .map(|index| self.outputs.remove(index))
+    }
+}
+
+impl IPipeFitting for TeeSplit {
+    /// Connect the output fitting.
+    ///
+    /// `TeeSplit` allows any number of output fittings to be connected.
+    ///
+    /// # Arguments
+    /// * `output` - The `IPipeFitting` to connect to the output.
+    ///
+    /// # Returns
+    /// Always `true`.
+    fn connect(&mut self, output: Arc<RwLock<dyn IPipeFitting>>) -> bool {
+        self.outputs.push(output);
+        true
+    }
+
+    /// Disconnect the most recently connected output fitting.
+    ///
+    /// # Returns
+    /// The disconnected output `IPipeFitting`, if any.
+    fn disconnect(&mut self) -> Option<Arc<RwLock<dyn IPipeFitting>>> {
+        self.outputs.pop()
+    }
+
+    /// Write the message to all connected outputs.
+    ///
+    /// # Arguments
+    /// * `message` - The `IPipeMessage` to write.
+    ///
+    /// # Returns
+    /// `true` if every output accepted the message, otherwise `false`.
+    fn write(&mut self, message: Arc<dyn IPipeMessage>) -> bool {
+        self.outputs.iter().fold(true, |success, output| {
+            let written = output.write().ok()
+                .map(|mut output| output.write(Arc::clone(&message)))
+                .unwrap_or(false);
+            success && written
+        })
+    }
+}
@@ -1,8 +1,10 @@
 use std::any::Any;
 use std::sync::{Arc, RwLock};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use puremvc::Error;
 use puremvc::core::{Controller, View};
-use puremvc::interfaces::{ICommand, IFacade, INotification, INotifier};
-use puremvc::patterns::{Notification, SimpleCommand};
+use puremvc::interfaces::{CommandFactory, ICommand, IFacade, INotification, INotifier};
+use puremvc::patterns::{Notification, Observer, SimpleCommand};
 
 /// A utility class used by Controller tests.
 ///
@@ -154,7 +156,7 @@ fn test_register_and_execute_command() {
     let controller = Controller::get_instance("ControllerTestKey2", |k| Controller::new(k));
 
     // Register the ControllerTestCommand for 'ControllerTest' notifications
-    controller.register_command("ControllerTest", || Box::new(ControllerTestCommand::new()));
+    controller.register_command("ControllerTest", Arc::new(|| Box::new(ControllerTestCommand::new())));
 
     // Create a value object with input value 12
     let vo = Arc::new(RwLock::new(ControllerTestVO { input: 12, result: 0 }));
@@ -178,7 +180,7 @@ fn test_register_and_remove_command() {
     let controller = Controller::get_instance("ControllerTestKey3", |k| Controller::new(k));
 
     // Register the ControllerTestCommand for 'ControllerRemoveTest' notifications
-    controller.register_command("ControllerRemoveTest", || Box::new(ControllerTestCommand::new()));
+    controller.register_command("ControllerRemoveTest", Arc::new(|| Box::new(ControllerTestCommand::new())));
 
     // Create a value object with input value 12
     let vo = Arc::new(RwLock::new(ControllerTestVO { input: 12, result: 0 }));
@@ -211,7 +213,7 @@ fn test_has_command() {
     let controller = Controller::get_instance("ControllerTestKey4", |k| Controller::new(k));
 
     // Register the ControllerTestCommand for 'hasCommandTest' notifications
-    controller.register_command("hasCommandTest", || Box::new(ControllerTestCommand::new()));
+    controller.register_command("hasCommandTest", Arc::new(|| Box::new(ControllerTestCommand::new())));
 
     // Assert that has_command returns true for the registered command
     assert_eq!(controller.has_command("hasCommandTest"), true, "Expecting controller.has_command('hasCommandTest')");
@@ -236,11 +238,11 @@ fn test_reregister_and_execute_command() {
     let controller = Controller::get_instance("ControllerTestKey5", |k| Controller::new(k));
 
     // Register the ControllerTestCommand2 for 'ControllerTest2' notifications
-    controller.register_command("ControllerTest2", || Box::new(ControllerTestCommand2::new()));
+    controller.register_command("ControllerTest2", Arc::new(|| Box::new(ControllerTestCommand2::new())));
     // Remove the command
     controller.remove_command("ControllerTest2");
     // Re-register the command
-    controller.register_command("ControllerTest2", || Box::new(ControllerTestCommand2::new()));
+    controller.register_command("ControllerTest2", Arc::new(|| Box::new(ControllerTestCommand2::new())));
 
     // Create a value object with input value 12
     let vo = Arc::new(RwLock::new(ControllerTestVO { input: 12, result: 0 }));
@@ -256,3 +258,141 @@ fn test_reregister_and_execute_command() {
     // Assert that the result is input * 2 (12 * 2 = 24), confirming single execution
     assert_eq!(vo.read().unwrap().result, 24);
 }
+
+/// Tests the `try_*` command methods.
+///
+/// Registers a command with `try_register_command`, asserts that registering another command
+/// for the same notification fails with `Error::AlreadyRegistered`, executes and removes it, and
+/// asserts that executing or removing it again fails with `Error::NotFound`.
+#[test]
+fn test_try_register_execute_and_remove_command() {
+    // Get a Multiton Controller instance
+    let controller = Controller::get_instance("ControllerTestKey6", Controller::new);
+
+    // Register a command, then a second one for the same notification
+    assert_eq!(controller.try_register_command("ControllerTryTest", Arc::new(|| Box::new(ControllerTestCommand::new()))), Ok(()));
+    assert_eq!(controller.try_register_command("ControllerTryTest", Arc::new(|| Box::new(ControllerTestCommand2::new()))).unwrap_err(),
+        Error::AlreadyRegistered { kind: "Command", name: "ControllerTryTest".into() });
+
+    // Execute the command
+    let vo = Arc::new(RwLock::new(ControllerTestVO { input: 12, result: 0 }));
+    let notification: Arc<dyn INotification> = Arc::new(Notification::new("ControllerTryTest", Some(vo.clone()), None));
+    assert_eq!(controller.try_execute_command(&notification), Ok(()));
+    assert_eq!(vo.read().unwrap().result, 24, "Expecting the first registered command executed");
+
+    // Remove the command, then try again
+    assert_eq!(controller.try_remove_command("ControllerTryTest"), Ok(()));
+    assert_eq!(controller.try_has_command("ControllerTryTest"), Ok(false), "Expecting controller.try_has_command('ControllerTryTest') == Ok(false)");
+    assert_eq!(controller.try_execute_command(&notification), Err(Error::NotFound { kind: "Command", name: "ControllerTryTest".into() }));
+    assert_eq!(controller.try_remove_command("ControllerTryTest"), Err(Error::NotFound { kind: "Command", name: "ControllerTryTest".into() }));
+}
+
+/// Tests registering a command factory that captures state.
+///
+/// Registers a factory closure capturing a counter, sends the notification twice, and asserts
+/// that the factory was called once per execution with its captured state intact.
+#[test]
+fn test_register_capturing_factory() {
+    // Get a Multiton Controller instance
+    let controller = Controller::get_instance("ControllerTestKey7", Controller::new);
+
+    // Register a factory that counts the commands it creates
+    let created = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&created);
+    controller.register_command("ControllerFactoryTest", Arc::new(move || {
+        counter.fetch_add(1, Ordering::SeqCst);
+        Box::new(ControllerTestCommand::new())
+    }));
+
+    // Execute the command twice
+    let vo = Arc::new(RwLock::new(ControllerTestVO { input: 5, result: 0 }));
+    let notification: Arc<dyn INotification> = Arc::new(Notification::new("ControllerFactoryTest", Some(vo.clone()), None));
+    controller.execute_command(&notification);
+    controller.execute_command(&notification);
+
+    // Assert that a new command was created for each execution
+    assert_eq!(created.load(Ordering::SeqCst), 2, "Expecting created == 2");
+    assert_eq!(vo.read().unwrap().result, 10, "Expecting vo.result == 10");
+}
+
+/// Tests adding several commands for one notification.
+///
+/// Adds three command factories with different priorities, sends the notification, and asserts
+/// that all commands executed highest priority first and in registration order among equals.
+#[test]
+fn test_add_command_priority_order() {
+    // Get a Multiton Controller instance
+    let controller = Controller::get_instance("ControllerTestKey8", Controller::new);
+
+    // Add factories recording the order their commands are created in
+    let order = Arc::new(RwLock::new(Vec::new()));
+    for (label, priority) in [("low", -1), ("first", 0), ("high", 10), ("second", 0)] {
+        let order = Arc::clone(&order);
+        controller.add_command("ControllerAddTest", Arc::new(move || {
+            order.write().unwrap().push(label);
+            Box::new(ControllerTestCommand2::new())
+        }), priority);
+    }
+
+    // Send the notification
+    let vo = Arc::new(RwLock::new(ControllerTestVO { input: 3, result: 0 }));
+    let notification: Arc<dyn INotification> = Arc::new(Notification::new("ControllerAddTest", Some(vo.clone()), None));
+    controller.execute_command(&notification);
+
+    // Assert that every command executed in priority order
+    assert_eq!(*order.read().unwrap(), vec!["high", "first", "second", "low"], "Expecting high, first, second, low");
+    assert_eq!(vo.read().unwrap().result, 24, "Expecting vo.result == 24");
+}
+
+/// Tests removing a single command mapping.
+///
+/// Adds two command factories for one notification, removes one of them, and asserts that only
+/// the other is executed. Then removes the other and asserts that no command remains.
+#[test]
+fn test_remove_command_factory() {
+    // Get a Multiton Controller instance
+    let controller = Controller::get_instance("ControllerTestKey9", Controller::new);
+
+    // Add two factories
+    let double: CommandFactory = Arc::new(|| Box::new(ControllerTestCommand::new()));
+    let accumulate: CommandFactory = Arc::new(|| Box::new(ControllerTestCommand2::new()));
+    controller.add_command("ControllerRemoveFactoryTest", Arc::clone(&double), 0);
+    controller.add_command("ControllerRemoveFactoryTest", Arc::clone(&accumulate), 0);
+
+    // Remove one of them and send the notification
+    controller.remove_command_factory("ControllerRemoveFactoryTest", &accumulate);
+    let vo = Arc::new(RwLock::new(ControllerTestVO { input: 4, result: 100 }));
+    let notification: Arc<dyn INotification> = Arc::new(Notification::new("ControllerRemoveFactoryTest", Some(vo.clone()), None));
+    controller.execute_command(&notification);
+
+    // Assert that only the remaining command executed
+    assert_eq!(vo.read().unwrap().result, 8, "Expecting vo.result == 8");
+    assert!(controller.has_command("ControllerRemoveFactoryTest"), "Expecting controller.has_command('ControllerRemoveFactoryTest') == true");
+
+    // Remove the remaining one
+    controller.remove_command_factory("ControllerRemoveFactoryTest", &double);
+    assert!(!controller.has_command("ControllerRemoveFactoryTest"), "Expecting controller.has_command('ControllerRemoveFactoryTest') == false");
+}
+
+/// Tests vetoing a notification before its command executes.
+///
+/// Registers a command, then an interceptor observer with a higher priority that stops the
+/// notification's propagation, and asserts that the command is not executed.
+#[test]
+fn test_stop_propagation_before_command() {
+    // Get Multiton Controller and View instances
+    let controller = Controller::get_instance("ControllerTestKey10", Controller::new);
+    let view = View::get_instance("ControllerTestKey10", View::new);
+
+    // Register the command and an interceptor ahead of it
+    controller.register_command("ControllerVetoTest", Arc::new(|| Box::new(ControllerTestCommand::new())));
+    let intercept = Arc::new(|notification: &Arc<dyn INotification>| notification.stop_propagation());
+    view.register_observer_with_priority("ControllerVetoTest", Arc::new(Observer::new(Some(intercept), Some(Arc::new(())))), 1);
+
+    // Send the notification through the View
+    let vo = Arc::new(RwLock::new(ControllerTestVO { input: 12, result: 0 }));
+    view.notify_observers(&(Arc::new(Notification::new("ControllerVetoTest", Some(vo.clone()), None)) as Arc<dyn INotification>));
+
+    // Assert that the command did not execute
+    assert_eq!(vo.read().unwrap().result, 0, "Expecting vo.result == 0");
+}
@@ -1,3 +1,4 @@
 mod controller_test;
 mod model_test;
+mod registry_test;
 mod view_test;
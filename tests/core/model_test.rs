@@ -1,7 +1,10 @@
 use std::any::Any;
-use std::sync::{Arc, RwLock};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, RwLock};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use puremvc::Error;
 use puremvc::core::Model;
-use puremvc::interfaces::{IFacade, INotifier, IProxy};
+use puremvc::interfaces::{IFacade, INotifier, IProxy, ProxyFactory};
 use puremvc::patterns::Proxy;
 
 /// A Proxy subclass used by ModelTest.
@@ -83,6 +86,103 @@ impl IProxy for ModelTestProxy {
     }
 }
 
+/// A Proxy subclass declaring dependencies, used by ModelTest.
+pub struct ModelTestDependentProxy {
+    proxy: Proxy,
+    dependencies: Vec<String>,
+    resolved: Vec<String>,
+    registrations: Arc<Mutex<Vec<String>>>,
+}
+
+impl ModelTestDependentProxy {
+    /// Constructor.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the proxy
+    /// * `dependencies` - The names of the proxies it depends on
+    /// * `registrations` - The log the proxy appends its name to when registered
+    fn new(name: &str, dependencies: &[&str], registrations: &Arc<Mutex<Vec<String>>>) -> Self {
+        Self {
+            proxy: Proxy::new(Some(name), None),
+            dependencies: dependencies.iter().map(|name| name.to_string()).collect(),
+            resolved: vec![],
+            registrations: Arc::clone(registrations),
+        }
+    }
+}
+
+impl INotifier for ModelTestDependentProxy {
+    /// Returns the key associated with this notifier.
+    fn key(&self) -> &str {
+        self.proxy.key()
+    }
+
+    /// Returns the facade instance for this notifier.
+    fn facade(&self) -> Arc<dyn IFacade> {
+        self.proxy.facade()
+    }
+
+    /// Initializes the notifier with the specified key.
+    ///
+    /// # Arguments
+    /// * `key` - The key to associate with this notifier
+    fn initialize_notifier(&mut self, key: &str) {
+        self.proxy.initialize_notifier(key);
+    }
+
+    /// Sends a notification with the specified name, body, and type.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the notification
+    /// * `body` - Optional data payload for the notification
+    /// * `type_` - Optional type identifier for the notification
+    fn send_notification(&self, name: &str, body: Option<Arc<dyn Any + Send + Sync>>, type_: Option<&str>) {
+        self.proxy.send_notification(name, body, type_);
+    }
+}
+
+impl IProxy for ModelTestDependentProxy {
+    /// Returns the name of the proxy.
+    fn name(&self) -> &str { self.proxy.name() }
+
+    /// Returns the data held by the proxy, if any.
+    fn data(&self) -> Option<&Arc<dyn Any + Send + Sync>> {
+        self.proxy.data()
+    }
+
+    /// Sets the data for the proxy.
+    ///
+    /// # Arguments
+    /// * `data` - Optional data to be stored in the proxy
+    fn set_data(&mut self, data: Option<Arc<dyn Any + Send + Sync>>) {
+        self.proxy.set_data(data);
+    }
+
+    /// Returns the names of the proxies this proxy depends on.
+    fn dependencies(&self) -> Vec<String> {
+        self.dependencies.clone()
+    }
+
+    /// Records the names of the resolved dependencies.
+    ///
+    /// # Arguments
+    /// * `dependencies` - The resolved dependencies
+    fn set_dependencies(&mut self, dependencies: HashMap<String, Arc<RwLock<dyn IProxy>>>) {
+        self.resolved = dependencies.into_keys().collect();
+        self.resolved.sort();
+    }
+
+    /// Called when the proxy is registered, appends its name to the registration log.
+    fn on_register(&mut self) {
+        self.registrations.lock().unwrap().push(self.proxy.name().into());
+    }
+
+    /// Returns a mutable reference to the proxy as a dynamic `Any` type.
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
 /// Tests the Model Multiton Factory Method.
 #[test]
 fn test_get_instance() {
@@ -199,3 +299,273 @@ fn test_on_register_and_on_remove() {
             assert_eq!(value, ModelTestProxy::ON_REMOVE_CALLED, "Expecting proxy.data() == ModelTestProxy::ON_REMOVE_CALLED");
         });
 }
+
+/// Tests the `try_*` proxy methods.
+///
+/// Registers a proxy with `try_register_proxy`, asserts that registering another proxy with the
+/// same name fails with `Error::AlreadyRegistered`, then retrieves and removes it, and asserts
+/// that retrieving or removing it again fails with `Error::NotFound`.
+#[test]
+fn test_try_register_retrieve_and_remove_proxy() {
+    // Get a Multiton Model instance
+    let model = Model::get_instance("ModelTestKey6", Model::new);
+
+    // Register a proxy, then a second one with the same name
+    assert_eq!(model.try_register_proxy(Arc::new(RwLock::new(Proxy::new(Some("tries"), None)))), Ok(()));
+    assert_eq!(model.try_register_proxy(Arc::new(RwLock::new(Proxy::new(Some("tries"), None)))).unwrap_err(),
+        Error::AlreadyRegistered { kind: "Proxy", name: "tries".into() });
+
+    // Retrieve and remove the proxy
+    assert_eq!(model.try_has_proxy("tries"), Ok(true), "Expecting model.try_has_proxy('tries') == Ok(true)");
+    assert!(model.try_retrieve_proxy("tries").is_ok(), "Expecting proxy retrieved");
+    assert!(model.try_remove_proxy("tries").is_ok(), "Expecting proxy removed");
+
+    // Assert that the proxy is gone
+    assert_eq!(model.try_has_proxy("tries"), Ok(false), "Expecting model.try_has_proxy('tries') == Ok(false)");
+    assert_eq!(model.try_retrieve_proxy("tries").err(), Some(Error::NotFound { kind: "Proxy", name: "tries".into() }));
+    assert_eq!(model.try_remove_proxy("tries").err(), Some(Error::NotFound { kind: "Proxy", name: "tries".into() }));
+}
+
+/// Tests that a poisoned proxy lock is reported instead of panicking.
+///
+/// Poisons a proxy's lock by panicking while holding it, then asserts that
+/// `try_register_proxy` fails with `Error::LockPoisoned` and that nothing was registered.
+#[test]
+fn test_try_register_poisoned_proxy() {
+    // Get a Multiton Model instance
+    let model = Model::get_instance("ModelTestKey7", Model::new);
+
+    // Poison the proxy's lock
+    let proxy: Arc<RwLock<dyn IProxy>> = Arc::new(RwLock::new(Proxy::new(Some("poisoned"), None)));
+    let clone = Arc::clone(&proxy);
+    let _ = std::thread::spawn(move || {
+        let _guard = clone.write().unwrap();
+        panic!("poison the proxy lock");
+    }).join();
+
+    // Assert that registration reports the poisoned lock
+    assert_eq!(model.try_register_proxy(proxy), Err(Error::LockPoisoned { lock: "Proxy" }));
+    assert!(!model.has_proxy("poisoned"), "Expecting model.has_proxy('poisoned') == false");
+}
+
+/// Tests lazy registration of a proxy by factory.
+///
+/// Registers a factory, asserts that it is not called until the proxy is first retrieved, that
+/// `on_register` was called on the instance by then, and that later retrievals reuse it.
+#[test]
+fn test_register_proxy_factory() {
+    // Get a Multiton Model instance
+    let model = Model::get_instance("ModelTestKey8", Model::new);
+
+    // Register a factory counting its calls
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&calls);
+    let factory: ProxyFactory = Arc::new(move || {
+        counter.fetch_add(1, Ordering::SeqCst);
+        Arc::new(RwLock::new(ModelTestProxy::new()))
+    });
+    model.register_proxy_factory(ModelTestProxy::NAME, factory);
+
+    // Assert that the proxy counts as registered, but is not instantiated
+    assert!(model.has_proxy(ModelTestProxy::NAME), "Expecting model.has_proxy(ModelTestProxy::NAME) == true");
+    assert_eq!(model.proxy_names(), vec![ModelTestProxy::NAME.to_string()], "Expecting the lazy proxy listed");
+    assert_eq!(calls.load(Ordering::SeqCst), 0, "Expecting the factory not called before retrieval");
+
+    // Retrieve the proxy twice
+    let proxy = model.retrieve_proxy(ModelTestProxy::NAME).unwrap();
+    let again = model.retrieve_proxy(ModelTestProxy::NAME).unwrap();
+
+    // Assert that the factory was called once, and on_register was called
+    assert_eq!(calls.load(Ordering::SeqCst), 1, "Expecting the factory called once");
+    assert!(Arc::ptr_eq(&proxy, &again), "Expecting the same instance retrieved twice");
+    let data = proxy.read().unwrap().data().and_then(|arc| arc.downcast_ref::<&'static str>().copied());
+    assert_eq!(data, Some(ModelTestProxy::ON_REGISTER_CALLED), "Expecting proxy.data() == ModelTestProxy::ON_REGISTER_CALLED");
+    assert_eq!(proxy.read().unwrap().key(), "ModelTestKey8", "Expecting the notifier initialized");
+}
+
+/// Tests removing a proxy registered by factory before it is retrieved.
+///
+/// Asserts that the factory is dropped without being called, and that the proxy is no longer
+/// registered.
+#[test]
+fn test_remove_proxy_factory() {
+    // Get a Multiton Model instance
+    let model = Model::get_instance("ModelTestKey9", Model::new);
+
+    // Register a factory counting its calls
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&calls);
+    model.register_proxy_factory("lazy", Arc::new(move || {
+        counter.fetch_add(1, Ordering::SeqCst);
+        Arc::new(RwLock::new(Proxy::new(Some("lazy"), None)))
+    }));
+
+    // Assert that the proxy cannot be registered again while pending
+    assert_eq!(model.try_register_proxy(Arc::new(RwLock::new(Proxy::new(Some("lazy"), None)))).unwrap_err(),
+        Error::AlreadyRegistered { kind: "Proxy", name: "lazy".into() });
+
+    // Remove the proxy before retrieving it
+    assert_eq!(model.try_remove_proxy("lazy").map(|proxy| proxy.is_none()), Ok(true), "Expecting the proxy removed without an instance");
+
+    // Assert that the factory was dropped without being called
+    assert!(!model.has_proxy("lazy"), "Expecting model.has_proxy('lazy') == false");
+    assert!(model.retrieve_proxy("lazy").is_none(), "Expecting model.retrieve_proxy('lazy') == None");
+    assert_eq!(calls.load(Ordering::SeqCst), 0, "Expecting the factory never called");
+}
+
+/// Tests a proxy factory retrieving another proxy registered by factory.
+///
+/// Asserts that the factory may retrieve the other proxy, and that both are then registered.
+#[test]
+fn test_proxy_factory_retrieves_proxy() {
+    // Get a Multiton Model instance
+    let model = Model::get_instance("ModelTestKey14", Model::new);
+
+    // Register a factory retrieving the proxy of another factory
+    model.register_proxy_factory("inner", Arc::new(|| Arc::new(RwLock::new(Proxy::new(Some("inner"), None)))));
+    model.register_proxy_factory("outer", Arc::new(|| {
+        let inner = Model::get_instance("ModelTestKey14", Model::new).retrieve_proxy("inner");
+        Arc::new(RwLock::new(Proxy::new(Some("outer"), inner.map(|inner| Arc::new(inner) as Arc<dyn Any + Send + Sync>))))
+    }));
+
+    // Retrieve the proxy, and assert that both were instantiated
+    let outer = model.retrieve_proxy("outer").unwrap();
+    assert!(outer.read().unwrap().data().is_some(), "Expecting the inner proxy retrieved by the factory");
+    assert!(model.retrieve_proxy("inner").is_some(), "Expecting the inner proxy registered");
+    Model::remove_model("ModelTestKey14");
+}
+
+/// Tests lazily registered proxies that depend on each other.
+///
+/// Registers two factories whose proxies depend on each other, and a factory retrieving its own
+/// proxy, and asserts that retrieving them reports the cycle instead of deadlocking.
+#[test]
+fn test_proxy_factory_cycle() {
+    // Get a Multiton Model instance
+    let model = Model::get_instance("ModelTestKey15", Model::new);
+    let registrations = Arc::new(Mutex::new(vec![]));
+
+    // Register factories of proxies depending on each other
+    for (name, dependency) in [("a", "b"), ("b", "a")] {
+        let log = Arc::clone(&registrations);
+        model.register_proxy_factory(name, Arc::new(move || Arc::new(RwLock::new(ModelTestDependentProxy::new(name, &[dependency], &log)))));
+    }
+    assert_eq!(model.try_retrieve_proxy("a").err(), Some(Error::DependencyCycle { cycle: vec!["a".into(), "b".into(), "a".into()] }));
+    assert_eq!(model.try_retrieve_proxy("b").err(), Some(Error::DependencyCycle { cycle: vec!["b".into(), "a".into(), "b".into()] }));
+    assert!(registrations.lock().unwrap().is_empty(), "Expecting no proxy registered");
+
+    // Register a factory retrieving its own proxy
+    let result = Arc::new(Mutex::new(None));
+    let seen = Arc::clone(&result);
+    model.register_proxy_factory("self", Arc::new(move || {
+        *seen.lock().unwrap() = Some(Model::get_instance("ModelTestKey15", Model::new).try_retrieve_proxy("self").err());
+        Arc::new(RwLock::new(Proxy::new(Some("self"), None)))
+    }));
+    assert!(model.try_retrieve_proxy("self").is_ok(), "Expecting the proxy retrieved");
+    assert_eq!(*result.lock().unwrap(), Some(Some(Error::DependencyCycle { cycle: vec!["self".into(), "self".into()] })),
+        "Expecting the factory's own retrieval reported as a cycle");
+    Model::remove_model("ModelTestKey15");
+}
+
+/// Tests registering proxies in dependency order.
+///
+/// Registers proxies listed before their dependencies, then asserts that each was registered
+/// after its dependencies, and was handed them before `on_register`.
+#[test]
+fn test_register_proxies() {
+    // Get a Multiton Model instance
+    let model = Model::get_instance("ModelTestKey10", Model::new);
+
+    // Register proxies out of order: a depends on b and c, b depends on c
+    let registrations = Arc::new(Mutex::new(vec![]));
+    let a = Arc::new(RwLock::new(ModelTestDependentProxy::new("a", &["b", "c"], &registrations)));
+    let b = Arc::new(RwLock::new(ModelTestDependentProxy::new("b", &["c"], &registrations)));
+    let c = Arc::new(RwLock::new(ModelTestDependentProxy::new("c", &[], &registrations)));
+    assert_eq!(model.register_proxies(vec![a.clone(), b.clone(), c]), Ok(()));
+
+    // Assert that the proxies were registered in dependency order, with their dependencies
+    assert_eq!(*registrations.lock().unwrap(), vec!["c", "b", "a"], "Expecting registration order c, b, a");
+    assert_eq!(a.read().unwrap().resolved, vec!["b", "c"], "Expecting a handed b and c");
+    assert_eq!(b.read().unwrap().resolved, vec!["c"], "Expecting b handed c");
+    assert_eq!(model.proxy_names(), vec!["a", "b", "c"], "Expecting all proxies registered");
+}
+
+/// Tests the errors of dependency resolution.
+///
+/// Asserts that a cycle, a missing dependency and a name registered already are reported, and
+/// that nothing is registered when they are.
+#[test]
+fn test_register_proxies_errors() {
+    // Get a Multiton Model instance
+    let model = Model::get_instance("ModelTestKey11", Model::new);
+    let registrations = Arc::new(Mutex::new(vec![]));
+
+    // Register proxies depending on each other
+    let cycle: Vec<Arc<RwLock<dyn IProxy>>> = vec![
+        Arc::new(RwLock::new(ModelTestDependentProxy::new("a", &["b"], &registrations))),
+        Arc::new(RwLock::new(ModelTestDependentProxy::new("b", &["a"], &registrations))),
+    ];
+    assert_eq!(model.register_proxies(cycle), Err(Error::DependencyCycle { cycle: vec!["a".into(), "b".into(), "a".into()] }));
+
+    // Register a proxy depending on an unregistered one
+    let missing: Vec<Arc<RwLock<dyn IProxy>>> = vec![
+        Arc::new(RwLock::new(ModelTestDependentProxy::new("c", &[], &registrations))),
+        Arc::new(RwLock::new(ModelTestDependentProxy::new("d", &["c", "e"], &registrations))),
+    ];
+    assert_eq!(model.register_proxies(missing), Err(Error::MissingDependency { proxy: "d".into(), dependency: "e".into() }));
+    assert_eq!(model.try_register_proxy(Arc::new(RwLock::new(ModelTestDependentProxy::new("f", &["e"], &registrations)))),
+        Err(Error::MissingDependency { proxy: "f".into(), dependency: "e".into() }));
+    model.register_proxy(Arc::new(RwLock::new(ModelTestDependentProxy::new("g", &["e"], &registrations))));
+
+    // Assert that nothing was registered
+    assert!(registrations.lock().unwrap().is_empty(), "Expecting no proxy registered");
+    assert!(model.proxy_names().is_empty(), "Expecting model.proxy_names() empty");
+
+    // Register a proxy, then a batch with the same name
+    model.register_proxy(Arc::new(RwLock::new(ModelTestDependentProxy::new("h", &[], &registrations))));
+    let taken: Vec<Arc<RwLock<dyn IProxy>>> = vec![
+        Arc::new(RwLock::new(ModelTestDependentProxy::new("i", &[], &registrations))),
+        Arc::new(RwLock::new(ModelTestDependentProxy::new("h", &[], &registrations))),
+    ];
+    assert_eq!(model.register_proxies(taken), Err(Error::AlreadyRegistered { kind: "Proxy", name: "h".into() }));
+    assert_eq!(*registrations.lock().unwrap(), vec!["h".to_string()], "Expecting only the first proxy registered");
+}
+
+/// Tests resolving a dependency registered by factory.
+///
+/// Asserts that the dependency is instantiated, and registered, before the dependent proxy.
+#[test]
+fn test_register_proxies_with_lazy_dependency() {
+    // Get a Multiton Model instance
+    let model = Model::get_instance("ModelTestKey12", Model::new);
+    let registrations = Arc::new(Mutex::new(vec![]));
+
+    // Register the dependency by factory, then the dependent proxy
+    let log = Arc::clone(&registrations);
+    model.register_proxy_factory("lazy", Arc::new(move || Arc::new(RwLock::new(ModelTestDependentProxy::new("lazy", &[], &log)))));
+    let proxy = Arc::new(RwLock::new(ModelTestDependentProxy::new("eager", &["lazy"], &registrations)));
+    assert_eq!(model.register_proxies(vec![proxy.clone()]), Ok(()));
+
+    // Assert that the dependency was instantiated first, and handed to the dependent proxy
+    assert_eq!(*registrations.lock().unwrap(), vec!["lazy", "eager"], "Expecting registration order lazy, eager");
+    assert_eq!(proxy.read().unwrap().resolved, vec!["lazy"], "Expecting eager handed lazy");
+}
+
+/// Tests that removing a Model calls the `on_remove` method of its proxies.
+///
+/// Registers a proxy with a Model that has no Facade, removes the Model, and asserts that the
+/// proxy's `on_remove` was called.
+#[test]
+fn test_remove_model_removes_proxies() {
+    // Get a Multiton Model instance, and register a ModelTestProxy
+    let model = Model::get_instance("ModelTestKey13", Model::new);
+    let proxy = Arc::new(RwLock::new(ModelTestProxy::new()));
+    model.register_proxy(proxy.clone());
+
+    // Remove the Model
+    Model::remove_model("ModelTestKey13");
+
+    // Assert that on_remove was called
+    let data = proxy.read().unwrap().data().and_then(|arc| arc.downcast_ref::<&'static str>().copied());
+    assert_eq!(data, Some(ModelTestProxy::ON_REMOVE_CALLED), "Expecting proxy.data() == ModelTestProxy::ON_REMOVE_CALLED");
+}
@@ -0,0 +1,49 @@
+use std::sync::{Arc, RwLock};
+use puremvc::core::{Registry, View};
+use puremvc::interfaces::INotification;
+use puremvc::patterns::{Facade, Mediator, Observer, Proxy, SimpleCommand};
+
+/// Tests listing the live cores.
+///
+/// Creates a core, asserts that its key is listed, removes it, and asserts that it is no longer
+/// listed and has no snapshot.
+#[test]
+fn test_core_keys() {
+    // Create a core
+    Facade::get_instance("RegistryTestKey1", Facade::new);
+    assert!(Registry::core_keys().contains(&"RegistryTestKey1".to_string()), "Expecting 'RegistryTestKey1' listed");
+
+    // Remove the core
+    Facade::remove_core("RegistryTestKey1");
+    assert!(!Registry::core_keys().contains(&"RegistryTestKey1".to_string()), "Expecting 'RegistryTestKey1' not listed");
+    assert_eq!(Registry::core_info("RegistryTestKey1"), None, "Expecting Registry::core_info('RegistryTestKey1') == None");
+}
+
+/// Tests taking a snapshot of a core.
+///
+/// Registers proxies, a mediator, commands and an observer with a core, and asserts that the
+/// snapshot lists them.
+#[test]
+fn test_core_info() {
+    // Create a core and register its actors
+    let facade = Facade::get_instance("RegistryTestKey2", Facade::new);
+    facade.register_proxy(Arc::new(RwLock::new(Proxy::new(Some("b"), None))));
+    facade.register_proxy(Arc::new(RwLock::new(Proxy::new(Some("a"), None))));
+    facade.register_mediator(Arc::new(RwLock::new(Mediator::new(Some("m"), None))));
+    facade.register_command("Start", Arc::new(|| Box::new(SimpleCommand::new())));
+    facade.add_command("Start", Arc::new(|| Box::new(SimpleCommand::new())), 1);
+    let notify = Arc::new(|_: &Arc<dyn INotification>| {});
+    View::get_instance("RegistryTestKey2", View::new)
+        .register_observer("Stop", Arc::new(Observer::new(Some(notify), Some(Arc::new(())))));
+
+    // Take the snapshot
+    let info = Registry::core_info("RegistryTestKey2").expect("Expecting a snapshot of 'RegistryTestKey2'");
+
+    // Assert that it lists the core's actors
+    assert_eq!(info.key, "RegistryTestKey2");
+    assert_eq!(info.proxy_names, vec!["a", "b"], "Expecting proxies 'a' and 'b'");
+    assert_eq!(info.mediator_names, vec!["m"], "Expecting mediator 'm'");
+    assert_eq!(info.command_counts.get("Start"), Some(&2), "Expecting two commands for 'Start'");
+    assert_eq!(info.observer_counts.get("Start"), Some(&1), "Expecting one observer for 'Start'");
+    assert_eq!(info.observer_counts.get("Stop"), Some(&1), "Expecting one observer for 'Stop'");
+}
@@ -1,7 +1,9 @@
 use std::any::{Any, TypeId};
 use std::sync::{mpsc, Arc, RwLock, Weak};
+use std::time::Duration;
+use puremvc::Error;
 use puremvc::core::View;
-use puremvc::interfaces::{IFacade, IMediator, INotification, INotifier};
+use puremvc::interfaces::{DispatchMode, IFacade, IMediator, INotification, INotifier, IView};
 use puremvc::patterns::{Mediator, Notification, Observer};
 
 /// Constants for notification names used in tests.
@@ -512,11 +514,179 @@ impl IMediator for ViewTestMediator6 {
     }
 }
 
+/// A Mediator subclass used by ViewTest to record the order mediators handle notifications in.
+struct ViewTestPriorityMediator {
+    mediator: Mediator,
+    priority: i32,
+    order: Arc<RwLock<Vec<String>>>
+}
+
+impl ViewTestPriorityMediator {
+    /// Constructor.
+    pub fn new(name: &str, priority: i32, order: Arc<RwLock<Vec<String>>>) -> Self {
+        Self { mediator: Mediator::new(Some(name), None), priority, order }
+    }
+}
+
+impl INotifier for ViewTestPriorityMediator {
+    /// Returns the key associated with this notifier.
+    fn key(&self) -> &str {
+        self.mediator.key()
+    }
+
+    /// Returns the facade instance for this notifier.
+    fn facade(&self) -> Arc<dyn IFacade> {
+        self.mediator.facade()
+    }
+
+    /// Initializes the notifier with the specified key.
+    ///
+    /// # Arguments
+    /// * `key` - The key to associate with this notifier
+    fn initialize_notifier(&mut self, key: &str) {
+        self.mediator.initialize_notifier(key);
+    }
+
+    /// Sends a notification with the specified name, body, and type.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the notification
+    /// * `body` - Optional data payload for the notification
+    /// * `type_` - Optional type identifier for the notification
+    fn send_notification(&self, name: &str, body: Option<Arc<dyn Any + Send + Sync>>, type_: Option<&str>) {
+        self.mediator.send_notification(name, body, type_);
+    }
+}
+
+impl IMediator for ViewTestPriorityMediator {
+    /// Returns the name of the mediator.
+    fn name(&self) -> &str { self.mediator.name() }
+
+    /// Returns the component associated with the mediator, if any.
+    fn component(&self) -> Option<&Weak<dyn Any + Send + Sync>> {
+        self.mediator.component()
+    }
+
+    /// Sets the component for the mediator.
+    ///
+    /// # Arguments
+    /// * `component` - Optional component to be associated with the mediator
+    fn set_component(&mut self, component: Option<Weak<dyn Any + Send + Sync>>) {
+        self.mediator.set_component(component);
+    }
+
+    /// Lists the notifications this mediator is interested in.
+    fn list_notification_interests(&self) -> Vec<String> {
+        vec!["PriorityTestNote".to_string()]
+    }
+
+    /// Returns the notification priority given to the constructor.
+    fn priority(&self) -> i32 {
+        self.priority
+    }
+
+    /// Handles a notification by recording the mediator's name.
+    fn handle_notification(&mut self, _notification: &Arc<dyn INotification>) {
+        self.order.write().unwrap().push(self.name().to_string());
+    }
+
+    /// Returns a mutable reference to the mediator as a dynamic `Any` type.
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// A Mediator subclass used by ViewTest that sends notifications it is itself interested in.
+struct ViewTestReentrantMediator {
+    mediator: Mediator,
+    view: Arc<dyn IView>,
+    order: Arc<RwLock<Vec<String>>>
+}
+
+impl ViewTestReentrantMediator {
+    pub const NAME: &'static str = "ViewTestReentrantMediator";
+    /// Constructor.
+    pub fn new(view: Arc<dyn IView>, order: Arc<RwLock<Vec<String>>>) -> Self {
+        Self { mediator: Mediator::new(Some(Self::NAME), None), view, order }
+    }
+}
+
+impl INotifier for ViewTestReentrantMediator {
+    /// Returns the key associated with this notifier.
+    fn key(&self) -> &str {
+        self.mediator.key()
+    }
+
+    /// Returns the facade instance for this notifier.
+    fn facade(&self) -> Arc<dyn IFacade> {
+        self.mediator.facade()
+    }
+
+    /// Initializes the notifier with the specified key.
+    ///
+    /// # Arguments
+    /// * `key` - The key to associate with this notifier
+    fn initialize_notifier(&mut self, key: &str) {
+        self.mediator.initialize_notifier(key);
+    }
+
+    /// Sends a notification with the specified name, body, and type.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the notification
+    /// * `body` - Optional data payload for the notification
+    /// * `type_` - Optional type identifier for the notification
+    fn send_notification(&self, name: &str, body: Option<Arc<dyn Any + Send + Sync>>, type_: Option<&str>) {
+        self.mediator.send_notification(name, body, type_);
+    }
+}
+
+impl IMediator for ViewTestReentrantMediator {
+    /// Returns the name of the mediator.
+    fn name(&self) -> &str { self.mediator.name() }
+
+    /// Returns the component associated with the mediator, if any.
+    fn component(&self) -> Option<&Weak<dyn Any + Send + Sync>> {
+        self.mediator.component()
+    }
+
+    /// Sets the component for the mediator.
+    ///
+    /// # Arguments
+    /// * `component` - Optional component to be associated with the mediator
+    fn set_component(&mut self, component: Option<Weak<dyn Any + Send + Sync>>) {
+        self.mediator.set_component(component);
+    }
+
+    /// Lists the notifications this mediator is interested in.
+    fn list_notification_interests(&self) -> Vec<String> {
+        vec!["Ping".to_string(), "Pong".to_string()]
+    }
+
+    /// Handles a 'Ping' notification by sending two 'Pong' notifications, recording each notification's type.
+    fn handle_notification(&mut self, notification: &Arc<dyn INotification>) {
+        let type_ = notification.get_type().unwrap_or_default();
+        self.order.write().unwrap().push(format!("{} {}", notification.name(), type_));
+
+        if notification.name() == "Ping" {
+            for type_ in ["first", "second"] {
+                self.view.notify_observers(&(Arc::new(Notification::new("Pong", None, Some(type_))) as Arc<dyn INotification>));
+                self.order.write().unwrap().push(format!("sent {}", type_));
+            }
+        }
+    }
+
+    /// Returns a mutable reference to the mediator as a dynamic `Any` type.
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
 /// Tests the View Multiton Factory Method.
 #[test]
 fn test_get_instance() {
     // Get a Multiton View instance
-    let view = View::get_instance("ViewTestKey1", |k| View::new(k));
+    let view = View::get_instance("ViewTestKey1", View::new);
 
     // Assert that the instance is not null
     assert!(Arc::strong_count(&view) > 0, "Expecting instance not null");
@@ -532,7 +702,7 @@ fn test_get_instance() {
 #[test]
 fn test_register_and_notify_observer() {
     // Get a Multiton View instance
-    let view = View::get_instance("ViewTestKey2", |k| View::new(k));
+    let view = View::get_instance("ViewTestKey2", View::new);
 
     // Create a context object to hold test state
     let context = Arc::new(RwLock::new(Object::default()));
@@ -563,7 +733,7 @@ fn test_register_and_notify_observer() {
 #[test]
 fn test_register_and_retrieve_mediator() {
     // Get a Multiton View instance
-    let view = View::get_instance("ViewTestKey3", |k| View::new(k));
+    let view = View::get_instance("ViewTestKey3", View::new);
 
     // Create a component object for the mediator
     let component = Arc::new(RwLock::new(Object::default()));
@@ -579,7 +749,7 @@ fn test_register_and_retrieve_mediator() {
 #[test]
 fn test_has_mediator() {
     // Get a Multiton View instance
-    let view = View::get_instance("ViewTestKey4", |k| View::new(k));
+    let view = View::get_instance("ViewTestKey4", View::new);
 
     // Create and register a mediator named 'hasMediatorTest'
     let mediator = Mediator::new(Some("hasMediatorTest"), None);
@@ -599,7 +769,7 @@ fn test_has_mediator() {
 #[test]
 fn test_register_and_remove_mediator() {
     // Get a Multiton View instance
-    let view = View::get_instance("ViewTestKey5", |k| View::new(k));
+    let view = View::get_instance("ViewTestKey5", View::new);
 
     // Create a component object for the mediator
     let component = Arc::new(RwLock::new(Object::default()));
@@ -623,7 +793,7 @@ fn test_register_and_remove_mediator() {
 #[test]
 fn test_on_register_and_on_remove() {
     // Get a Multiton View instance
-    let view = View::get_instance("ViewTestKey6", |k| View::new(k));
+    let view = View::get_instance("ViewTestKey6", View::new);
 
     // Create a component object to track registration and removal
     let component = Arc::new(RwLock::new(Object::default()));
@@ -645,7 +815,7 @@ fn test_on_register_and_on_remove() {
 #[test]
 fn test_successive_register_and_remove_mediator() {
     // Get a Multiton View instance
-    let view = View::get_instance("ViewTestKey7", |k| View::new(k));
+    let view = View::get_instance("ViewTestKey7", View::new);
 
     // Create a component object for the mediator
     let component = Arc::new(RwLock::new(Object::default()));
@@ -693,7 +863,7 @@ fn test_successive_register_and_remove_mediator() {
 #[test]
 fn test_remove_mediator_and_subsequent_notify() {
     // Get a Multiton View instance
-    let view = View::get_instance("ViewTestKey8", |k| View::new(k));
+    let view = View::get_instance("ViewTestKey8", View::new);
 
     // Create a component object to track notifications
     let component = Arc::new(RwLock::new(Object::default()));
@@ -734,7 +904,7 @@ fn test_remove_mediator_and_subsequent_notify() {
 #[test]
 fn test_remove_one_of_two_mediators_and_subsequent_notify() {
     // Get a Multiton View instance
-    let view = View::get_instance("ViewTestKey9", |k| View::new(k));
+    let view = View::get_instance("ViewTestKey9", View::new);
 
     // Create a component object to track notifications
     let component = Arc::new(RwLock::new(Object::default()));
@@ -790,7 +960,7 @@ fn test_remove_one_of_two_mediators_and_subsequent_notify() {
 #[test]
 fn test_mediator_reregistration() {
     // Get a Multiton View instance
-    let view = View::get_instance("ViewTestKey10", |k| View::new(k));
+    let view = View::get_instance("ViewTestKey10", View::new);
 
     // Create a component object to track notifications
     let component = Arc::new(RwLock::new(Object::default()));
@@ -835,7 +1005,7 @@ fn test_mediator_reregistration() {
 #[test]
 fn test_modify_observer_list_during_notification() {
     // Get a Multiton View instance
-    let view = View::get_instance("ViewTestKey11", |k| View::new(k));
+    let view = View::get_instance("ViewTestKey11", View::new);
 
     // Create a component object to track notifications
     let component = Arc::new(RwLock::new(Object::default()));
@@ -898,3 +1068,319 @@ fn test_modify_observer_list_during_notification() {
     // Assert that no mediators remain to respond
     assert_eq!(component.write().unwrap().counter, 0);
 }
+
+/// Tests the `try_*` mediator methods.
+///
+/// Registers a mediator with `try_register_mediator`, asserts that registering another mediator
+/// with the same name fails with `Error::AlreadyRegistered` instead of being silently ignored,
+/// then removes it and asserts that retrieving or removing it again fails with `Error::NotFound`.
+#[test]
+fn test_try_register_retrieve_and_remove_mediator() {
+    // Get a Multiton View instance
+    let view = View::get_instance("ViewTestKey12", View::new);
+
+    // Register a mediator, then a second one with the same name
+    assert_eq!(view.try_register_mediator(Arc::new(RwLock::new(Mediator::new(Some("tryMediator"), None)))), Ok(()));
+    assert_eq!(view.try_register_mediator(Arc::new(RwLock::new(Mediator::new(Some("tryMediator"), None)))).unwrap_err(),
+        Error::AlreadyRegistered { kind: "Mediator", name: "tryMediator".into() });
+
+    // Retrieve and remove the mediator
+    assert_eq!(view.try_has_mediator("tryMediator"), Ok(true), "Expecting view.try_has_mediator('tryMediator') == Ok(true)");
+    assert!(view.try_retrieve_mediator("tryMediator").is_ok(), "Expecting mediator retrieved");
+    assert!(view.try_remove_mediator("tryMediator").is_ok(), "Expecting mediator removed");
+
+    // Assert that the mediator is gone
+    assert_eq!(view.try_has_mediator("tryMediator"), Ok(false), "Expecting view.try_has_mediator('tryMediator') == Ok(false)");
+    assert_eq!(view.try_retrieve_mediator("tryMediator").err(), Some(Error::NotFound { kind: "Mediator", name: "tryMediator".into() }));
+    assert_eq!(view.try_remove_mediator("tryMediator").err(), Some(Error::NotFound { kind: "Mediator", name: "tryMediator".into() }));
+}
+
+/// Tests registering observers with priorities.
+///
+/// Registers observers out of priority order and asserts that they are notified highest priority
+/// first, and in registration order among equal priorities.
+#[test]
+fn test_register_observer_with_priority() {
+    // Get a Multiton View instance
+    let view = View::get_instance("ViewTestKey13", View::new);
+
+    // Register observers recording the order they are notified in
+    let order = Arc::new(RwLock::new(Vec::new()));
+    for (label, priority) in [("render", 0), ("log", -10), ("validate", 10), ("render2", 0)] {
+        let notify = {
+            let order = Arc::clone(&order);
+            Arc::new(move |_: &Arc<dyn INotification>| order.write().unwrap().push(label))
+        };
+        let observer = Observer::new(Some(notify), Some(Arc::new(label)));
+        view.register_observer_with_priority("ObserverPriorityTestNote", Arc::new(observer), priority);
+    }
+
+    // Notify the observers
+    view.notify_observers(&(Arc::new(Notification::new("ObserverPriorityTestNote", None, None)) as Arc<dyn INotification>));
+
+    // Assert that the observers were notified in priority order
+    assert_eq!(*order.read().unwrap(), vec!["validate", "render", "render2", "log"], "Expecting validate, render, render2, log");
+}
+
+/// Tests that mediators are notified according to their priority.
+///
+/// Registers a rendering mediator before a validating mediator with a higher priority, and
+/// asserts that the validating mediator handles the notification first.
+#[test]
+fn test_mediator_priority() {
+    // Get a Multiton View instance
+    let view = View::get_instance("ViewTestKey14", View::new);
+
+    // Register the rendering mediator, then the validating one
+    let order = Arc::new(RwLock::new(Vec::new()));
+    view.register_mediator(Arc::new(RwLock::new(ViewTestPriorityMediator::new("render", 0, order.clone()))));
+    view.register_mediator(Arc::new(RwLock::new(ViewTestPriorityMediator::new("validate", 10, order.clone()))));
+
+    // Notify the mediators
+    view.notify_observers(&(Arc::new(Notification::new("PriorityTestNote", None, None)) as Arc<dyn INotification>));
+
+    // Assert that the validating mediator was notified first
+    assert_eq!(*order.read().unwrap(), vec!["validate".to_string(), "render".to_string()], "Expecting validate, render");
+}
+
+/// Tests removing one of several observers.
+///
+/// Registers two observers for a notification, removes one of them by context, and asserts that
+/// only the other is still notified.
+#[test]
+fn test_remove_one_of_two_observers() {
+    // Get a Multiton View instance
+    let view = View::get_instance("ViewTestKey15", View::new);
+
+    // Register two observers counting their notifications
+    let counts = Arc::new(RwLock::new([0, 0]));
+    let contexts: [Arc<dyn Any + Send + Sync>; 2] = [Arc::new(0), Arc::new(1)];
+    for (index, context) in contexts.iter().enumerate() {
+        let notify = {
+            let counts = Arc::clone(&counts);
+            Arc::new(move |_: &Arc<dyn INotification>| counts.write().unwrap()[index] += 1)
+        };
+        view.register_observer("ObserverRemoveTestNote", Arc::new(Observer::new(Some(notify), Some(Arc::clone(context)))));
+    }
+
+    // Remove the first observer and notify
+    view.remove_observer("ObserverRemoveTestNote", Arc::clone(&contexts[0]));
+    view.notify_observers(&(Arc::new(Notification::new("ObserverRemoveTestNote", None, None)) as Arc<dyn INotification>));
+
+    // Assert that only the second observer was notified
+    assert_eq!(*counts.read().unwrap(), [0, 1], "Expecting counts == [0, 1]");
+}
+
+/// Tests stopping the propagation of a notification.
+///
+/// Registers an interceptor observer ahead of another observer and asserts that once the
+/// interceptor stops the notification's propagation, the other observer is not notified.
+#[test]
+fn test_stop_propagation() {
+    // Get a Multiton View instance
+    let view = View::get_instance("ViewTestKey16", View::new);
+
+    // Register an observer, then an interceptor with a higher priority vetoing 'deny' notifications
+    let notified = Arc::new(RwLock::new(Vec::new()));
+    let notify = {
+        let notified = Arc::clone(&notified);
+        Arc::new(move |notification: &Arc<dyn INotification>| notified.write().unwrap().push(notification.get_type().unwrap_or_default().to_string()))
+    };
+    view.register_observer("StopPropagationTestNote", Arc::new(Observer::new(Some(notify), Some(Arc::new("observer")))));
+
+    let intercept = Arc::new(|notification: &Arc<dyn INotification>| {
+        if notification.get_type() == Some("deny") {
+            notification.stop_propagation();
+        }
+    });
+    view.register_observer_with_priority("StopPropagationTestNote", Arc::new(Observer::new(Some(intercept), Some(Arc::new("interceptor")))), 1);
+
+    // Notify the observers with an allowed and a vetoed notification
+    view.notify_observers(&(Arc::new(Notification::new("StopPropagationTestNote", None, Some("allow"))) as Arc<dyn INotification>));
+    view.notify_observers(&(Arc::new(Notification::new("StopPropagationTestNote", None, Some("deny"))) as Arc<dyn INotification>));
+
+    // Assert that only the allowed notification reached the observer
+    assert_eq!(*notified.read().unwrap(), vec!["allow".to_string()], "Expecting only 'allow' notified");
+}
+
+/// Tests deferred dispatch of re-entrant notifications.
+///
+/// Registers a mediator that sends two notifications it is itself interested in while handling
+/// a notification, and asserts that in `DispatchMode::Deferred` the View delivers them after
+/// the current notification in the order they were sent, rather than deadlocking on the
+/// mediator's lock.
+#[test]
+fn test_deferred_dispatch() {
+    // Get a Multiton View instance in deferred dispatch mode
+    let view = View::get_instance("ViewTestKey17", View::new);
+    assert_eq!(view.dispatch_mode(), DispatchMode::Immediate, "Expecting view.dispatch_mode() == DispatchMode::Immediate");
+    view.set_dispatch_mode(DispatchMode::Deferred);
+    assert_eq!(view.dispatch_mode(), DispatchMode::Deferred, "Expecting view.dispatch_mode() == DispatchMode::Deferred");
+
+    // Register the re-entrant mediator
+    let order = Arc::new(RwLock::new(Vec::new()));
+    view.register_mediator(Arc::new(RwLock::new(ViewTestReentrantMediator::new(view.clone(), order.clone()))));
+
+    // Send 'Ping' on another thread, failing rather than hanging on a deadlock
+    let (sender, receiver) = mpsc::channel();
+    {
+        let view = view.clone();
+        std::thread::spawn(move || {
+            view.notify_observers(&(Arc::new(Notification::new("Ping", None, Some("start"))) as Arc<dyn INotification>));
+            let _ = sender.send(());
+        });
+    }
+    assert!(receiver.recv_timeout(Duration::from_secs(5)).is_ok(), "Expecting notify_observers to return");
+
+    // Assert that the 'Pong' notifications were delivered after 'Ping', in the order they were sent
+    assert_eq!(*order.read().unwrap(), vec!["Ping start", "sent first", "sent second", "Pong first", "Pong second"],
+        "Expecting 'Pong' notifications delivered after 'Ping' in FIFO order");
+}
+
+/// Tests background notification delivery.
+///
+/// Registers an observer that blocks until released, and asserts that `notify_observers` returns
+/// before the observer completes, and that the observer runs on a worker thread.
+#[cfg(feature = "thread-pool")]
+#[test]
+fn test_background_dispatch() {
+    // Get a Multiton View instance in background dispatch mode
+    let view = View::get_instance("ViewTestKey18", View::new);
+    view.set_dispatch_mode(DispatchMode::Background { workers: 2, serialize: false });
+
+    // Register an observer reporting its thread once released
+    let (release, released) = mpsc::channel::<()>();
+    let released = Arc::new(std::sync::Mutex::new(released));
+    let (report, reported) = mpsc::channel();
+    let notify = Arc::new(move |_: &Arc<dyn INotification>| {
+        let _ = released.lock().unwrap().recv();
+        let _ = report.send(std::thread::current().id());
+    });
+    view.register_observer("BackgroundTestNote", Arc::new(Observer::new(Some(notify), Some(Arc::new(())))));
+
+    // Notify the observer; this must not wait for it
+    view.notify_observers(&(Arc::new(Notification::new("BackgroundTestNote", None, None)) as Arc<dyn INotification>));
+    assert!(reported.try_recv().is_err(), "Expecting the observer still running");
+
+    // Release the observer and assert that it ran on another thread
+    release.send(()).unwrap();
+    let thread = reported.recv_timeout(Duration::from_secs(5)).expect("Expecting the observer notified");
+    assert_ne!(thread, std::thread::current().id(), "Expecting the observer notified on a worker thread");
+}
+
+/// Tests serialized background notification delivery.
+///
+/// Sends a series of notifications to one observer on a pool of several workers, and asserts
+/// that the observer handles them one at a time, in the order they were sent.
+#[cfg(feature = "thread-pool")]
+#[test]
+fn test_serialized_background_dispatch() {
+    // Get a Multiton View instance in serialized background dispatch mode
+    let view = View::get_instance("ViewTestKey19", View::new);
+    view.set_dispatch_mode(DispatchMode::Background { workers: 4, serialize: true });
+
+    // Register an observer recording notification bodies, and whether it was ever entered concurrently
+    let busy = Arc::new(std::sync::atomic::AtomicBool::new(false));
+    let overlapped = Arc::new(std::sync::atomic::AtomicBool::new(false));
+    let (report, reported) = mpsc::channel();
+    let notify = {
+        let (busy, overlapped) = (busy.clone(), overlapped.clone());
+        Arc::new(move |notification: &Arc<dyn INotification>| {
+            if busy.swap(true, std::sync::atomic::Ordering::SeqCst) {
+                overlapped.store(true, std::sync::atomic::Ordering::SeqCst);
+            }
+            std::thread::sleep(Duration::from_millis(1));
+            busy.store(false, std::sync::atomic::Ordering::SeqCst);
+            let _ = report.send(*notification.body_ref::<i32>().unwrap());
+        })
+    };
+    view.register_observer("SerializedTestNote", Arc::new(Observer::new(Some(notify), Some(Arc::new(())))));
+
+    // Send the notifications
+    for index in 0..20 {
+        view.notify_observers(&(Arc::new(Notification::new("SerializedTestNote", Some(Arc::new(index)), None)) as Arc<dyn INotification>));
+    }
+
+    // Assert that they were handled one at a time, in order
+    let handled: Vec<i32> = (0..20).map(|_| reported.recv_timeout(Duration::from_secs(5)).unwrap()).collect();
+    assert_eq!(handled, (0..20).collect::<Vec<i32>>(), "Expecting notifications handled in order");
+    assert!(!overlapped.load(std::sync::atomic::Ordering::SeqCst), "Expecting no concurrent notification");
+}
+
+/// Tests that serialized background delivery spreads observers across the workers.
+///
+/// Registers several observers on a pool of four workers, and asserts that they were not all
+/// notified on the same worker thread.
+#[cfg(feature = "thread-pool")]
+#[test]
+fn test_serialized_background_dispatch_spreads_observers() {
+    // Get a Multiton View instance in serialized background dispatch mode
+    let view = View::get_instance("ViewTestKey20", View::new);
+    view.set_dispatch_mode(DispatchMode::Background { workers: 4, serialize: true });
+
+    // Register observers reporting their thread
+    let (report, reported) = mpsc::channel();
+    for _ in 0..16 {
+        let report = report.clone();
+        let notify = Arc::new(move |_: &Arc<dyn INotification>| { let _ = report.send(std::thread::current().id()); });
+        view.register_observer("SpreadTestNote", Arc::new(Observer::new(Some(notify), Some(Arc::new(())))));
+    }
+
+    // Notify the observers, and collect the threads they ran on
+    view.notify_observers(&(Arc::new(Notification::new("SpreadTestNote", None, None)) as Arc<dyn INotification>));
+    let mut threads: Vec<_> = (0..16).map(|_| reported.recv_timeout(Duration::from_secs(5)).unwrap()).collect();
+    threads.sort_by_key(|thread| format!("{:?}", thread));
+    threads.dedup();
+    assert!(threads.len() > 1, "Expecting observers notified on more than one worker");
+}
+
+/// Tests that interceptors are told of a background delivery once it has ended.
+///
+/// Registers an observer taking some time and an interceptor reporting the elapsed time it is
+/// given, and asserts that the elapsed time covers the observer's work.
+#[cfg(feature = "thread-pool")]
+#[test]
+fn test_background_dispatch_after_notify() {
+    /// An interceptor reporting the elapsed time of each delivery.
+    struct ElapsedInterceptor(std::sync::Mutex<mpsc::Sender<Duration>>);
+
+    impl puremvc::interfaces::IInterceptor for ElapsedInterceptor {
+        /// Reports the elapsed time.
+        fn after_notify(&self, _: &str, _: &Arc<dyn INotification>, _: usize, elapsed: Duration) {
+            let _ = self.0.lock().unwrap().send(elapsed);
+        }
+    }
+
+    // Get a Multiton View instance in background dispatch mode
+    let view = View::get_instance("ViewTestKey21", View::new);
+    view.set_dispatch_mode(DispatchMode::Background { workers: 2, serialize: false });
+    let (report, reported) = mpsc::channel();
+    view.add_interceptor(Arc::new(ElapsedInterceptor(std::sync::Mutex::new(report))));
+
+    // Register an observer taking some time
+    let notify = Arc::new(|_: &Arc<dyn INotification>| std::thread::sleep(Duration::from_millis(50)));
+    view.register_observer("ElapsedTestNote", Arc::new(Observer::new(Some(notify), Some(Arc::new(())))));
+
+    // Notify the observer, and assert that the elapsed time covers its work
+    view.notify_observers(&(Arc::new(Notification::new("ElapsedTestNote", None, None)) as Arc<dyn INotification>));
+    let elapsed = reported.recv_timeout(Duration::from_secs(5)).expect("Expecting after_notify called");
+    assert!(elapsed >= Duration::from_millis(50), "Expecting the elapsed time to cover the delivery");
+}
+
+/// Tests that removing a View calls the `on_remove` method of its mediators.
+///
+/// Registers a mediator with a View that has no Facade, removes the View, and asserts that the
+/// mediator's `on_remove` was called.
+#[test]
+fn test_remove_view_removes_mediators() {
+    // Get a Multiton View instance, and register a ViewTestMediator4
+    let view = View::get_instance("ViewTestKey22", View::new);
+    let component = Arc::new(RwLock::new(Object::default()));
+    view.register_mediator(Arc::new(RwLock::new(ViewTestMediator4::new(Some(Arc::downgrade(&component).clone())))));
+
+    // Remove the View
+    View::remove_view("ViewTestKey22");
+
+    // Assert that on_remove was called
+    assert!(component.write().unwrap().on_remove_called, "Expecting component.on_remove_called == true");
+}
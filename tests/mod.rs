@@ -1,2 +1,3 @@
 mod core;
-mod patterns;
\ No newline at end of file
+mod patterns;
+mod utilities;
\ No newline at end of file
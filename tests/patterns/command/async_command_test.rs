@@ -0,0 +1,116 @@
+use std::any::Any;
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+use puremvc::interfaces::{IAsyncCommand, ICommand, IFacade, INotification, INotifier};
+use puremvc::patterns::{AsyncCommand, Notification};
+
+/// An AsyncCommand subclass that doubles its input on a worker thread.
+struct AsyncCommandTestCommand {
+    command: AsyncCommand,
+}
+
+impl AsyncCommandTestCommand {
+    /// Constructor.
+    fn new() -> Self {
+        Self { command: AsyncCommand::new() }
+    }
+}
+
+impl INotifier for AsyncCommandTestCommand {
+    /// Returns the key associated with this notifier.
+    fn key(&self) -> &str {
+        self.command.key()
+    }
+
+    /// Returns the facade instance for this notifier.
+    fn facade(&self) -> Arc<dyn IFacade> {
+        self.command.facade()
+    }
+
+    /// Initializes the notifier with the specified key.
+    ///
+    /// # Arguments
+    /// * `key` - The key to associate with this notifier
+    fn initialize_notifier(&mut self, key: &str) {
+        self.command.initialize_notifier(key);
+    }
+
+    /// Sends a notification with the specified name, body, and type.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the notification
+    /// * `body` - Optional data payload for the notification
+    /// * `type_` - Optional type identifier for the notification
+    fn send_notification(&self, name: &str, body: Option<Arc<dyn Any + Send + Sync>>, type_: Option<&str>) {
+        self.command.send_notification(name, body, type_);
+    }
+}
+
+impl IAsyncCommand for AsyncCommandTestCommand {
+    /// Sets the callback to invoke on completion.
+    ///
+    /// # Arguments
+    /// * `on_complete` - The completion callback
+    fn set_on_complete(&mut self, on_complete: Option<Arc<dyn Fn() + Send + Sync>>) {
+        self.command.set_on_complete(on_complete);
+    }
+}
+
+impl ICommand for AsyncCommandTestCommand {
+    /// Doubles the input on a worker thread, then signals completion.
+    ///
+    /// # Arguments
+    /// * `notification` - The notification carrying an `Arc<Mutex<i32>>`
+    fn execute(&mut self, notification: &Arc<dyn INotification>) {
+        let value = notification.body()
+            .and_then(|body| body.downcast_ref::<Arc<Mutex<i32>>>())
+            .cloned()
+            .unwrap();
+        let on_complete = self.command.on_complete();
+        thread::spawn(move || {
+            *value.lock().unwrap() *= 2;
+            if let Some(on_complete) = on_complete {
+                on_complete();
+            }
+        });
+    }
+}
+
+/// Tests that an AsyncCommand invokes its completion callback.
+///
+/// Creates an `AsyncCommand`, sets a completion callback, and calls `command_complete`,
+/// asserting that the callback was invoked.
+#[test]
+fn test_command_complete() {
+    let completed = Arc::new(Mutex::new(false));
+
+    let mut command = AsyncCommand::new();
+    command.set_on_complete(Some({
+        let completed = Arc::clone(&completed);
+        Arc::new(move || *completed.lock().unwrap() = true)
+    }));
+    command.command_complete();
+
+    assert!(*completed.lock().unwrap(), "Expecting completion callback invoked");
+}
+
+/// Tests that an AsyncCommand completes after its work finishes on another thread.
+///
+/// Executes an `AsyncCommandTestCommand` with an input of 5 and waits for its completion
+/// callback, then asserts that the worker thread doubled the input to 10.
+#[test]
+fn test_complete_from_worker_thread() {
+    let value = Arc::new(Mutex::new(5));
+    let (sender, receiver) = mpsc::channel();
+
+    let mut command = AsyncCommandTestCommand::new();
+    command.set_on_complete(Some(Arc::new(move || sender.send(()).unwrap())));
+
+    let notification: Arc<dyn INotification> = Arc::new(Notification::new("AsyncCommandTest", Some(Arc::new(Arc::clone(&value))), None));
+    command.execute(&notification);
+
+    receiver.recv_timeout(Duration::from_secs(5)).expect("Expecting command to complete");
+    assert_eq!(*value.lock().unwrap(), 10, "Expecting value == 10");
+}
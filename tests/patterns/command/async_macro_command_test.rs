@@ -0,0 +1,257 @@
+use std::any::Any;
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+use puremvc::interfaces::{IAsyncCommand, ICommand, IFacade, INotification, INotifier};
+use puremvc::patterns::{AsyncCommand, AsyncMacroCommand, Notification, SimpleCommand};
+
+/// Returns the step log carried by the notification body.
+fn log(notification: &Arc<dyn INotification>) -> Arc<Mutex<Vec<String>>> {
+    notification.body()
+        .and_then(|body| body.downcast_ref::<Arc<Mutex<Vec<String>>>>())
+        .cloned()
+        .unwrap()
+}
+
+/// An AsyncCommand subclass that logs its start and, later on a worker thread, its end.
+struct AsyncMacroCommandTestAsyncCommand {
+    command: AsyncCommand,
+}
+
+impl AsyncMacroCommandTestAsyncCommand {
+    /// Constructor.
+    fn new() -> Self {
+        Self { command: AsyncCommand::new() }
+    }
+}
+
+impl INotifier for AsyncMacroCommandTestAsyncCommand {
+    /// Returns the key associated with this notifier.
+    fn key(&self) -> &str {
+        self.command.key()
+    }
+
+    /// Returns the facade instance for this notifier.
+    fn facade(&self) -> Arc<dyn IFacade> {
+        self.command.facade()
+    }
+
+    /// Initializes the notifier with the specified key.
+    ///
+    /// # Arguments
+    /// * `key` - The key to associate with this notifier
+    fn initialize_notifier(&mut self, key: &str) {
+        self.command.initialize_notifier(key);
+    }
+
+    /// Sends a notification with the specified name, body, and type.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the notification
+    /// * `body` - Optional data payload for the notification
+    /// * `type_` - Optional type identifier for the notification
+    fn send_notification(&self, name: &str, body: Option<Arc<dyn Any + Send + Sync>>, type_: Option<&str>) {
+        self.command.send_notification(name, body, type_);
+    }
+}
+
+impl IAsyncCommand for AsyncMacroCommandTestAsyncCommand {
+    /// Sets the callback to invoke on completion.
+    ///
+    /// # Arguments
+    /// * `on_complete` - The completion callback
+    fn set_on_complete(&mut self, on_complete: Option<Arc<dyn Fn() + Send + Sync>>) {
+        self.command.set_on_complete(on_complete);
+    }
+}
+
+impl ICommand for AsyncMacroCommandTestAsyncCommand {
+    /// Logs "async start", then logs "async end" and completes on a worker thread.
+    ///
+    /// # Arguments
+    /// * `notification` - The notification carrying the step log
+    fn execute(&mut self, notification: &Arc<dyn INotification>) {
+        let log = log(notification);
+        log.lock().unwrap().push("async start".into());
+        let on_complete = self.command.on_complete();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(20));
+            log.lock().unwrap().push("async end".into());
+            if let Some(on_complete) = on_complete {
+                on_complete();
+            }
+        });
+    }
+}
+
+/// A SimpleCommand subclass that logs its execution.
+struct AsyncMacroCommandTestSyncCommand {
+    command: SimpleCommand,
+}
+
+impl AsyncMacroCommandTestSyncCommand {
+    /// Constructor.
+    fn new() -> Self {
+        Self { command: SimpleCommand::new() }
+    }
+}
+
+impl INotifier for AsyncMacroCommandTestSyncCommand {
+    /// Returns the key associated with this notifier.
+    fn key(&self) -> &str {
+        self.command.key()
+    }
+
+    /// Returns the facade instance for this notifier.
+    fn facade(&self) -> Arc<dyn IFacade> {
+        self.command.facade()
+    }
+
+    /// Initializes the notifier with the specified key.
+    ///
+    /// # Arguments
+    /// * `key` - The key to associate with this notifier
+    fn initialize_notifier(&mut self, key: &str) {
+        self.command.initialize_notifier(key);
+    }
+
+    /// Sends a notification with the specified name, body, and type.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the notification
+    /// * `body` - Optional data payload for the notification
+    /// * `type_` - Optional type identifier for the notification
+    fn send_notification(&self, name: &str, body: Option<Arc<dyn Any + Send + Sync>>, type_: Option<&str>) {
+        self.command.send_notification(name, body, type_);
+    }
+}
+
+impl ICommand for AsyncMacroCommandTestSyncCommand {
+    /// Logs "sync".
+    ///
+    /// # Arguments
+    /// * `notification` - The notification carrying the step log
+    fn execute(&mut self, notification: &Arc<dyn INotification>) {
+        log(notification).lock().unwrap().push("sync".into());
+    }
+}
+
+/// An AsyncMacroCommand subclass used by AsyncMacroCommandTest.
+struct AsyncMacroCommandTestCommand {
+    command: AsyncMacroCommand,
+}
+
+impl AsyncMacroCommandTestCommand {
+    /// Constructor.
+    fn new() -> Self {
+        Self { command: AsyncMacroCommand::new() }
+    }
+
+    /// Initializes the AsyncMacroCommand by adding its subcommands.
+    fn initialize_macro_command(&mut self) {
+        self.command.add_async_sub_command(AsyncMacroCommandTestAsyncCommand::new);
+        self.command.add_sub_command(AsyncMacroCommandTestSyncCommand::new);
+        self.command.add_async_sub_command(AsyncMacroCommandTestAsyncCommand::new);
+    }
+}
+
+impl INotifier for AsyncMacroCommandTestCommand {
+    /// Returns the key associated with this notifier.
+    fn key(&self) -> &str {
+        self.command.key()
+    }
+
+    /// Returns the facade instance for this notifier.
+    fn facade(&self) -> Arc<dyn IFacade> {
+        self.command.facade()
+    }
+
+    /// Initializes the notifier with the specified key.
+    ///
+    /// # Arguments
+    /// * `key` - The key to associate with this notifier
+    fn initialize_notifier(&mut self, key: &str) {
+        self.command.initialize_notifier(key);
+    }
+
+    /// Sends a notification with the specified name, body, and type.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the notification
+    /// * `body` - Optional data payload for the notification
+    /// * `type_` - Optional type identifier for the notification
+    fn send_notification(&self, name: &str, body: Option<Arc<dyn Any + Send + Sync>>, type_: Option<&str>) {
+        self.command.send_notification(name, body, type_);
+    }
+}
+
+impl IAsyncCommand for AsyncMacroCommandTestCommand {
+    /// Sets the callback to invoke on completion.
+    ///
+    /// # Arguments
+    /// * `on_complete` - The completion callback
+    fn set_on_complete(&mut self, on_complete: Option<Arc<dyn Fn() + Send + Sync>>) {
+        self.command.set_on_complete(on_complete);
+    }
+}
+
+impl ICommand for AsyncMacroCommandTestCommand {
+    /// Executes the AsyncMacroCommand, initializing and running its subcommands.
+    ///
+    /// # Arguments
+    /// * `notification` - The notification to pass to subcommands
+    fn execute(&mut self, notification: &Arc<dyn INotification>) {
+        self.initialize_macro_command();
+        self.command.execute(notification);
+    }
+}
+
+/// Tests that an AsyncMacroCommand waits for each asynchronous SubCommand.
+///
+/// Executes an `AsyncMacroCommandTestCommand` with an async, a sync, and another async
+/// SubCommand, and asserts that `execute` returns after the first async SubCommand started,
+/// and that every step ran in order before the completion callback was invoked.
+#[test]
+fn test_async_macro_command_execute() {
+    let log = Arc::new(Mutex::new(Vec::<String>::new()));
+    let (sender, receiver) = mpsc::channel();
+
+    let mut command = AsyncMacroCommandTestCommand::new();
+    command.set_on_complete(Some({
+        let log = Arc::clone(&log);
+        Arc::new(move || {
+            log.lock().unwrap().push("complete".into());
+            sender.send(()).unwrap();
+        })
+    }));
+
+    let notification: Arc<dyn INotification> = Arc::new(Notification::new("AsyncMacroCommandTest", Some(Arc::new(Arc::clone(&log))), None));
+    command.execute(&notification);
+    assert_eq!(*log.lock().unwrap(), vec!["async start"], "Expecting execute to return after the first async start");
+
+    receiver.recv_timeout(Duration::from_secs(5)).expect("Expecting macro command to complete");
+    assert_eq!(*log.lock().unwrap(), vec!["async start", "async end", "sync", "async start", "async end", "complete"]);
+}
+
+/// Tests an AsyncMacroCommand with only synchronous SubCommands.
+///
+/// Adds two synchronous SubCommands and asserts that both ran and the completion callback was
+/// invoked before `execute` returned.
+#[test]
+fn test_sync_sub_commands_complete_immediately() {
+    let log = Arc::new(Mutex::new(Vec::<String>::new()));
+
+    let mut command = AsyncMacroCommand::new();
+    command.add_sub_command(AsyncMacroCommandTestSyncCommand::new);
+    command.add_sub_command(AsyncMacroCommandTestSyncCommand::new);
+    command.set_on_complete(Some({
+        let log = Arc::clone(&log);
+        Arc::new(move || log.lock().unwrap().push("complete".into()))
+    }));
+
+    let notification: Arc<dyn INotification> = Arc::new(Notification::new("AsyncMacroCommandTest", Some(Arc::new(Arc::clone(&log))), None));
+    command.execute(&notification);
+
+    assert_eq!(*log.lock().unwrap(), vec!["sync", "sync", "complete"]);
+}
@@ -1,2 +1,5 @@
 mod simple_command_test;
 mod macro_command_test;
+mod async_command_test;
+mod async_macro_command_test;
+mod undoable_command_test;
@@ -0,0 +1,105 @@
+use std::any::Any;
+use std::sync::{Arc, Mutex};
+use puremvc::interfaces::{ICommand, IFacade, INotification, INotifier, IUndoableCommand};
+use puremvc::patterns::{Notification, UndoableCommand};
+
+/// An UndoableCommand subclass that appends the name of its notification to a log.
+struct AppendCommand {
+    command: UndoableCommand,
+    log: Arc<Mutex<Vec<String>>>,
+}
+
+impl AppendCommand {
+    /// Constructor.
+    ///
+    /// # Arguments
+    /// * `log` - The log to append to
+    fn new(log: &Arc<Mutex<Vec<String>>>) -> Self {
+        Self { command: UndoableCommand::new(), log: Arc::clone(log) }
+    }
+
+    /// Appends the name of a notification to the log.
+    ///
+    /// # Arguments
+    /// * `notification` - The notification whose name to append
+    fn append(&self, notification: &Arc<dyn INotification>) {
+        self.log.lock().unwrap().push(notification.name().to_string());
+    }
+}
+
+impl INotifier for AppendCommand {
+    /// Returns the key associated with this notifier.
+    fn key(&self) -> &str {
+        self.command.key()
+    }
+
+    /// Returns the facade instance for this notifier.
+    fn facade(&self) -> Arc<dyn IFacade> {
+        self.command.facade()
+    }
+
+    /// Initializes the notifier with the specified key.
+    ///
+    /// # Arguments
+    /// * `key` - The key to associate with this notifier
+    fn initialize_notifier(&mut self, key: &str) {
+        self.command.initialize_notifier(key);
+    }
+
+    /// Sends a notification with the specified name, body, and type.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the notification
+    /// * `body` - Optional data payload for the notification
+    /// * `type_` - Optional type identifier for the notification
+    fn send_notification(&self, name: &str, body: Option<Arc<dyn Any + Send + Sync>>, type_: Option<&str>) {
+        self.command.send_notification(name, body, type_);
+    }
+}
+
+impl ICommand for AppendCommand {
+    /// Appends the name of the notification to the log, keeping the notification.
+    ///
+    /// # Arguments
+    /// * `notification` - The notification to append
+    fn execute(&mut self, notification: &Arc<dyn INotification>) {
+        self.command.execute(notification);
+        self.append(notification);
+    }
+}
+
+impl IUndoableCommand for AppendCommand {
+    /// Removes the last name appended.
+    fn undo(&mut self) {
+        self.command.undo();
+        self.log.lock().unwrap().pop();
+    }
+
+    /// Appends the name of the kept notification again.
+    fn redo(&mut self) {
+        self.command.redo();
+        self.append(&self.command.notification().unwrap());
+    }
+}
+
+/// Tests that redo applies the changes of an UndoableCommand again.
+///
+/// Executes an `AppendCommand`, undoes and redoes it, and asserts the log at each step, with
+/// the base `UndoableCommand` keeping the notification the redo applies again.
+#[test]
+fn test_redo_applies_changes_again() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let mut command = AppendCommand::new(&log);
+    assert!(command.command.notification().is_none(), "Expecting no notification before execute");
+
+    let notification: Arc<dyn INotification> = Arc::new(Notification::new("UndoableCommandTest", None, None));
+    command.execute(&notification);
+    assert!(Arc::ptr_eq(&command.command.notification().unwrap(), &notification), "Expecting the notification kept");
+    assert_eq!(*log.lock().unwrap(), ["UndoableCommandTest"], "Expecting the notification appended");
+
+    command.undo();
+    assert!(log.lock().unwrap().is_empty(), "Expecting the append reverted");
+
+    command.redo();
+    assert_eq!(*log.lock().unwrap(), ["UndoableCommandTest"], "Expecting the notification appended again");
+}
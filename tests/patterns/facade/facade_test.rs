@@ -1,6 +1,10 @@
 use std::any::Any;
-use std::sync::{Arc, RwLock};
-use puremvc::interfaces::{ICommand, IFacade, INotification, INotifier};
+use std::sync::{Arc, RwLock, Weak};
+use std::time::Duration;
+use puremvc::Error;
+use puremvc::core::View;
+use puremvc::interfaces::{ICommand, IFacade, IInterceptor, IMediator, INotification, INotifier, IProxy};
+use puremvc::patterns::Observer;
 use puremvc::patterns::{Facade, Mediator, Proxy, SimpleCommand};
 
 /// A utility struct to simulate a Flash Sprite for testing.
@@ -72,6 +76,158 @@ impl ICommand for FacadeTestCommand {
     }
 }
 
+/// A Proxy subclass used by FacadeTest to record its removal.
+struct FacadeTestProxy {
+    proxy: Proxy,
+    log: Arc<RwLock<Vec<String>>>
+}
+
+impl FacadeTestProxy {
+    /// Constructor.
+    fn new(log: Arc<RwLock<Vec<String>>>) -> Self {
+        Self { proxy: Proxy::new(Some("FacadeTestProxy"), None), log }
+    }
+}
+
+impl INotifier for FacadeTestProxy {
+    /// Returns the key associated with this notifier.
+    fn key(&self) -> &str {
+        self.proxy.key()
+    }
+
+    /// Returns the facade instance for this notifier.
+    fn facade(&self) -> Arc<dyn IFacade> {
+        self.proxy.facade()
+    }
+
+    /// Initializes the notifier with the specified key.
+    ///
+    /// # Arguments
+    /// * `key` - The key to associate with this notifier
+    fn initialize_notifier(&mut self, key: &str) {
+        self.proxy.initialize_notifier(key);
+    }
+
+    /// Sends a notification with the specified name, body, and type.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the notification
+    /// * `body` - Optional data payload for the notification
+    /// * `type_` - Optional type identifier for the notification
+    fn send_notification(&self, name: &str, body: Option<Arc<dyn Any + Send + Sync>>, type_: Option<&str>) {
+        self.proxy.send_notification(name, body, type_);
+    }
+}
+
+impl IProxy for FacadeTestProxy {
+    /// Returns the name of the proxy.
+    fn name(&self) -> &str { self.proxy.name() }
+
+    /// Returns the data held by the proxy, if any.
+    fn data(&self) -> Option<&Arc<dyn Any + Send + Sync>> {
+        self.proxy.data()
+    }
+
+    /// Sets the data for the proxy.
+    ///
+    /// # Arguments
+    /// * `data` - Optional data to be stored in the proxy
+    fn set_data(&mut self, data: Option<Arc<dyn Any + Send + Sync>>) {
+        self.proxy.set_data(data);
+    }
+
+    /// Called when the proxy is removed, recording the removal.
+    fn on_remove(&mut self) {
+        self.log.write().unwrap().push("proxy removed".into());
+    }
+
+    /// Returns a mutable reference to the proxy as a dynamic `Any` type.
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// A Mediator subclass used by FacadeTest to record the core's removal and its own.
+struct FacadeTestMediator {
+    mediator: Mediator,
+    log: Arc<RwLock<Vec<String>>>
+}
+
+impl FacadeTestMediator {
+    /// Constructor.
+    fn new(log: Arc<RwLock<Vec<String>>>) -> Self {
+        Self { mediator: Mediator::new(Some("FacadeTestMediator"), None), log }
+    }
+}
+
+impl INotifier for FacadeTestMediator {
+    /// Returns the key associated with this notifier.
+    fn key(&self) -> &str {
+        self.mediator.key()
+    }
+
+    /// Returns the facade instance for this notifier.
+    fn facade(&self) -> Arc<dyn IFacade> {
+        self.mediator.facade()
+    }
+
+    /// Initializes the notifier with the specified key.
+    ///
+    /// # Arguments
+    /// * `key` - The key to associate with this notifier
+    fn initialize_notifier(&mut self, key: &str) {
+        self.mediator.initialize_notifier(key);
+    }
+
+    /// Sends a notification with the specified name, body, and type.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the notification
+    /// * `body` - Optional data payload for the notification
+    /// * `type_` - Optional type identifier for the notification
+    fn send_notification(&self, name: &str, body: Option<Arc<dyn Any + Send + Sync>>, type_: Option<&str>) {
+        self.mediator.send_notification(name, body, type_);
+    }
+}
+
+impl IMediator for FacadeTestMediator {
+    /// Returns the name of the mediator.
+    fn name(&self) -> &str { self.mediator.name() }
+
+    /// Returns the component associated with the mediator, if any.
+    fn component(&self) -> Option<&Weak<dyn Any + Send + Sync>> {
+        self.mediator.component()
+    }
+
+    /// Sets the component for the mediator.
+    ///
+    /// # Arguments
+    /// * `component` - Optional component to be associated with the mediator
+    fn set_component(&mut self, component: Option<Weak<dyn Any + Send + Sync>>) {
+        self.mediator.set_component(component);
+    }
+
+    /// Lists the notifications this mediator is interested in.
+    fn list_notification_interests(&self) -> Vec<String> {
+        vec![Facade::CORE_REMOVED.to_string()]
+    }
+
+    /// Handles the core removal notification by recording it.
+    fn handle_notification(&mut self, _notification: &Arc<dyn INotification>) {
+        self.log.write().unwrap().push("core removed".into());
+    }
+
+    /// Called when the mediator is removed, recording the removal.
+    fn on_remove(&mut self) {
+        self.log.write().unwrap().push("mediator removed".into());
+    }
+
+    /// Returns a mutable reference to the mediator as a dynamic `Any` type.
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
 /// Tests the Facade Multiton Factory Method.
 ///
 /// Gets a Facade instance and asserts it is not null.
@@ -95,7 +251,7 @@ fn test_register_command_and_send_notification() {
     // Get a Multiton Facade instance
     let facade = Facade::get_instance("FacadeTestKey2", |k| Facade::new(k));
     // Register the FacadeTestCommand for 'FacadeTestNote' notifications
-    facade.register_command("FacadeTestNote", || Box::new(FacadeTestCommand::new()));
+    facade.register_command("FacadeTestNote", Arc::new(|| Box::new(FacadeTestCommand::new())));
 
     // Create a value object with input value 32
     let vo = Arc::new(RwLock::new(FacadeTestVO{input: 32, result: 0}));
@@ -117,7 +273,7 @@ fn test_register_and_remove_command_and_send_notification() {
     // Get a Multiton Facade instance
     let facade = Facade::get_instance("FacadeTestKey3", |k| Facade::new(k));
     // Register the FacadeTestCommand for 'FacadeTestNote' notifications
-    facade.register_command( "FacadeTestNote", || Box::new(FacadeTestCommand::new()));
+    facade.register_command( "FacadeTestNote", Arc::new(|| Box::new(FacadeTestCommand::new())));
     // Remove the command
     facade.remove_command("FacadeTestNote");
 
@@ -270,7 +426,7 @@ fn test_has_command() {
     // Get a Multiton Facade instance
     let facade = Facade::get_instance("FacadeTestKey9", |k| Facade::new(k));
     // Register the FacadeTestCommand for 'FacadeTestCommand' notifications
-    facade.register_command("FacadeTestCommand", || Box::new(FacadeTestCommand::new()));
+    facade.register_command("FacadeTestCommand", Arc::new(|| Box::new(FacadeTestCommand::new())));
 
     // Assert that has_command returns true for the registered command
     assert!(facade.has_command("FacadeTestCommand"));
@@ -304,3 +460,167 @@ fn test_has_core_and_remove_core() {
     // Assert that the core no longer exists
     assert!(!Facade::has_core("FacadeTestKey10"));
 }
+
+/// Tests the `try_get_instance` method.
+///
+/// Verifies that `try_get_instance` returns `None` for an unregistered core without creating it,
+/// returns the instance once the core exists, and `None` again once the core is removed.
+#[test]
+fn test_try_get_instance() {
+    // Assert that looking up a missing core does not create it
+    assert!(Facade::try_get_instance("FacadeTestKey15").is_none(), "Expecting no instance");
+    assert!(!Facade::has_core("FacadeTestKey15"), "Expecting Facade::has_core('FacadeTestKey15') == false");
+
+    // Create the core, then look it up
+    let facade = Facade::get_instance("FacadeTestKey15", Facade::new);
+    let found = Facade::try_get_instance("FacadeTestKey15");
+    assert!(found.is_some_and(|found| Arc::ptr_eq(&found, &facade)), "Expecting the same instance");
+
+    // Remove the core, then look it up again
+    Facade::remove_core("FacadeTestKey15");
+    assert!(Facade::try_get_instance("FacadeTestKey15").is_none(), "Expecting no instance after remove_core");
+}
+
+/// Tests the `try_*` methods of the Facade.
+///
+/// Asserts that duplicate registrations and lookups of unregistered actors are reported as
+/// errors by the Facade rather than silently ignored.
+#[test]
+fn test_try_methods() {
+    // Get a Multiton Facade instance
+    let facade = Facade::get_instance("FacadeTestKey11", Facade::new);
+
+    // Commands
+    assert_eq!(facade.try_register_command("FacadeTryTest", Arc::new(|| Box::new(FacadeTestCommand::new()))), Ok(()));
+    assert_eq!(facade.try_register_command("FacadeTryTest", Arc::new(|| Box::new(FacadeTestCommand::new()))),
+        Err(Error::AlreadyRegistered { kind: "Command", name: "FacadeTryTest".into() }));
+    assert_eq!(facade.try_has_command("FacadeTryTest"), Ok(true), "Expecting facade.try_has_command('FacadeTryTest') == Ok(true)");
+    assert_eq!(facade.try_remove_command("FacadeTryTest"), Ok(()));
+    assert_eq!(facade.try_remove_command("FacadeTryTest"), Err(Error::NotFound { kind: "Command", name: "FacadeTryTest".into() }));
+
+    // Proxies
+    assert_eq!(facade.try_register_proxy(Arc::new(RwLock::new(Proxy::new(Some("tryProxy"), None)))), Ok(()));
+    assert_eq!(facade.try_register_proxy(Arc::new(RwLock::new(Proxy::new(Some("tryProxy"), None)))),
+        Err(Error::AlreadyRegistered { kind: "Proxy", name: "tryProxy".into() }));
+    assert!(facade.try_remove_proxy("tryProxy").is_ok(), "Expecting proxy removed");
+    assert_eq!(facade.try_retrieve_proxy("tryProxy").err(), Some(Error::NotFound { kind: "Proxy", name: "tryProxy".into() }));
+
+    // Mediators
+    assert_eq!(facade.try_register_mediator(Arc::new(RwLock::new(Mediator::new(Some("tryMediator"), None)))), Ok(()));
+    assert_eq!(facade.try_register_mediator(Arc::new(RwLock::new(Mediator::new(Some("tryMediator"), None)))),
+        Err(Error::AlreadyRegistered { kind: "Mediator", name: "tryMediator".into() }));
+    assert!(facade.try_remove_mediator("tryMediator").is_ok(), "Expecting mediator removed");
+    assert_eq!(facade.try_has_mediator("tryMediator"), Ok(false), "Expecting facade.try_has_mediator('tryMediator') == Ok(false)");
+}
+
+/// Tests the core created notification.
+///
+/// Registers an observer with a core's View before creating its Facade, and asserts that the
+/// observer is notified of the core's creation once, with the core's key as the body.
+#[test]
+fn test_core_created() {
+    // Observe the core's creation
+    let created = Arc::new(RwLock::new(Vec::new()));
+    let notify = {
+        let created = Arc::clone(&created);
+        Arc::new(move |notification: &Arc<dyn INotification>| {
+            created.write().unwrap().push(notification.body_ref::<String>().unwrap().clone());
+        })
+    };
+    View::get_instance("FacadeTestKey12", View::new)
+        .register_observer(Facade::CORE_CREATED, Arc::new(Observer::new(Some(notify), Some(Arc::new(())))));
+
+    // Create the Facade, then get it again
+    Facade::get_instance("FacadeTestKey12", Facade::new);
+    Facade::get_instance("FacadeTestKey12", Facade::new);
+
+    // Assert that the creation was notified once
+    assert_eq!(*created.read().unwrap(), vec!["FacadeTestKey12".to_string()], "Expecting one creation of 'FacadeTestKey12'");
+}
+
+/// Tests core teardown.
+///
+/// Registers a mediator, a proxy and two shutdown hooks, removes the core, and asserts that the
+/// mediator was notified of the removal, the hooks ran in reverse order, and the mediator and
+/// proxy were removed with their `on_remove` methods called.
+#[test]
+fn test_remove_core_teardown() {
+    // Get a Multiton Facade instance
+    let facade = Facade::get_instance("FacadeTestKey13", Facade::new);
+
+    // Register a mediator, a proxy and shutdown hooks recording the teardown
+    let log = Arc::new(RwLock::new(Vec::new()));
+    facade.register_mediator(Arc::new(RwLock::new(FacadeTestMediator::new(log.clone()))));
+    facade.register_proxy(Arc::new(RwLock::new(FacadeTestProxy::new(log.clone()))));
+    for hook in ["hook 1", "hook 2"] {
+        let log = log.clone();
+        facade.add_shutdown_hook(Box::new(move |key| log.write().unwrap().push(format!("{} {}", hook, key))));
+    }
+
+    // Remove the core
+    Facade::remove_core("FacadeTestKey13");
+
+    // Assert the teardown order
+    assert_eq!(*log.read().unwrap(), vec!["core removed", "hook 2 FacadeTestKey13", "hook 1 FacadeTestKey13", "mediator removed", "proxy removed"],
+        "Expecting the core removal notified, then hooks run in reverse, then the mediator and proxy removed");
+    assert!(!Facade::has_core("FacadeTestKey13"), "Expecting Facade::has_core('FacadeTestKey13') == false");
+}
+
+/// An interceptor used by FacadeTest to record the calls it receives.
+struct FacadeTestInterceptor {
+    calls: RwLock<Vec<String>>
+}
+
+impl IInterceptor for FacadeTestInterceptor {
+    /// Records the notification name and observer count.
+    fn before_notify(&self, key: &str, notification: &Arc<dyn INotification>, observer_count: usize) {
+        self.calls.write().unwrap().push(format!("{} before notify {} {}", key, notification.name(), observer_count));
+    }
+
+    /// Records the notification name and observer count.
+    fn after_notify(&self, key: &str, notification: &Arc<dyn INotification>, observer_count: usize, _elapsed: Duration) {
+        self.calls.write().unwrap().push(format!("{} after notify {} {}", key, notification.name(), observer_count));
+    }
+
+    /// Records the notification name and command count.
+    fn before_execute(&self, key: &str, notification: &Arc<dyn INotification>, command_count: usize) {
+        self.calls.write().unwrap().push(format!("{} before execute {} {}", key, notification.name(), command_count));
+    }
+
+    /// Records the notification name and command count.
+    fn after_execute(&self, key: &str, notification: &Arc<dyn INotification>, command_count: usize, _elapsed: Duration) {
+        self.calls.write().unwrap().push(format!("{} after execute {} {}", key, notification.name(), command_count));
+    }
+}
+
+/// Tests adding and removing an interceptor.
+///
+/// Adds an interceptor to a core, sends a notification with a command mapped to it, and asserts
+/// that the interceptor was called around both the delivery and the command execution. Then
+/// removes the interceptor and asserts that it is no longer called.
+#[test]
+fn test_add_and_remove_interceptor() {
+    // Get a Multiton Facade instance with an interceptor
+    let facade = Facade::get_instance("FacadeTestKey14", Facade::new);
+    let interceptor = Arc::new(FacadeTestInterceptor { calls: RwLock::new(Vec::new()) });
+    facade.add_interceptor(interceptor.clone());
+
+    // Send a notification with a command mapped to it
+    facade.register_command("FacadeInterceptorTest", Arc::new(|| Box::new(FacadeTestCommand::new())));
+    let vo = Arc::new(RwLock::new(FacadeTestVO { input: 5, result: 0 }));
+    facade.send_notification("FacadeInterceptorTest", Some(vo.clone()), None);
+
+    // Assert that the interceptor was called around the delivery and the execution
+    assert_eq!(vo.read().unwrap().result, 10, "Expecting vo.result == 10");
+    assert_eq!(*interceptor.calls.read().unwrap(), vec![
+        "FacadeTestKey14 before notify FacadeInterceptorTest 1",
+        "FacadeTestKey14 before execute FacadeInterceptorTest 1",
+        "FacadeTestKey14 after execute FacadeInterceptorTest 1",
+        "FacadeTestKey14 after notify FacadeInterceptorTest 1",
+    ]);
+
+    // Remove the interceptor and send the notification again
+    facade.remove_interceptor(&(interceptor.clone() as Arc<dyn IInterceptor>));
+    facade.send_notification("FacadeInterceptorTest", Some(vo.clone()), None);
+    assert_eq!(interceptor.calls.read().unwrap().len(), 4, "Expecting no further calls");
+}
@@ -0,0 +1,59 @@
+use std::sync::{Arc, Mutex};
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use puremvc::interfaces::IInterceptor;
+use puremvc::patterns::{Facade, LogInterceptor, SimpleCommand};
+
+/// A logger capturing the records logged with the `puremvc` target.
+struct CapturingLogger {
+    records: Mutex<Vec<(Level, String)>>
+}
+
+impl Log for CapturingLogger {
+    /// Accepts records with the `puremvc` target.
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.target() == "puremvc"
+    }
+
+    /// Captures the level and message of a record.
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            self.records.lock().unwrap().push((record.level(), record.args().to_string()));
+        }
+    }
+
+    /// Does nothing, as records are not buffered.
+    fn flush(&self) {}
+}
+
+static LOGGER: CapturingLogger = CapturingLogger { records: Mutex::new(Vec::new()) };
+
+/// Tests logging a core's traffic.
+///
+/// Adds a `LogInterceptor` to a core, sends a notification with a command mapped to it, and
+/// asserts that the delivery and the command execution were logged at the given level.
+#[test]
+fn test_log_interceptor() {
+    // Install the capturing logger
+    log::set_logger(&LOGGER).unwrap();
+    log::set_max_level(LevelFilter::Trace);
+
+    // Get a Multiton Facade instance with a LogInterceptor
+    let facade = Facade::get_instance("LogInterceptorTestKey1", Facade::new);
+    let interceptor = LogInterceptor::new(Level::Info);
+    assert_eq!(interceptor.level(), Level::Info, "Expecting interceptor.level() == Level::Info");
+    facade.add_interceptor(Arc::new(interceptor) as Arc<dyn IInterceptor>);
+
+    // Send a notification with a command mapped to it
+    facade.register_command("LogTest", Arc::new(|| Box::new(SimpleCommand::new())));
+    facade.send_notification("LogTest", None, Some("kind"));
+
+    // Assert that the command execution and the delivery were logged
+    let records: Vec<(Level, String)> = LOGGER.records.lock().unwrap().iter()
+        .filter(|(_, message)| message.starts_with("[LogInterceptorTestKey1]"))
+        .cloned()
+        .collect();
+    assert_eq!(records.len(), 2, "Expecting two records");
+    assert!(records.iter().all(|(level, _)| *level == Level::Info), "Expecting records logged at Level::Info");
+    assert!(records[0].1.starts_with("[LogInterceptorTestKey1] execute LogTest (type: kind) commands: 1"), "Expecting the execution logged first");
+    assert!(records[1].1.starts_with("[LogInterceptorTestKey1] notify LogTest (type: kind) observers: 1"), "Expecting the delivery logged last");
+}
@@ -0,0 +1,4 @@
+#[cfg(feature = "log")]
+mod log_interceptor_test;
+
+mod notification_recorder_test;
@@ -0,0 +1,89 @@
+use std::sync::{Arc, RwLock};
+use puremvc::core::View;
+use puremvc::interfaces::{IInterceptor, INotification};
+use puremvc::patterns::{Facade, NotificationRecorder, Observer};
+
+/// Register an observer collecting the names and bodies of the notifications it receives.
+///
+/// # Arguments
+/// * `key` - The key of the core to observe.
+/// * `names` - The notification names to observe.
+///
+/// # Returns
+/// The names and bodies received, in delivery order.
+fn observe(key: &str, names: &[&str]) -> Arc<RwLock<Vec<(String, i32)>>> {
+    let view = View::get_instance(key, View::new);
+    let received = Arc::new(RwLock::new(Vec::new()));
+    for name in names {
+        let notify = {
+            let received = received.clone();
+            Arc::new(move |notification: &Arc<dyn INotification>| {
+                let body = notification.body().and_then(|body| body.downcast_ref::<i32>()).copied().unwrap_or_default();
+                received.write().unwrap().push((notification.name().to_string(), body));
+            })
+        };
+        view.register_observer(name, Arc::new(Observer::new(Some(notify), Some(received.clone()))));
+    }
+    received
+}
+
+/// Tests recording notifications into a ring buffer.
+///
+/// Adds a recorder with a capacity of two to a core, sends three notifications, and asserts that
+/// only the last two were kept, with their key, name, type and serialized body.
+#[test]
+fn test_record_notifications() {
+    // Get a Multiton Facade instance with a recorder
+    let facade = Facade::get_instance("NotificationRecorderTestKey1", Facade::new);
+    let recorder = Arc::new(NotificationRecorder::new(2));
+    assert_eq!(recorder.capacity(), 2, "Expecting recorder.capacity() == 2");
+    facade.add_interceptor(recorder.clone());
+
+    // Send three notifications
+    facade.send_notification("Note1", Some(Arc::new(1)), None);
+    facade.send_notification("Note2", Some(Arc::new(String::from("two"))), Some("text"));
+    facade.send_notification("Note3", Some(Arc::new(vec![3])), None);
+
+    // Assert that the last two notifications were recorded
+    let records = recorder.records();
+    assert_eq!(records.len(), 2, "Expecting records.len() == 2");
+    assert_eq!(records[0].key, "NotificationRecorderTestKey1", "Expecting records[0].key == NotificationRecorderTestKey1");
+    assert_eq!(records[0].name, "Note2", "Expecting records[0].name == Note2");
+    assert_eq!(records[0].type_.as_deref(), Some("text"), "Expecting records[0].type_ == text");
+    assert_eq!(records[0].serialized_body.as_deref(), Some("two"), "Expecting records[0].serialized_body == two");
+    assert_eq!(records[1].name, "Note3", "Expecting records[1].name == Note3");
+    assert!(records[1].serialized_body.is_none(), "Expecting records[1].serialized_body is None");
+    assert!(records[1].body.is_some(), "Expecting records[1].body is Some");
+    assert!(records[0].timestamp <= records[1].timestamp, "Expecting records in delivery order");
+
+    // Clear the records
+    recorder.clear();
+    assert!(recorder.records().is_empty(), "Expecting no records");
+}
+
+/// Tests replaying recorded notifications into a fresh core.
+///
+/// Records notifications sent to a core, replays them into another core, and asserts that its
+/// observer received the same sequence of notifications.
+#[test]
+fn test_replay_notifications() {
+    // Record notifications sent to a core
+    let facade = Facade::get_instance("NotificationRecorderTestKey2", Facade::new);
+    let recorder = Arc::new(NotificationRecorder::new(10));
+    facade.add_interceptor(recorder.clone() as Arc<dyn IInterceptor>);
+    let received = observe("NotificationRecorderTestKey2", &["Note1", "Note2"]);
+    facade.send_notification("Note1", Some(Arc::new(1)), None);
+    facade.send_notification("Note2", Some(Arc::new(2)), None);
+    facade.send_notification("Note1", Some(Arc::new(3)), None);
+    assert_eq!(recorder.records()[0].serialized_body.as_deref(), Some("1"), "Expecting records[0].serialized_body == 1");
+
+    // Replay them into a fresh core
+    let replayed = Facade::get_instance("NotificationRecorderTestKey3", Facade::new);
+    let replay_received = observe("NotificationRecorderTestKey3", &["Note1", "Note2"]);
+    recorder.replay(replayed.as_ref());
+
+    // Assert that the fresh core received the same sequence
+    assert_eq!(*replay_received.read().unwrap(), *received.read().unwrap(), "Expecting the same sequence");
+    assert_eq!(replay_received.read().unwrap().len(), 3, "Expecting three notifications");
+    assert_eq!(recorder.records().len(), 3, "Expecting the replay not recorded");
+}
@@ -2,4 +2,6 @@ mod command;
 mod mediator;
 mod observer;
 mod proxy;
-mod facade;
\ No newline at end of file
+mod facade;
+mod interceptor;
+mod persistence;
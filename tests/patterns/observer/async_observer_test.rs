@@ -0,0 +1,122 @@
+use std::any::Any;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use puremvc::core::View;
+use puremvc::interfaces::{AsyncNotifyFn, BoxFuture, IFacade, INotification, IObserver};
+use puremvc::patterns::{AsyncObserver, Facade, Notification, Observer};
+
+/// Creates an asynchronous callback that sleeps, then records the notification name and label.
+fn record(label: &'static str, log: &Arc<Mutex<Vec<String>>>) -> AsyncNotifyFn {
+    let log = Arc::clone(log);
+    Arc::new(move |notification: Arc<dyn INotification>| {
+        let log = Arc::clone(&log);
+        Box::pin(async move {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            log.lock().unwrap().push(format!("{}:{}", notification.name(), label));
+        }) as BoxFuture
+    })
+}
+
+/// Tests awaiting asynchronous observers through `send_notification_async`.
+///
+/// Registers two `AsyncObserver`s and a synchronous `Observer` for the same notification, then
+/// awaits `send_notification_async` and asserts that every observer handled the notification in
+/// registration order by the time the future completed.
+#[tokio::test]
+async fn test_send_notification_async() {
+    let key = "AsyncObserverTestSend";
+    let facade = Facade::get_instance(key, Facade::new);
+    let view = View::get_instance(key, View::new);
+    let log = Arc::new(Mutex::new(Vec::new()));
+
+    let context: Arc<dyn Any + Send + Sync> = Arc::new(0);
+    view.register_observer("AsyncObserverTestNote", Arc::new(AsyncObserver::new(Some(record("first", &log)), Some(Arc::clone(&context)))));
+    view.register_observer("AsyncObserverTestNote", Arc::new(Observer::new(Some({
+        let log = Arc::clone(&log);
+        Arc::new(move |notification: &Arc<dyn INotification>| log.lock().unwrap().push(format!("{}:sync", notification.name())))
+    }), Some(Arc::clone(&context)))));
+    view.register_observer("AsyncObserverTestNote", Arc::new(AsyncObserver::new(Some(record("second", &log)), Some(Arc::clone(&context)))));
+
+    facade.send_notification_async("AsyncObserverTestNote", None, None).await;
+
+    assert_eq!(*log.lock().unwrap(), vec![
+        "AsyncObserverTestNote:first",
+        "AsyncObserverTestNote:sync",
+        "AsyncObserverTestNote:second",
+    ]);
+
+    Facade::remove_core(key);
+}
+
+/// Tests notifying an AsyncObserver synchronously.
+///
+/// Notifies an `AsyncObserver` through `notify_observer` outside of any runtime and asserts that
+/// the call returned only after its asynchronous callback completed.
+#[test]
+fn test_notify_observer_blocks() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let notify = {
+        let log = Arc::clone(&log);
+        Arc::new(move |notification: Arc<dyn INotification>| {
+            let log = Arc::clone(&log);
+            Box::pin(async move {
+                let (sender, receiver) = std::sync::mpsc::channel();
+                std::thread::spawn(move || sender.send(()).unwrap());
+                receiver.recv().unwrap();
+                log.lock().unwrap().push(notification.name().to_string());
+            }) as BoxFuture
+        })
+    };
+
+    let observer = AsyncObserver::new(Some(notify), None);
+    let notification: Arc<dyn INotification> = Arc::new(Notification::new("AsyncObserverTestBlock", None, None));
+    observer.notify_observer(&notification);
+
+    assert_eq!(*log.lock().unwrap(), vec!["AsyncObserverTestBlock"]);
+}
+
+/// Tests the AsyncObserver accessors.
+///
+/// Sets a synchronous callback and a context through the `IObserver` accessors and asserts that
+/// the callback is invoked through `notify_observer_async` and that the context compares equal.
+#[tokio::test]
+async fn test_async_observer_accessors() {
+    let count = Arc::new(Mutex::new(0));
+    let context: Arc<dyn Any + Send + Sync> = Arc::new(Arc::clone(&count));
+
+    let mut observer: Box<dyn IObserver> = Box::new(AsyncObserver::new(None, None));
+    observer.set_notify(Some({
+        let count = Arc::clone(&count);
+        Arc::new(move |_: &Arc<dyn INotification>| *count.lock().unwrap() += 1)
+    }));
+    observer.set_context(Some(Arc::clone(&context)));
+
+    let notification: Arc<dyn INotification> = Arc::new(Notification::new("AsyncObserverTestAccessors", None, None));
+    observer.notify_observer_async(&notification).await;
+
+    assert_eq!(*count.lock().unwrap(), 1, "Expecting count == 1");
+    assert!(observer.compare_notify_context(&context), "Expecting observer.compare_notify_context(context) == true");
+}
+
+/// Tests awaiting delivery through a notifier's facade.
+///
+/// Registers an `AsyncObserver` and awaits `notify_observers_async` on the `IView` with a
+/// notification that has no observers and one that has, asserting that only the latter was
+/// recorded.
+#[tokio::test]
+async fn test_notify_observers_async() {
+    let key = "AsyncObserverTestNotify";
+    let facade: Arc<dyn IFacade> = Facade::get_instance(key, Facade::new);
+    let view = View::get_instance(key, View::new);
+    let log = Arc::new(Mutex::new(Vec::new()));
+
+    view.register_observer("AsyncObserverTestNotify", Arc::new(AsyncObserver::new(Some(record("observer", &log)), Some(Arc::new(0)))));
+
+    let unobserved: Arc<dyn INotification> = Arc::new(Notification::new("AsyncObserverTestNobody", None, None));
+    view.notify_observers_async(&unobserved).await;
+    facade.facade().send_notification_async("AsyncObserverTestNotify", None, None).await;
+
+    assert_eq!(*log.lock().unwrap(), vec!["AsyncObserverTestNotify:observer"]);
+
+    Facade::remove_core(key);
+}
@@ -1,3 +1,8 @@
 mod notification_test;
 mod observer_test;
 mod notifier_test;
+mod typed_notification_test;
+#[cfg(feature = "async")]
+mod async_observer_test;
+#[cfg(feature = "serde")]
+mod serialized_notification_test;
@@ -74,4 +74,18 @@ fn test_to_string() {
 
     // Assert that the to_string output matches the expected string
     assert_eq!(note.to_string(), expected);
-}
\ No newline at end of file
+}
+/// Tests stopping the propagation of a Notification.
+#[test]
+fn test_stop_propagation() {
+    // Create a new Notification
+    let notification = Notification::new("TestNote", None, None);
+    assert!(!notification.is_propagation_stopped(), "Expecting notification.is_propagation_stopped() == false");
+
+    // Stop its propagation through a shared reference
+    let notification: Arc<dyn INotification> = Arc::new(notification);
+    notification.stop_propagation();
+
+    // Assert that the propagation is stopped
+    assert!(notification.is_propagation_stopped(), "Expecting notification.is_propagation_stopped() == true");
+}
@@ -78,7 +78,7 @@ fn test_notifier() {
     // Get a Multiton Facade instance
     let facade= Facade::get_instance("NotifierTestKey1", |k| Facade::new(k));
     // Register the NotifierTestCommand for 'NotifierTestNote' notifications
-    facade.register_command("NotifierTestNote", || Box::new(NotifierTestCommand::new()));
+    facade.register_command("NotifierTestNote", Arc::new(|| Box::new(NotifierTestCommand::new())));
 
     // Create a value object with input value 5
     let vo = Arc::new(RwLock::new(NotifierTestVO{ input: 5, result: 0 }));
@@ -0,0 +1,75 @@
+use std::sync::Arc;
+use serde::{Deserialize, Serialize};
+use puremvc::Error;
+use puremvc::patterns::{BodyCodecRegistry, Notification, SerializedNotification};
+
+/// A notification body used by SerializedNotificationTest.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+/// Tests round-tripping a notification through JSON.
+///
+/// Registers a codec for the body of 'PointMoved', encodes a notification, writes it to JSON,
+/// reads it back and decodes it, asserting that the name, type and body are preserved.
+#[test]
+fn test_json_round_trip() {
+    let mut registry = BodyCodecRegistry::new();
+    registry.register::<Point>("PointMoved");
+    assert!(registry.has_codec("PointMoved"), "Expecting a codec for PointMoved");
+
+    let notification = Notification::new("PointMoved", Some(Arc::new(Point { x: 1, y: 2 })), Some("absolute"));
+    let json = registry.encode(&notification).unwrap().to_json().unwrap();
+    assert_eq!(json, r#"{"name":"PointMoved","type":"absolute","body":{"x":1,"y":2}}"#, "Expecting the JSON representation");
+
+    let decoded = registry.decode(SerializedNotification::from_json(&json).unwrap()).unwrap();
+    assert_eq!(decoded.name(), "PointMoved", "Expecting name == PointMoved");
+    assert_eq!(decoded.get_type(), Some("absolute"), "Expecting type == absolute");
+    assert_eq!(decoded.body_ref::<Point>().unwrap(), &Point { x: 1, y: 2 }, "Expecting body == Point {{ x: 1, y: 2 }}");
+}
+
+/// Tests round-tripping a notification through the binary format.
+///
+/// Encodes a notification with a body and one without, writes them to bytes, reads them back
+/// and decodes them, asserting that they are preserved.
+#[test]
+fn test_binary_round_trip() {
+    let mut registry = BodyCodecRegistry::new();
+    registry.register::<Vec<String>>("Names");
+
+    let notification = Notification::new("Names", Some(Arc::new(vec![String::from("a"), String::from("b")])), None);
+    let bytes = registry.encode(&notification).unwrap().to_bytes().unwrap();
+    let decoded = registry.decode(SerializedNotification::from_bytes(&bytes).unwrap()).unwrap();
+    assert_eq!(decoded.name(), "Names", "Expecting name == Names");
+    assert!(decoded.get_type().is_none(), "Expecting no type");
+    assert_eq!(decoded.body_ref::<Vec<String>>().unwrap(), &vec![String::from("a"), String::from("b")], "Expecting body == [a, b]");
+
+    let empty = Notification::new("Empty", None, None);
+    let bytes = registry.encode(&empty).unwrap().to_bytes().unwrap();
+    let decoded = registry.decode(SerializedNotification::from_bytes(&bytes).unwrap()).unwrap();
+    assert_eq!(decoded.name(), "Empty", "Expecting name == Empty");
+    assert!(decoded.body().is_none(), "Expecting no body");
+}
+
+/// Tests the errors reported when a body cannot be encoded or decoded.
+#[test]
+fn test_codec_errors() {
+    let mut registry = BodyCodecRegistry::new();
+    let notification = Notification::new("Count", Some(Arc::new(5u32)), None);
+    assert_eq!(registry.encode(&notification).unwrap_err(), Error::NotFound { kind: "Body codec", name: "Count".into() },
+        "Expecting Error::NotFound without a codec");
+
+    registry.register::<String>("Count");
+    assert!(matches!(registry.encode(&notification), Err(Error::BodyTypeMismatch { .. })),
+        "Expecting Error::BodyTypeMismatch for a body of another type");
+
+    let serialized = SerializedNotification::from_json(r#"{"name":"Count","body":5}"#).unwrap();
+    assert!(matches!(registry.decode(serialized), Err(Error::Serialization { notification, .. }) if notification == "Count"),
+        "Expecting Error::Serialization for a body not valid for the codec");
+
+    registry.remove("Count");
+    assert!(!registry.has_codec("Count"), "Expecting no codec for Count");
+    assert!(SerializedNotification::from_json("not json").is_err(), "Expecting an error for invalid JSON");
+}
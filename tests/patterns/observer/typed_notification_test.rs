@@ -0,0 +1,96 @@
+use std::sync::{Arc, Mutex};
+use puremvc::Error;
+use puremvc::core::View;
+use puremvc::interfaces::{INotification, INotifier, ITypedNotifier};
+use puremvc::patterns::{Facade, Notification, Observer, SimpleCommand, TypedNotification};
+
+/// A utility struct used as a typed notification body.
+#[derive(Debug, PartialEq)]
+struct Login {
+    user: String,
+}
+
+/// Tests retrieving a notification body as a given type.
+///
+/// Creates a `Notification` with a `Login` body and asserts that `body_as` and `body_ref`
+/// return it.
+#[test]
+fn test_body_as() {
+    let notification: Arc<dyn INotification> = Arc::new(Notification::new("Login", Some(Arc::new(Login { user: "admin".into() })), None));
+
+    assert_eq!(notification.body_as::<Login>().unwrap().user, "admin", "Expecting body_as::<Login>().user == 'admin'");
+    assert_eq!(notification.body_ref::<Login>().unwrap().user, "admin", "Expecting body_ref::<Login>().user == 'admin'");
+}
+
+/// Tests that a missing or mismatched body is reported as an error.
+///
+/// Asserts that `body_as` returns `Error::MissingBody` for a notification without a body, and
+/// `Error::BodyTypeMismatch` for a body of another type.
+#[test]
+fn test_body_as_errors() {
+    let empty: Arc<dyn INotification> = Arc::new(Notification::new("Empty", None, None));
+    assert_eq!(empty.body_as::<Login>().unwrap_err(), Error::MissingBody { notification: "Empty".into() });
+
+    let number: Arc<dyn INotification> = Arc::new(Notification::new("Number", Some(Arc::new(5)), None));
+    let error = number.body_ref::<Login>().unwrap_err();
+    assert!(matches!(&error, Error::BodyTypeMismatch { notification, .. } if notification == "Number"), "Expecting BodyTypeMismatch");
+    assert!(error.to_string().contains("Login"), "Expecting error message to name the expected type");
+}
+
+/// Tests converting an INotification into a TypedNotification.
+///
+/// Converts a `Notification` with a `Login` body and asserts that the name, typed body and type
+/// are preserved, and that converting to another body type fails.
+#[test]
+fn test_typed_notification_try_from() {
+    let notification: Arc<dyn INotification> = Arc::new(Notification::new("Login", Some(Arc::new(Login { user: "admin".into() })), Some("Form")));
+
+    let typed = TypedNotification::<Login>::try_from(notification.as_ref()).unwrap();
+    assert_eq!(typed.name(), "Login", "Expecting typed.name() == 'Login'");
+    assert_eq!(typed.get_type(), Some("Form"), "Expecting typed.get_type() == 'Form'");
+    assert_eq!(**typed.typed_body(), Login { user: "admin".into() });
+
+    assert!(TypedNotification::<String>::try_from(notification.as_ref()).is_err(), "Expecting conversion to String to fail");
+}
+
+/// Tests that a TypedNotification exposes its body as an INotification.
+///
+/// Creates a `TypedNotification`, replaces its body through `set_body` with a value of the
+/// right and of the wrong type, and asserts that only the former was accepted.
+#[test]
+fn test_typed_notification_body() {
+    let mut typed = TypedNotification::new("Count", 1_i32, None);
+    typed.set_body(Some(Arc::new(2_i32)));
+    typed.set_body(Some(Arc::new("three")));
+
+    let notification: Arc<dyn INotification> = Arc::new(typed);
+    assert_eq!(*notification.body_as::<i32>().unwrap(), 2, "Expecting body == 2");
+}
+
+/// Tests sending a typed notification.
+///
+/// Sends a typed `Login` body through an `INotifier` and through the `IFacade`, and asserts that
+/// an observer received both bodies with `body_as`.
+#[test]
+fn test_send_typed_notification() {
+    let key = "TypedNotificationTestSend";
+    let facade = Facade::get_instance(key, Facade::new);
+    let users = Arc::new(Mutex::new(Vec::new()));
+
+    let notify = {
+        let users = Arc::clone(&users);
+        Arc::new(move |notification: &Arc<dyn INotification>| {
+            users.lock().unwrap().push(notification.body_as::<Login>().unwrap().user.clone());
+        })
+    };
+    View::get_instance(key, View::new).register_observer("Login", Arc::new(Observer::new(Some(notify), Some(Arc::new(0)))));
+
+    let mut command = SimpleCommand::new();
+    command.initialize_notifier(key);
+    command.send_typed_notification("Login", Login { user: "admin".into() }, None);
+    facade.send_typed_notification("Login", Login { user: "guest".into() }, None);
+
+    assert_eq!(*users.lock().unwrap(), vec!["admin", "guest"]);
+
+    Facade::remove_core(key);
+}
@@ -0,0 +1,209 @@
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use puremvc::Error;
+use puremvc::interfaces::{IProxy, IStore};
+use puremvc::patterns::{Facade, FileStore, PersistentProxy, Proxy, StoreFormat};
+
+/// Proxy data used by FileStoreTest.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+struct Settings {
+    theme: String,
+    font_size: u32,
+}
+
+/// An IStore whose saves always fail.
+struct FailingStore;
+
+impl IStore for FailingStore {
+    /// Returns no data.
+    fn load(&self, _key: &str) -> Result<Option<Value>, Error> {
+        Ok(None)
+    }
+
+    /// Fails with `Error::Persistence`.
+    fn save(&self, key: &str, _data: &Value) -> Result<(), Error> {
+        Err(Error::Persistence { key: key.into(), message: "disk full".into() })
+    }
+
+    /// Fails with `Error::Persistence`.
+    fn remove(&self, key: &str) -> Result<(), Error> {
+        Err(Error::Persistence { key: key.into(), message: "disk full".into() })
+    }
+}
+
+/// Create an empty directory for a test.
+///
+/// # Arguments
+/// * `name` - The name of the test.
+fn directory(name: &str) -> PathBuf {
+    let directory = std::env::temp_dir().join(format!("puremvc-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&directory);
+    directory
+}
+
+/// Tests saving, loading and removing data in both formats.
+///
+/// Saves data under a key whose characters need escaping, asserts the file name and that no
+/// temporary file was left behind, loads the data back, then removes it.
+#[test]
+fn test_save_load_and_remove() {
+    for format in [StoreFormat::Json, StoreFormat::Binary] {
+        let directory = directory(&format!("store-{}", format.extension()));
+        let store = FileStore::new(&directory, format);
+        assert_eq!(store.format(), format, "Expecting the store format");
+        assert!(store.load("app/settings").unwrap().is_none(), "Expecting nothing saved");
+
+        let data = json!({ "theme": "dark", "font_size": 12 });
+        store.save("app/settings", &data).unwrap();
+        assert_eq!(store.path("app/settings"), directory.join(format!("app%2Fsettings.{}", format.extension())), "Expecting the file name escaped");
+        assert_eq!(fs::read_dir(store.directory()).unwrap().count(), 1, "Expecting a single file");
+        assert_eq!(store.load("app/settings").unwrap(), Some(data), "Expecting the data loaded");
+
+        store.remove("app/settings").unwrap();
+        store.remove("app/settings").unwrap();
+        assert!(store.load("app/settings").unwrap().is_none(), "Expecting the data removed");
+        let _ = fs::remove_dir_all(&directory);
+    }
+}
+
+/// Tests that keys differing only by escaped characters are kept in distinct files.
+#[test]
+fn test_distinct_keys() {
+    let directory = directory("distinct");
+    let store = FileStore::new(&directory, StoreFormat::Json);
+    store.save("a.b", &json!(1)).unwrap();
+    store.save("a_b", &json!(2)).unwrap();
+    store.save("a%2Eb", &json!(3)).unwrap();
+    assert_eq!(store.load("a.b").unwrap(), Some(json!(1)), "Expecting the data of 'a.b'");
+    assert_eq!(store.load("a_b").unwrap(), Some(json!(2)), "Expecting the data of 'a_b'");
+    assert_eq!(store.load("a%2Eb").unwrap(), Some(json!(3)), "Expecting the data of 'a%2Eb'");
+    let _ = fs::remove_dir_all(&directory);
+}
+
+/// Tests the error reported for a file that cannot be decoded.
+#[test]
+fn test_corrupt_file() {
+    let directory = directory("corrupt");
+    let store = FileStore::new(&directory, StoreFormat::Json);
+    fs::create_dir_all(&directory).unwrap();
+    fs::write(store.path("settings"), b"not json").unwrap();
+    assert!(matches!(store.load("settings"), Err(Error::Persistence { key, .. }) if key == "settings"), "Expecting Error::Persistence");
+    let _ = fs::remove_dir_all(&directory);
+}
+
+/// Tests registering a persistent proxy whose saved data cannot be restored.
+///
+/// Asserts that `try_register_proxy` reports the error and leaves the proxy unregistered, while
+/// `register_proxy` registers it with its own data.
+#[test]
+fn test_register_corrupt_proxy() {
+    let directory = directory("register-corrupt");
+    let store = Arc::new(FileStore::new(&directory, StoreFormat::Json));
+    fs::create_dir_all(&directory).unwrap();
+    fs::write(store.path("Settings"), b"not json").unwrap();
+
+    let facade = Facade::get_instance("FileStoreTestKey3", Facade::new);
+    facade.set_store(Some(store));
+    let proxy = Arc::new(RwLock::new(PersistentProxy::new("Settings", Some(Settings::default()))));
+    assert!(matches!(facade.try_register_proxy(proxy.clone()), Err(Error::Persistence { .. })), "Expecting Error::Persistence");
+    assert!(!facade.has_proxy("Settings"), "Expecting the proxy not registered");
+
+    facade.register_proxy(proxy.clone());
+    assert!(facade.has_proxy("Settings"), "Expecting the proxy registered");
+    assert_eq!(*proxy.read().unwrap().value().unwrap(), Settings::default(), "Expecting the proxy's own data");
+
+    Facade::remove_core("FileStoreTestKey3");
+    let _ = fs::remove_dir_all(&directory);
+}
+
+/// Tests registering a batch of proxies, one of which cannot be restored.
+///
+/// Asserts that `register_proxies` reports the error and removes the proxies it registered
+/// before the failing one.
+#[test]
+fn test_register_proxies_rolled_back() {
+    let directory = directory("rollback");
+    let store = Arc::new(FileStore::new(&directory, StoreFormat::Json));
+    fs::create_dir_all(&directory).unwrap();
+    fs::write(store.path("Corrupt"), b"not json").unwrap();
+
+    let facade = Facade::get_instance("FileStoreTestKey5", Facade::new);
+    facade.set_store(Some(store));
+    let proxies: Vec<Arc<RwLock<dyn IProxy>>> = vec![
+        Arc::new(RwLock::new(Proxy::new(Some("Plain"), None))),
+        Arc::new(RwLock::new(PersistentProxy::new("Corrupt", Some(Settings::default())))),
+    ];
+    assert!(matches!(facade.register_proxies(proxies), Err(Error::Persistence { .. })), "Expecting Error::Persistence");
+    assert!(!facade.has_proxy("Plain"), "Expecting the proxy registered first removed");
+    assert!(!facade.has_proxy("Corrupt"), "Expecting the failing proxy not registered");
+
+    Facade::remove_core("FileStoreTestKey5");
+    let _ = fs::remove_dir_all(&directory);
+}
+
+/// Tests removing a persistent proxy whose data cannot be saved.
+///
+/// Asserts that `try_remove_proxy` reports the error and leaves the proxy registered, so its
+/// data is not lost.
+#[test]
+fn test_remove_unsaved_proxy() {
+    let facade = Facade::get_instance("FileStoreTestKey4", Facade::new);
+    facade.set_store(Some(Arc::new(FailingStore)));
+    facade.register_proxy(Arc::new(RwLock::new(PersistentProxy::new("Settings", Some(Settings::default())))));
+
+    assert_eq!(facade.try_remove_proxy("Settings").err(),
+        Some(Error::Persistence { key: "Settings".into(), message: "disk full".into() }), "Expecting Error::Persistence");
+    assert!(facade.has_proxy("Settings"), "Expecting the proxy still registered");
+
+    facade.set_store(None);
+    assert!(facade.try_remove_proxy("Settings").unwrap().is_some(), "Expecting the proxy removed without a store");
+    Facade::remove_core("FileStoreTestKey4");
+}
+
+/// Tests that a persistent proxy survives a restart.
+///
+/// Registers a `PersistentProxy` with a core that has a store, updates its data and removes the
+/// core, which saves it. Then registers a fresh `PersistentProxy` with another core using the
+/// same store, and asserts that its data was restored on registration.
+#[test]
+fn test_persistent_proxy_survives_restart() {
+    let directory = directory("restart");
+    let store = Arc::new(FileStore::new(&directory, StoreFormat::Binary));
+
+    // Run the application once, changing the settings
+    let facade = Facade::get_instance("FileStoreTestKey1", Facade::new);
+    facade.set_store(Some(store.clone()));
+    let proxy = Arc::new(RwLock::new(PersistentProxy::new("Settings", Some(Settings::default()))));
+    facade.register_proxy(proxy.clone());
+    facade.register_proxy(Arc::new(RwLock::new(Proxy::new(Some("Transient"), Some(Arc::new(1))))));
+    proxy.write().unwrap().set_value(Some(Settings { theme: "dark".into(), font_size: 14 }));
+    Facade::remove_core("FileStoreTestKey1");
+    assert_eq!(fs::read_dir(&directory).unwrap().count(), 1, "Expecting only the persistent proxy saved");
+
+    // Run it again, and assert that the settings were restored
+    let facade = Facade::get_instance("FileStoreTestKey2", Facade::new);
+    facade.set_store(Some(store.clone()));
+    let proxy = Arc::new(RwLock::new(PersistentProxy::<Settings>::new("Settings", Some(Settings::default()))));
+    facade.register_proxy(proxy.clone());
+    assert_eq!(*proxy.read().unwrap().value().unwrap(), Settings { theme: "dark".into(), font_size: 14 }, "Expecting the settings restored");
+
+    // Save explicitly, change the data, then restore it
+    proxy.write().unwrap().set_value(Some(Settings { theme: "light".into(), font_size: 10 }));
+    facade.save_proxies().unwrap();
+    proxy.write().unwrap().set_value(None);
+    assert!(facade.restore_proxy("Settings").unwrap(), "Expecting restore_proxy() == true");
+    assert_eq!(proxy.read().unwrap().value().unwrap().theme, "light", "Expecting the saved settings restored");
+    assert!(matches!(facade.restore_proxy("Missing"), Err(Error::NotFound { .. })), "Expecting Error::NotFound");
+
+    // A proxy without data deletes its saved data
+    proxy.write().unwrap().set_value(None);
+    facade.save_proxies().unwrap();
+    assert!(store.load("Settings").unwrap().is_none(), "Expecting the saved settings deleted");
+    assert!(proxy.read().unwrap().data().is_none(), "Expecting no data");
+
+    Facade::remove_core("FileStoreTestKey2");
+    let _ = fs::remove_dir_all(&directory);
+}
@@ -0,0 +1,2 @@
+#[cfg(feature = "persistence")]
+mod file_store_test;
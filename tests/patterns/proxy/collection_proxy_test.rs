@@ -0,0 +1,136 @@
+use std::sync::{Arc, Mutex, RwLock};
+use puremvc::Error;
+use puremvc::core::View;
+use puremvc::interfaces::{INotification, IProxy};
+use puremvc::patterns::{CollectionItem, CollectionProxy, CollectionSummary, Facade, Observer};
+
+/// A notification received by the test observer, as its name and a description of its body.
+#[derive(Debug, PartialEq)]
+enum Received {
+    /// An item notification, with the index and the item.
+    Item(String, usize, String),
+    /// A summary notification.
+    Summary(CollectionSummary),
+}
+
+/// Register an observer collecting the collection notifications it receives.
+///
+/// # Arguments
+/// * `key` - The key of the core to observe.
+///
+/// # Returns
+/// The notifications received, in delivery order.
+fn observe(key: &str) -> Arc<Mutex<Vec<Received>>> {
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let notify = {
+        let received = received.clone();
+        Arc::new(move |notification: &Arc<dyn INotification>| {
+            assert_eq!(notification.get_type(), Some("Todos"), "Expecting the type to be the proxy name");
+            let entry = match notification.body_ref::<CollectionItem<String>>() {
+                Ok(item) => Received::Item(notification.name().into(), item.key, item.item.clone()),
+                Err(_) => Received::Summary(*notification.body_ref::<CollectionSummary>().unwrap()),
+            };
+            received.lock().unwrap().push(entry);
+        })
+    };
+    let view = View::get_instance(key, View::new);
+    let observer = Arc::new(Observer::new(Some(notify), Some(received.clone())));
+    for name in [CollectionProxy::<String>::ITEM_ADDED, CollectionProxy::<String>::ITEM_UPDATED,
+                 CollectionProxy::<String>::ITEM_REMOVED, CollectionProxy::<String>::CHANGED] {
+        view.register_observer(name, observer.clone());
+    }
+    received
+}
+
+/// Tests the item operations and their notifications.
+///
+/// Adds, updates and removes items of a registered `CollectionProxy`, and asserts that an item
+/// notification was sent for each with the index and the item.
+#[test]
+fn test_item_notifications() {
+    let facade = Facade::get_instance("CollectionProxyTestKey1", Facade::new);
+    let received = observe("CollectionProxyTestKey1");
+
+    // Add an item before registration, which sends nothing
+    let mut proxy = CollectionProxy::new(Some("Todos"), vec!["write".to_string()]);
+    proxy.add("test".into());
+    let proxy = Arc::new(RwLock::new(proxy));
+    facade.register_proxy(proxy.clone());
+
+    // Add, update and remove items
+    let mut todos = proxy.write().unwrap();
+    assert_eq!(todos.add("ship".into()), 2, "Expecting the index of the added item");
+    assert_eq!(todos.update(1, "review".into()), Some("test".into()), "Expecting the previous item");
+    assert_eq!(todos.update(5, "nothing".into()), None, "Expecting no update out of bounds");
+    assert_eq!(todos.remove(0), Some("write".into()), "Expecting the removed item");
+    assert_eq!(todos.remove(5), None, "Expecting no removal out of bounds");
+
+    // Query the items
+    assert_eq!(todos.items(), ["review", "ship"], "Expecting the remaining items");
+    assert_eq!(todos.get(1).map(String::as_str), Some("ship"), "Expecting todos.get(1) == 'ship'");
+    assert_eq!(todos.len(), 2, "Expecting todos.len() == 2");
+    assert!(!todos.is_empty(), "Expecting todos.is_empty() == false");
+    assert_eq!(todos.position(|item| item.starts_with('s')), Some(1), "Expecting the matching index");
+    assert_eq!(todos.filter(|item| item.contains('e')), vec!["review"], "Expecting the matching items");
+    drop(todos);
+
+    assert_eq!(*received.lock().unwrap(), vec![
+        Received::Item(CollectionProxy::<String>::ITEM_ADDED.into(), 2, "ship".into()),
+        Received::Item(CollectionProxy::<String>::ITEM_UPDATED.into(), 1, "review".into()),
+        Received::Item(CollectionProxy::<String>::ITEM_REMOVED.into(), 0, "write".into()),
+    ], "Expecting a notification for each change");
+}
+
+/// Tests that batch operations send a single summary notification.
+///
+/// Runs `add_all`, `remove_where`, `batch` and `clear` on a registered `CollectionProxy`, and
+/// asserts that each sent a single summary, and that a batch changing nothing sent none.
+#[test]
+fn test_batch_notifications() {
+    let facade = Facade::get_instance("CollectionProxyTestKey2", Facade::new);
+    let received = observe("CollectionProxyTestKey2");
+    let proxy = Arc::new(RwLock::new(CollectionProxy::<String>::new(Some("Todos"), vec![])));
+    facade.register_proxy(proxy.clone());
+
+    let mut todos = proxy.write().unwrap();
+    todos.add_all(["a", "b", "c", "d"].map(String::from));
+    assert_eq!(todos.remove_where(|item| item == "b" || item == "d"), vec!["b", "d"], "Expecting the removed items");
+    todos.batch(|todos| {
+        todos.update(0, "A".into());
+        todos.add("e".into());
+        assert!(todos.in_batch(), "Expecting in_batch() == true");
+    });
+    todos.batch(|todos| { todos.remove(10); });
+    todos.clear();
+    assert!(todos.is_empty(), "Expecting todos.is_empty() == true");
+    drop(todos);
+
+    assert_eq!(*received.lock().unwrap(), vec![
+        Received::Summary(CollectionSummary { added: 4, updated: 0, removed: 0, len: 4 }),
+        Received::Summary(CollectionSummary { added: 0, updated: 0, removed: 2, len: 2 }),
+        Received::Summary(CollectionSummary { added: 1, updated: 1, removed: 0, len: 3 }),
+        Received::Summary(CollectionSummary { added: 0, updated: 0, removed: 3, len: 0 }),
+    ], "Expecting a summary for each batch operation");
+}
+
+/// Tests the data object of a `CollectionProxy`.
+///
+/// Asserts that the data object is a snapshot of the items, refreshed after a change, and that
+/// setting a `Vec<T>` as the data object replaces the items.
+#[test]
+fn test_data() {
+    let mut proxy = CollectionProxy::new(Some("Todos"), vec![1, 2]);
+    let snapshot = |proxy: &CollectionProxy<i32>| proxy.data().unwrap().downcast_ref::<Vec<i32>>().unwrap().clone();
+    assert_eq!(snapshot(&proxy), vec![1, 2], "Expecting a snapshot of the items");
+
+    proxy.add(3);
+    assert_eq!(snapshot(&proxy), vec![1, 2, 3], "Expecting the snapshot refreshed");
+
+    proxy.set_data(Some(Arc::new(vec![7, 8])));
+    assert_eq!(proxy.items(), [7, 8], "Expecting the items replaced");
+    proxy.set_data(Some(Arc::new("not a list")));
+    assert_eq!(proxy.items(), [7, 8], "Expecting data of another type ignored");
+    assert!(matches!(proxy.try_set_data(Some(Arc::new("not a list"))), Err(Error::BodyTypeMismatch { .. })), "Expecting Error::BodyTypeMismatch");
+    proxy.set_data(None);
+    assert!(proxy.is_empty(), "Expecting the items cleared");
+}
@@ -0,0 +1,266 @@
+use std::any::Any;
+use std::sync::{Arc, Mutex, RwLock};
+use puremvc::core::View;
+use puremvc::interfaces::{ICommand, IFacade, INotification, INotifier, IProxy, IUndoableCommand};
+use puremvc::patterns::{CommandHistoryProxy, CommandHistoryState, Facade, Observer, UndoableCommand};
+
+/// An UndoableCommand subclass that increments a counter.
+struct IncrementCommand {
+    command: UndoableCommand,
+    counter: Option<Arc<Mutex<i32>>>,
+}
+
+impl IncrementCommand {
+    /// Constructor.
+    fn new() -> Self {
+        Self { command: UndoableCommand::new(), counter: None }
+    }
+}
+
+impl INotifier for IncrementCommand {
+    /// Returns the key associated with this notifier.
+    fn key(&self) -> &str {
+        self.command.key()
+    }
+
+    /// Returns the facade instance for this notifier.
+    fn facade(&self) -> Arc<dyn IFacade> {
+        self.command.facade()
+    }
+
+    /// Initializes the notifier with the specified key.
+    ///
+    /// # Arguments
+    /// * `key` - The key to associate with this notifier
+    fn initialize_notifier(&mut self, key: &str) {
+        self.command.initialize_notifier(key);
+    }
+
+    /// Sends a notification with the specified name, body, and type.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the notification
+    /// * `body` - Optional data payload for the notification
+    /// * `type_` - Optional type identifier for the notification
+    fn send_notification(&self, name: &str, body: Option<Arc<dyn Any + Send + Sync>>, type_: Option<&str>) {
+        self.command.send_notification(name, body, type_);
+    }
+}
+
+impl ICommand for IncrementCommand {
+    /// Increments the counter carried by the notification.
+    ///
+    /// # Arguments
+    /// * `notification` - The notification carrying an `Arc<Mutex<i32>>`
+    fn execute(&mut self, notification: &Arc<dyn INotification>) {
+        self.command.execute(notification);
+        let counter = notification.body()
+            .and_then(|body| body.downcast_ref::<Arc<Mutex<i32>>>())
+            .cloned()
+            .unwrap();
+        *counter.lock().unwrap() += 1;
+        self.counter = Some(counter);
+    }
+}
+
+impl IUndoableCommand for IncrementCommand {
+    /// Decrements the counter.
+    fn undo(&mut self) {
+        *self.counter.as_ref().unwrap().lock().unwrap() -= 1;
+    }
+
+    /// Increments the counter again.
+    fn redo(&mut self) {
+        *self.counter.as_ref().unwrap().lock().unwrap() += 1;
+    }
+}
+
+/// An UndoableCommand subclass that logs the history state it reads when undone and redone.
+struct InspectCommand {
+    command: UndoableCommand,
+    log: Option<Arc<Mutex<Vec<CommandHistoryState>>>>,
+}
+
+impl InspectCommand {
+    /// Constructor.
+    fn new() -> Self {
+        Self { command: UndoableCommand::new(), log: None }
+    }
+
+    /// Logs the state of the history registered with the core.
+    fn inspect(&self) {
+        let proxy = self.facade().retrieve_proxy(CommandHistoryProxy::NAME).unwrap();
+        let state = proxy.write().unwrap().as_any().downcast_ref::<CommandHistoryProxy>().unwrap().state();
+        self.log.as_ref().unwrap().lock().unwrap().push(state);
+    }
+}
+
+impl INotifier for InspectCommand {
+    /// Returns the key associated with this notifier.
+    fn key(&self) -> &str {
+        self.command.key()
+    }
+
+    /// Returns the facade instance for this notifier.
+    fn facade(&self) -> Arc<dyn IFacade> {
+        self.command.facade()
+    }
+
+    /// Initializes the notifier with the specified key.
+    ///
+    /// # Arguments
+    /// * `key` - The key to associate with this notifier
+    fn initialize_notifier(&mut self, key: &str) {
+        self.command.initialize_notifier(key);
+    }
+
+    /// Sends a notification with the specified name, body, and type.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the notification
+    /// * `body` - Optional data payload for the notification
+    /// * `type_` - Optional type identifier for the notification
+    fn send_notification(&self, name: &str, body: Option<Arc<dyn Any + Send + Sync>>, type_: Option<&str>) {
+        self.command.send_notification(name, body, type_);
+    }
+}
+
+impl ICommand for InspectCommand {
+    /// Keeps the log carried by the notification.
+    ///
+    /// # Arguments
+    /// * `notification` - The notification carrying an `Arc<Mutex<Vec<CommandHistoryState>>>`
+    fn execute(&mut self, notification: &Arc<dyn INotification>) {
+        self.command.execute(notification);
+        self.log = notification.body()
+            .and_then(|body| body.downcast_ref::<Arc<Mutex<Vec<CommandHistoryState>>>>())
+            .cloned();
+    }
+}
+
+impl IUndoableCommand for InspectCommand {
+    /// Logs the history state.
+    fn undo(&mut self) {
+        self.inspect();
+    }
+
+    /// Logs the history state.
+    fn redo(&mut self) {
+        self.inspect();
+    }
+}
+
+/// Run a closure against the history registered with a core.
+///
+/// # Arguments
+/// * `facade` - The facade of the core.
+/// * `f` - The closure to run.
+fn with_history<R>(facade: &Arc<dyn IFacade>, f: impl FnOnce(&mut CommandHistoryProxy) -> R) -> R {
+    let proxy = facade.retrieve_proxy(CommandHistoryProxy::NAME).unwrap();
+    let mut proxy = proxy.write().unwrap();
+    f(proxy.as_any().downcast_mut::<CommandHistoryProxy>().unwrap())
+}
+
+/// Tests undoing and redoing recorded commands.
+///
+/// Registers a `CommandHistoryProxy` with a capacity of two and a recording command, executes
+/// the command three times, then steps back and forth through the history, asserting the
+/// counter, the stacks and the change notifications at each step.
+#[test]
+fn test_undo_and_redo() {
+    // Get a Multiton Facade instance with a history and a recording command
+    let facade = Facade::get_instance("CommandHistoryProxyTestKey1", Facade::new);
+    facade.register_proxy(Arc::new(RwLock::new(CommandHistoryProxy::new(2))));
+    facade.register_command("Increment", CommandHistoryProxy::recording(Arc::new(|| Box::new(IncrementCommand::new()))));
+
+    // Observe the change notifications
+    let changes = Arc::new(Mutex::new(Vec::new()));
+    let notify = {
+        let changes = changes.clone();
+        Arc::new(move |notification: &Arc<dyn INotification>| {
+            let state = *notification.body().unwrap().downcast_ref::<CommandHistoryState>().unwrap();
+            changes.lock().unwrap().push((notification.get_type().unwrap().to_string(), state.undo_count, state.redo_count));
+        })
+    };
+    View::get_instance("CommandHistoryProxyTestKey1", View::new)
+        .register_observer(CommandHistoryProxy::CHANGED, Arc::new(Observer::new(Some(notify), Some(changes.clone()))));
+
+    // Execute the command three times
+    let counter = Arc::new(Mutex::new(0));
+    for _ in 0..3 {
+        facade.send_notification("Increment", Some(Arc::new(counter.clone())), None);
+    }
+    assert_eq!(*counter.lock().unwrap(), 3, "Expecting counter == 3");
+    assert_eq!(with_history(&facade, |history| history.state()), CommandHistoryState { undo_count: 2, redo_count: 0 },
+        "Expecting the oldest command dropped");
+
+    // Undo both commands kept, then fail to undo further
+    assert!(with_history(&facade, |history| history.undo()), "Expecting undo() == true");
+    assert!(with_history(&facade, |history| history.undo()), "Expecting undo() == true");
+    assert!(!with_history(&facade, |history| history.undo()), "Expecting undo() == false");
+    assert_eq!(*counter.lock().unwrap(), 1, "Expecting counter == 1");
+    assert!(with_history(&facade, |history| history.can_redo() && !history.can_undo()), "Expecting only redo possible");
+
+    // Redo one command, then execute a new one, clearing the redo stack
+    assert!(with_history(&facade, |history| history.redo()), "Expecting redo() == true");
+    assert_eq!(*counter.lock().unwrap(), 2, "Expecting counter == 2");
+    facade.send_notification("Increment", Some(Arc::new(counter.clone())), None);
+    assert_eq!(*counter.lock().unwrap(), 3, "Expecting counter == 3");
+    assert!(!with_history(&facade, |history| history.redo()), "Expecting redo() == false");
+
+    // Clear the history
+    with_history(&facade, |history| history.clear());
+
+    // Assert the change notifications
+    let expected: Vec<(String, usize, usize)> = vec![
+        (CommandHistoryProxy::PUSHED, 1, 0),
+        (CommandHistoryProxy::PUSHED, 2, 0),
+        (CommandHistoryProxy::PUSHED, 2, 0),
+        (CommandHistoryProxy::UNDONE, 1, 1),
+        (CommandHistoryProxy::UNDONE, 0, 2),
+        (CommandHistoryProxy::REDONE, 1, 1),
+        (CommandHistoryProxy::PUSHED, 2, 0),
+        (CommandHistoryProxy::CLEARED, 0, 0),
+    ].into_iter().map(|(type_, undo, redo)| (type_.to_string(), undo, redo)).collect();
+    assert_eq!(*changes.lock().unwrap(), expected, "Expecting a notification for each change");
+}
+
+/// Tests undoing and redoing commands that retrieve the history.
+///
+/// Records a command reading the history state when undone and redone, then undoes and redoes
+/// it with `undo_registered` and `redo_registered`, and asserts that it could read the state.
+#[test]
+fn test_undo_and_redo_registered() {
+    // Get a Multiton Facade instance with a history and a recording command
+    let facade = Facade::get_instance("CommandHistoryProxyTestKey2", Facade::new);
+    assert!(!CommandHistoryProxy::undo_registered(&facade), "Expecting undo_registered() == false without a history");
+    facade.register_proxy(Arc::new(RwLock::new(CommandHistoryProxy::new(2))));
+    facade.register_command("Inspect", CommandHistoryProxy::recording(Arc::new(|| Box::new(InspectCommand::new()))));
+
+    // Execute the command, then undo and redo it
+    let log: Arc<Mutex<Vec<CommandHistoryState>>> = Arc::new(Mutex::new(Vec::new()));
+    facade.send_notification("Inspect", Some(Arc::new(log.clone())), None);
+    assert!(CommandHistoryProxy::undo_registered(&facade), "Expecting undo_registered() == true");
+    assert!(!CommandHistoryProxy::undo_registered(&facade), "Expecting undo_registered() == false");
+    assert!(CommandHistoryProxy::redo_registered(&facade), "Expecting redo_registered() == true");
+    assert!(!CommandHistoryProxy::redo_registered(&facade), "Expecting redo_registered() == false");
+
+    // Assert that the command read the state while taken off the stacks
+    assert_eq!(*log.lock().unwrap(), vec![
+        CommandHistoryState { undo_count: 0, redo_count: 0 },
+        CommandHistoryState { undo_count: 0, redo_count: 0 },
+    ], "Expecting the history read while undoing and redoing");
+    assert_eq!(with_history(&facade, |history| history.state()), CommandHistoryState { undo_count: 1, redo_count: 0 },
+        "Expecting the command back on the undo stack");
+    Facade::remove_core("CommandHistoryProxyTestKey2");
+}
+
+/// Tests that a CommandHistoryProxy with no capacity keeps nothing.
+#[test]
+fn test_zero_capacity() {
+    let mut history = CommandHistoryProxy::new(0);
+    assert_eq!(history.capacity(), 0, "Expecting capacity() == 0");
+    history.push(Box::new(UndoableCommand::new()));
+    assert!(!history.can_undo(), "Expecting can_undo() == false");
+    assert_eq!(history.name(), CommandHistoryProxy::NAME, "Expecting name() == CommandHistoryProxy::NAME");
+}
@@ -0,0 +1,139 @@
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex, RwLock};
+use puremvc::Error;
+use puremvc::core::View;
+use puremvc::interfaces::{INotification, IProxy};
+use puremvc::patterns::{CollectionItem, CollectionSummary, Facade, KeyedCollectionProxy, Observer};
+
+/// The `KeyedCollectionProxy` type used by KeyedCollectionProxyTest.
+type Users = KeyedCollectionProxy<u32, String>;
+
+/// A notification received by the test observer, as its name and a description of its body.
+#[derive(Debug, PartialEq)]
+enum Received {
+    /// An item notification, with the key and the item.
+    Item(String, u32, String),
+    /// A summary notification.
+    Summary(CollectionSummary),
+}
+
+/// Register an observer collecting the collection notifications it receives.
+///
+/// # Arguments
+/// * `key` - The key of the core to observe.
+///
+/// # Returns
+/// The notifications received, in delivery order.
+fn observe(key: &str) -> Arc<Mutex<Vec<Received>>> {
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let notify = {
+        let received = received.clone();
+        Arc::new(move |notification: &Arc<dyn INotification>| {
+            assert_eq!(notification.get_type(), Some("Users"), "Expecting the type to be the proxy name");
+            let entry = match notification.body_ref::<CollectionItem<String, u32>>() {
+                Ok(item) => Received::Item(notification.name().into(), item.key, item.item.clone()),
+                Err(_) => Received::Summary(*notification.body_ref::<CollectionSummary>().unwrap()),
+            };
+            received.lock().unwrap().push(entry);
+        })
+    };
+    let view = View::get_instance(key, View::new);
+    let observer = Arc::new(Observer::new(Some(notify), Some(received.clone())));
+    for name in [Users::ITEM_ADDED, Users::ITEM_UPDATED, Users::ITEM_REMOVED, Users::CHANGED] {
+        view.register_observer(name, observer.clone());
+    }
+    received
+}
+
+/// Tests the item operations and their notifications.
+///
+/// Inserts, replaces and removes items of a registered `KeyedCollectionProxy`, and asserts that
+/// an item notification was sent for each with the key and the item.
+#[test]
+fn test_item_notifications() {
+    let facade = Facade::get_instance("KeyedCollectionProxyTestKey1", Facade::new);
+    let received = observe("KeyedCollectionProxyTestKey1");
+
+    // Insert an item before registration, which sends nothing
+    let mut proxy = Users::new(Some("Users"), BTreeMap::from([(1, "ada".to_string())]));
+    proxy.insert(2, "bob".into());
+    let proxy = Arc::new(RwLock::new(proxy));
+    facade.register_proxy(proxy.clone());
+
+    // Insert, replace and remove items
+    let mut users = proxy.write().unwrap();
+    assert_eq!(users.insert(3, "cy".into()), None, "Expecting no previous item");
+    assert_eq!(users.insert(2, "bea".into()), Some("bob".into()), "Expecting the previous item");
+    assert_eq!(users.remove(&1), Some("ada".into()), "Expecting the removed item");
+    assert_eq!(users.remove(&5), None, "Expecting no removal of a missing key");
+
+    // Query the items
+    assert_eq!(users.items().values().collect::<Vec<_>>(), ["bea", "cy"], "Expecting the remaining items");
+    assert_eq!(users.get(&3).map(String::as_str), Some("cy"), "Expecting users.get(&3) == 'cy'");
+    assert!(users.contains_key(&2), "Expecting users.contains_key(&2) == true");
+    assert_eq!(users.len(), 2, "Expecting users.len() == 2");
+    assert!(!users.is_empty(), "Expecting users.is_empty() == false");
+    assert_eq!(users.filter(|_, item| item.starts_with('c')), vec![(&3, &"cy".to_string())], "Expecting the matching items");
+    drop(users);
+
+    assert_eq!(*received.lock().unwrap(), vec![
+        Received::Item(Users::ITEM_ADDED.into(), 3, "cy".into()),
+        Received::Item(Users::ITEM_UPDATED.into(), 2, "bea".into()),
+        Received::Item(Users::ITEM_REMOVED.into(), 1, "ada".into()),
+    ], "Expecting a notification for each change");
+}
+
+/// Tests that batch operations send a single summary notification.
+///
+/// Runs `insert_all`, `remove_where`, `batch` and `clear` on a registered `KeyedCollectionProxy`,
+/// and asserts that each sent a single summary, and that a batch changing nothing sent none.
+#[test]
+fn test_batch_notifications() {
+    let facade = Facade::get_instance("KeyedCollectionProxyTestKey2", Facade::new);
+    let received = observe("KeyedCollectionProxyTestKey2");
+    let proxy = Arc::new(RwLock::new(Users::new(Some("Users"), BTreeMap::new())));
+    facade.register_proxy(proxy.clone());
+
+    let mut users = proxy.write().unwrap();
+    users.insert_all([(1, "a"), (2, "b"), (3, "c"), (4, "d")].map(|(key, item)| (key, item.to_string())));
+    assert_eq!(users.remove_where(|key, _| key % 2 == 0), vec![(2, "b".to_string()), (4, "d".to_string())], "Expecting the removed items");
+    users.batch(|users| {
+        users.insert(1, "A".into());
+        users.insert(5, "e".into());
+        assert!(users.in_batch(), "Expecting in_batch() == true");
+    });
+    users.batch(|users| { users.remove(&10); });
+    users.clear();
+    assert!(users.is_empty(), "Expecting users.is_empty() == true");
+    drop(users);
+
+    assert_eq!(*received.lock().unwrap(), vec![
+        Received::Summary(CollectionSummary { added: 4, updated: 0, removed: 0, len: 4 }),
+        Received::Summary(CollectionSummary { added: 0, updated: 0, removed: 2, len: 2 }),
+        Received::Summary(CollectionSummary { added: 1, updated: 1, removed: 0, len: 3 }),
+        Received::Summary(CollectionSummary { added: 0, updated: 0, removed: 3, len: 0 }),
+    ], "Expecting a summary for each batch operation");
+}
+
+/// Tests the data object of a `KeyedCollectionProxy`.
+///
+/// Asserts that the data object is a snapshot of the items, refreshed after a change, that
+/// setting a `BTreeMap<K, T>` as the data object replaces the items, and that data of another
+/// type is reported and ignored.
+#[test]
+fn test_data() {
+    let mut proxy = KeyedCollectionProxy::new(Some("Users"), BTreeMap::from([("a", 1)]));
+    let snapshot = |proxy: &KeyedCollectionProxy<&'static str, i32>| proxy.data().unwrap().downcast_ref::<BTreeMap<&'static str, i32>>().unwrap().clone();
+    assert_eq!(snapshot(&proxy), BTreeMap::from([("a", 1)]), "Expecting a snapshot of the items");
+
+    proxy.insert("b", 2);
+    assert_eq!(snapshot(&proxy), BTreeMap::from([("a", 1), ("b", 2)]), "Expecting the snapshot refreshed");
+
+    proxy.set_data(Some(Arc::new(BTreeMap::from([("c", 3)]))));
+    assert_eq!(*proxy.items(), BTreeMap::from([("c", 3)]), "Expecting the items replaced");
+    proxy.set_data(Some(Arc::new(vec![4])));
+    assert_eq!(*proxy.items(), BTreeMap::from([("c", 3)]), "Expecting data of another type ignored");
+    assert!(matches!(proxy.try_set_data(Some(Arc::new(vec![4]))), Err(Error::BodyTypeMismatch { .. })), "Expecting Error::BodyTypeMismatch");
+    proxy.set_data(None);
+    assert!(proxy.is_empty(), "Expecting the items cleared");
+}
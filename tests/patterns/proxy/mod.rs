@@ -1 +1,6 @@
-mod proxy_test;
\ No newline at end of file
+mod proxy_test;
+mod command_history_proxy_test;
+mod observable_proxy_test;
+mod typed_proxy_test;
+mod collection_proxy_test;
+mod keyed_collection_proxy_test;
@@ -0,0 +1,95 @@
+use std::sync::{Arc, Mutex, RwLock};
+use puremvc::Error;
+use puremvc::core::View;
+use puremvc::interfaces::{INotification, IProxy};
+use puremvc::patterns::{DataChange, Facade, ObservableProxy, Observer};
+
+/// The proxy names, old values and new values of the data changes received.
+type Changes = Arc<Mutex<Vec<(String, Option<i32>, Option<i32>)>>>;
+
+/// Register an observer collecting the old and new values of the data changes it receives.
+///
+/// # Arguments
+/// * `key` - The key of the core to observe.
+/// * `name` - The notification name to observe.
+///
+/// # Returns
+/// The proxy names, old values and new values received, in delivery order.
+fn observe(key: &str, name: &str) -> Changes {
+    let changes: Changes = Arc::new(Mutex::new(Vec::new()));
+    let notify = {
+        let changes = changes.clone();
+        Arc::new(move |notification: &Arc<dyn INotification>| {
+            let change = notification.body_ref::<DataChange>().unwrap();
+            changes.lock().unwrap().push((
+                notification.get_type().unwrap().to_string(),
+                change.old_as::<i32>().ok().map(|old| *old),
+                change.new_as::<i32>().ok().map(|new| *new),
+            ));
+        })
+    };
+    View::get_instance(key, View::new).register_observer(name, Arc::new(Observer::new(Some(notify), Some(changes.clone()))));
+    changes
+}
+
+/// Tests that setting the data sends a data changed notification.
+///
+/// Registers an `ObservableProxy`, sets its data twice, and asserts that a notification was
+/// sent for each change with the old and new values.
+#[test]
+fn test_set_data_notifies() {
+    let facade = Facade::get_instance("ObservableProxyTestKey1", Facade::new);
+    let changes = observe("ObservableProxyTestKey1", ObservableProxy::DATA_CHANGED);
+
+    // Set the data before registration, which sends nothing
+    let mut proxy = ObservableProxy::new(Some("Counter"), None, None);
+    assert_eq!(proxy.notification_name(), ObservableProxy::DATA_CHANGED, "Expecting the default notification name");
+    proxy.set_data(Some(Arc::new(1)));
+    let proxy = Arc::new(RwLock::new(proxy));
+    facade.register_proxy(proxy.clone());
+
+    // Set the data twice
+    proxy.write().unwrap().set_data(Some(Arc::new(2)));
+    proxy.write().unwrap().set_data(None);
+
+    assert_eq!(*changes.lock().unwrap(), vec![
+        ("Counter".to_string(), Some(1), Some(2)),
+        ("Counter".to_string(), Some(2), None),
+    ], "Expecting a notification for each change");
+}
+
+/// Tests coalescing updates within a batch.
+///
+/// Sets the data of an `ObservableProxy` several times within nested batches, and asserts that
+/// a single notification was sent when the outermost batch ended, under the configured name.
+#[test]
+fn test_batch_coalesces_updates() {
+    let facade = Facade::get_instance("ObservableProxyTestKey2", Facade::new);
+    let changes = observe("ObservableProxyTestKey2", "CounterChanged");
+    let proxy = Arc::new(RwLock::new(ObservableProxy::new(Some("Counter"), Some(Arc::new(0)), Some("CounterChanged"))));
+    facade.register_proxy(proxy.clone());
+
+    // Update the data within nested batches
+    proxy.write().unwrap().batch(|proxy| {
+        proxy.set_data(Some(Arc::new(1)));
+        proxy.batch(|proxy| proxy.set_data(Some(Arc::new(2))));
+        assert!(proxy.in_batch(), "Expecting in_batch() == true");
+        proxy.set_data(Some(Arc::new(3)));
+    });
+    assert_eq!(*changes.lock().unwrap(), vec![("Counter".to_string(), Some(0), Some(3))], "Expecting a single notification");
+
+    // A batch without updates sends nothing
+    proxy.write().unwrap().begin_batch();
+    proxy.write().unwrap().end_batch();
+    assert!(!proxy.read().unwrap().in_batch(), "Expecting in_batch() == false");
+    assert_eq!(changes.lock().unwrap().len(), 1, "Expecting no further notification");
+}
+
+/// Tests the errors reported when reading a data change as the wrong type.
+#[test]
+fn test_data_change_errors() {
+    let change = DataChange { old: None, new: Some(Arc::new(String::from("value"))) };
+    assert!(matches!(change.old_as::<String>(), Err(Error::MissingBody { .. })), "Expecting Error::MissingBody");
+    assert!(matches!(change.new_as::<i32>(), Err(Error::BodyTypeMismatch { .. })), "Expecting Error::BodyTypeMismatch");
+    assert_eq!(*change.new_as::<String>().unwrap(), "value", "Expecting new == value");
+}
@@ -0,0 +1,50 @@
+use std::sync::{Arc, RwLock};
+use puremvc::core::Model;
+use puremvc::interfaces::IProxy;
+use puremvc::patterns::{Facade, ObservableProxy, Proxy};
+
+/// Tests retrieving a proxy as its concrete type.
+///
+/// Registers a `Proxy` and retrieves it as a `Proxy`, holding two read guards at once to assert
+/// that reading does not take the write lock. Then updates it through a write guard.
+#[test]
+fn test_retrieve_proxy_as() {
+    let facade = Facade::get_instance("TypedProxyTestKey1", Facade::new);
+    facade.register_proxy(Arc::new(RwLock::new(Proxy::new(Some("Colors"), Some(Arc::new(vec!["red"]))))));
+
+    // Hold two read guards at once
+    let proxy = facade.retrieve_proxy_as::<Proxy>("Colors").expect("Expecting the proxy retrieved as a Proxy");
+    let first = proxy.read().unwrap();
+    let second = proxy.read().unwrap();
+    assert_eq!(first.name(), "Colors", "Expecting name == Colors");
+    assert_eq!(second.data().unwrap().downcast_ref::<Vec<&str>>().unwrap(), &vec!["red"], "Expecting data == [red]");
+    drop((first, second));
+
+    // Update the proxy through a write guard
+    proxy.write().unwrap().set_data(Some(Arc::new(vec!["green"])));
+    let data = proxy.read().unwrap().data().cloned().unwrap();
+    assert_eq!(data.downcast_ref::<Vec<&str>>().unwrap(), &vec!["green"], "Expecting data == [green]");
+    assert!(Arc::ptr_eq(proxy.proxy(), &facade.retrieve_proxy("Colors").unwrap()), "Expecting the registered proxy");
+}
+
+/// Tests that retrieving a proxy as another type, or an unregistered proxy, returns None.
+#[test]
+fn test_retrieve_proxy_as_wrong_type() {
+    let model = Model::get_instance("TypedProxyTestKey2", Model::new);
+    model.register_proxy(Arc::new(RwLock::new(Proxy::new(Some("Plain"), None))));
+
+    assert!(model.retrieve_proxy_as::<Proxy>("Plain").is_some(), "Expecting the proxy retrieved as a Proxy");
+    assert!(model.retrieve_proxy_as::<ObservableProxy>("Plain").is_none(), "Expecting None for another type");
+    assert!(model.retrieve_proxy_as::<Proxy>("Missing").is_none(), "Expecting None for an unregistered proxy");
+}
+
+/// Tests downcasting a proxy trait object without mutable access.
+#[test]
+fn test_downcast_proxy() {
+    let mut proxy: Box<dyn IProxy> = Box::new(Proxy::new(Some("Boxed"), None));
+    assert!(proxy.as_any_ref().is::<Proxy>(), "Expecting as_any_ref() to be a Proxy");
+    assert_eq!(proxy.downcast_ref::<Proxy>().unwrap().name(), "Boxed", "Expecting name == Boxed");
+    assert!(proxy.downcast_ref::<ObservableProxy>().is_none(), "Expecting None for another type");
+    proxy.downcast_mut::<Proxy>().unwrap().set_data(Some(Arc::new(1)));
+    assert!(proxy.data().is_some(), "Expecting data set");
+}
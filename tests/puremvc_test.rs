@@ -1,7 +1,7 @@
 use std::any::Any;
 use std::sync::{Arc, RwLock, Weak};
 use puremvc::core::{Controller, Model, View};
-use puremvc::interfaces::{ICommand, IController, IFacade, IMediator, IModel, INotification, INotifier, IObserver, IProxy, IView};
+use puremvc::interfaces::{CommandFactory, ICommand, IController, IFacade, IMediator, IModel, INotification, INotifier, IObserver, IProxy, IView};
 use puremvc::patterns::{Mediator, Notification, Proxy, SimpleCommand};
 
 #[derive(Debug, PartialEq, Eq)]
@@ -292,7 +292,7 @@ impl Drop for TestController {
 impl IController for TestController {
     fn initialize_controller(&self) {}
 
-    fn register_command(&self, notification_name: &str, factory: fn() -> Box<dyn ICommand + Send + Sync>) { self.controller.register_command(&notification_name, factory) }
+    fn register_command(&self, notification_name: &str, factory: CommandFactory) { self.controller.register_command(notification_name, factory) }
     fn execute_command(&self, notification: &Arc<dyn INotification>) { self.controller.execute_command(&notification); }
     fn has_command(&self, notification_name: &str) -> bool { self.controller.has_command(notification_name) }
     fn remove_command(&self, notification_name: &str) { self.controller.remove_command(notification_name); }
@@ -318,7 +318,7 @@ fn test_controller() {
 
         let controller = TestController::new("TestController", resource2.clone());
 
-        controller.register_command("TestCommand", || Box::new(TestCommand::new()) );
+        controller.register_command("TestCommand", Arc::new(|| Box::new(TestCommand::new())));
 
         let notification = Arc::new(Notification::new("TestCommand", None, None));
         controller.execute_command(&(notification as Arc<dyn INotification>));
@@ -332,3 +332,11 @@ fn test_controller() {
         assert_eq!(resource2.read().unwrap().state, State::Released);
     }
 }
+
+#[test]
+#[should_panic(expected = "IController::add_command is not implemented")]
+fn test_controller_add_command_default() {
+    let resource = Arc::new(RwLock::new(Resource{state: State::Allocated}));
+    let controller = TestController::new("TestControllerAddCommand", resource);
+    controller.add_command("TestCommand", Arc::new(|| Box::new(TestCommand::new())), 1);
+}
@@ -0,0 +1,187 @@
+use std::net::{TcpListener, TcpStream};
+use std::sync::mpsc::{self, Receiver};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
+use puremvc::core::View;
+use puremvc::interfaces::INotification;
+use puremvc::patterns::{BodyCodecRegistry, Facade, Observer};
+use puremvc::utilities::bridge::{BridgeMediator, BridgeStream};
+use puremvc::Error;
+
+/// Register an observer forwarding the names and bodies of the notifications it receives.
+///
+/// # Arguments
+/// * `key` - The key of the core to observe.
+/// * `name` - The notification name to observe.
+///
+/// # Returns
+/// The receiving end of the channel the notifications are forwarded to.
+fn observe(key: &str, name: &str) -> Receiver<(String, Option<String>, i32)> {
+    let (sender, receiver) = mpsc::channel();
+    let sender = Mutex::new(sender);
+    let notify = Arc::new(move |notification: &Arc<dyn INotification>| {
+        let body = *notification.body_ref::<i32>().unwrap();
+        let type_ = notification.get_type().map(|type_| type_.to_string());
+        let _ = sender.lock().unwrap().send((notification.name().to_string(), type_, body));
+    });
+    View::get_instance(key, View::new).register_observer(name, Arc::new(Observer::new(Some(notify), Some(Arc::new(())))));
+    receiver
+}
+
+/// Create the codecs shared by both ends of a bridge.
+fn codecs() -> Arc<BodyCodecRegistry> {
+    let mut codecs = BodyCodecRegistry::new();
+    codecs.register::<i32>("Ping");
+    codecs.register::<i32>("Pong");
+    Arc::new(codecs)
+}
+
+/// Connect two cores, exchange a notification in each direction, then disconnect.
+///
+/// # Arguments
+/// * `left_key` - The key of the core forwarding 'Ping'.
+/// * `right_key` - The key of the core forwarding 'Pong'.
+/// * `left` - The left end of the connection.
+/// * `right` - The right end of the connection.
+fn exchange(left_key: &str, right_key: &str, left: BridgeStream, right: BridgeStream) {
+    // Connect the cores, each forwarding one notification name
+    let left_facade = Facade::get_instance(left_key, Facade::new);
+    let right_facade = Facade::get_instance(right_key, Facade::new);
+    left_facade.register_mediator(Arc::new(RwLock::new(BridgeMediator::new(None, left, &["Ping"], codecs()))));
+    right_facade.register_mediator(Arc::new(RwLock::new(BridgeMediator::new(None, right, &["Pong"], codecs()))));
+    let pings = observe(right_key, "Ping");
+    let pongs = observe(left_key, "Pong");
+
+    // Send a Ping from the left core, and assert that the right core received it
+    left_facade.send_notification("Ping", Some(Arc::new(1)), Some("request"));
+    assert_eq!(pings.recv_timeout(Duration::from_secs(5)).unwrap(), ("Ping".to_string(), Some("request".to_string()), 1),
+        "Expecting the Ping received");
+
+    // Send a Pong from the right core, and assert that the left core received it
+    right_facade.send_notification("Pong", Some(Arc::new(2)), None);
+    assert_eq!(pongs.recv_timeout(Duration::from_secs(5)).unwrap(), ("Pong".to_string(), None, 2),
+        "Expecting the Pong received");
+
+    // Assert that notifications not forwarded stay local
+    right_facade.send_notification("Ping", Some(Arc::new(3)), None);
+    assert_eq!(pings.recv_timeout(Duration::from_secs(5)).unwrap().2, 3, "Expecting the local Ping");
+    assert!(pings.recv_timeout(Duration::from_millis(100)).is_err(), "Expecting no Ping echoed back");
+
+    // Disconnect the cores
+    left_facade.remove_mediator(BridgeMediator::NAME);
+    Facade::remove_core(left_key);
+    Facade::remove_core(right_key);
+}
+
+/// Tests bridging two cores over TCP loopback.
+#[test]
+fn test_bridge_over_tcp() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let left = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+    let (right, _) = listener.accept().unwrap();
+    exchange("BridgeMediatorTestKey1", "BridgeMediatorTestKey2", left.into(), right.into());
+}
+
+/// Tests bridging two cores over a Unix domain socket.
+#[cfg(unix)]
+#[test]
+fn test_bridge_over_unix_socket() {
+    let (left, right) = std::os::unix::net::UnixStream::pair().unwrap();
+    exchange("BridgeMediatorTestKey3", "BridgeMediatorTestKey4", left.into(), right.into());
+}
+
+/// Tests that forwarding both ways does not echo notifications back.
+///
+/// Connects two cores that both forward 'Ping', sends a Ping from each, and asserts that each
+/// core received it exactly once from the other.
+#[test]
+fn test_no_echo() {
+    let (left, right) = {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let left = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        (left, listener.accept().unwrap().0)
+    };
+    let left_facade = Facade::get_instance("BridgeMediatorTestKey5", Facade::new);
+    let right_facade = Facade::get_instance("BridgeMediatorTestKey6", Facade::new);
+    left_facade.register_mediator(Arc::new(RwLock::new(BridgeMediator::new(None, left, &["Ping"], codecs()))));
+    right_facade.register_mediator(Arc::new(RwLock::new(BridgeMediator::new(None, right, &["Ping"], codecs()))));
+    let left_pings = observe("BridgeMediatorTestKey5", "Ping");
+    let right_pings = observe("BridgeMediatorTestKey6", "Ping");
+
+    left_facade.send_notification("Ping", Some(Arc::new(1)), None);
+    assert_eq!(left_pings.recv_timeout(Duration::from_secs(5)).unwrap().2, 1, "Expecting the local Ping");
+    assert_eq!(right_pings.recv_timeout(Duration::from_secs(5)).unwrap().2, 1, "Expecting the remote Ping");
+    assert!(left_pings.recv_timeout(Duration::from_millis(100)).is_err(), "Expecting no Ping echoed back");
+    assert!(right_pings.recv_timeout(Duration::from_millis(100)).is_err(), "Expecting a single remote Ping");
+
+    Facade::remove_core("BridgeMediatorTestKey5");
+    Facade::remove_core("BridgeMediatorTestKey6");
+}
+
+/// Tests that a forwarded notification which cannot be decoded is reported.
+///
+/// Connects a core forwarding 'Ping' to a core without a codec for it, sends a Ping, and
+/// asserts that the receiving core sent `RECEIVE_FAILED` with the decoding error.
+#[test]
+fn test_receive_failed() {
+    let (left, right) = {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let left = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        (left, listener.accept().unwrap().0)
+    };
+    let left_facade = Facade::get_instance("BridgeMediatorTestKey7", Facade::new);
+    let right_facade = Facade::get_instance("BridgeMediatorTestKey8", Facade::new);
+    left_facade.register_mediator(Arc::new(RwLock::new(BridgeMediator::new(None, left, &["Ping"], codecs()))));
+    right_facade.register_mediator(Arc::new(RwLock::new(BridgeMediator::new(None, right, &[], Arc::new(BodyCodecRegistry::new())))));
+
+    let (sender, receiver) = mpsc::channel();
+    let sender = Mutex::new(sender);
+    let notify = Arc::new(move |notification: &Arc<dyn INotification>| {
+        let error = notification.body_ref::<Error>().unwrap().clone();
+        let type_ = notification.get_type().map(|type_| type_.to_string());
+        let _ = sender.lock().unwrap().send((error, type_));
+    });
+    View::get_instance("BridgeMediatorTestKey8", View::new)
+        .register_observer(BridgeMediator::RECEIVE_FAILED, Arc::new(Observer::new(Some(notify), Some(Arc::new(())))));
+
+    left_facade.send_notification("Ping", Some(Arc::new(1)), None);
+    let (error, type_) = receiver.recv_timeout(Duration::from_secs(5)).unwrap();
+    assert_eq!(error, Error::NotFound { kind: "Body codec", name: "Ping".into() }, "Expecting the missing codec reported");
+    assert_eq!(type_.as_deref(), Some(BridgeMediator::NAME), "Expecting the BridgeMediator name as type");
+
+    Facade::remove_core("BridgeMediatorTestKey7");
+    Facade::remove_core("BridgeMediatorTestKey8");
+}
+
+/// Tests that a notification which cannot be encoded is reported.
+///
+/// Connects a core forwarding 'Ping' without a codec for it, sends a Ping, and asserts that it
+/// sent `SEND_FAILED` with the encoding error.
+#[test]
+fn test_send_failed() {
+    let (left, _right) = {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let left = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        (left, listener.accept().unwrap().0)
+    };
+    let facade = Facade::get_instance("BridgeMediatorTestKey9", Facade::new);
+    facade.register_mediator(Arc::new(RwLock::new(BridgeMediator::new(None, left, &["Ping", BridgeMediator::SEND_FAILED], Arc::new(BodyCodecRegistry::new())))));
+
+    let (sender, receiver) = mpsc::channel();
+    let sender = Mutex::new(sender);
+    let notify = Arc::new(move |notification: &Arc<dyn INotification>| {
+        let error = notification.body_ref::<Error>().unwrap().clone();
+        let type_ = notification.get_type().map(|type_| type_.to_string());
+        let _ = sender.lock().unwrap().send((error, type_));
+    });
+    View::get_instance("BridgeMediatorTestKey9", View::new)
+        .register_observer(BridgeMediator::SEND_FAILED, Arc::new(Observer::new(Some(notify), Some(Arc::new(())))));
+
+    facade.send_notification("Ping", Some(Arc::new(1)), None);
+    let (error, type_) = receiver.recv_timeout(Duration::from_secs(5)).unwrap();
+    assert_eq!(error, Error::NotFound { kind: "Body codec", name: "Ping".into() }, "Expecting the missing codec reported");
+    assert_eq!(type_.as_deref(), Some(BridgeMediator::NAME), "Expecting the BridgeMediator name as type");
+    assert!(receiver.recv_timeout(Duration::from_millis(100)).is_err(), "Expecting SEND_FAILED itself not forwarded");
+
+    Facade::remove_core("BridgeMediatorTestKey9");
+}
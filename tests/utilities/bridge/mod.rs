@@ -0,0 +1 @@
+mod bridge_mediator_test;
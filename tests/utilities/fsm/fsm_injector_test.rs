@@ -0,0 +1,112 @@
+use std::sync::Arc;
+use puremvc::Error;
+use puremvc::core::View;
+use puremvc::interfaces::{DispatchMode, IFacade, INotifier};
+use puremvc::patterns::Facade;
+use puremvc::utilities::fsm::{FsmDefinition, FsmInjector, StateDefinition, StateMachine};
+
+/// Returns the door state machine definition used by FsmInjectorTest.
+fn door() -> FsmDefinition {
+    FsmDefinition::new(Some("CLOSED"), vec![
+        StateDefinition::new("OPENED", None, None, Some("opened")).transition("close", "CLOSED"),
+        StateDefinition::new("CLOSED", None, None, Some("closed")).transition("open", "OPENED").transition("lock", "LOCKED"),
+        StateDefinition::new("LOCKED", None, None, Some("locked")).transition("unlock", "CLOSED"),
+    ])
+}
+
+/// Returns the name of the current state of the `StateMachine` registered with the given facade.
+fn current(facade: &Arc<dyn IFacade>) -> Option<String> {
+    let mediator = facade.retrieve_mediator(StateMachine::NAME)?;
+    let mut guard = mediator.write().unwrap();
+    guard.as_any().downcast_mut::<StateMachine>()?
+        .current_state()
+        .map(|state| state.name().to_string())
+}
+
+/// Tests creating a StateMachine from a definition.
+///
+/// Creates a `StateMachine` from the door definition and asserts that every state and its
+/// transitions were created.
+#[test]
+fn test_create_state_machine() {
+    let state_machine = FsmInjector::new(door()).create_state_machine();
+
+    let closed = state_machine.retrieve_state("CLOSED").expect("Expecting CLOSED state");
+    assert_eq!(closed.changed(), Some("closed"), "Expecting closed.changed() == 'closed'");
+    assert_eq!(closed.target("open"), Some("OPENED"), "Expecting closed.target('open') == 'OPENED'");
+    assert_eq!(closed.target("lock"), Some("LOCKED"), "Expecting closed.target('lock') == 'LOCKED'");
+    assert!(state_machine.retrieve_state("OPENED").is_some(), "Expecting OPENED state");
+    assert!(state_machine.retrieve_state("LOCKED").is_some(), "Expecting LOCKED state");
+    assert!(state_machine.current_state().is_none(), "Expecting no current state before registration");
+}
+
+/// Tests injecting a StateMachine into a core.
+///
+/// Injects the door definition, asserts the initial state was entered, then drives the state
+/// machine with actions and asserts the resulting states.
+#[test]
+fn test_inject() {
+    let key = "FsmInjectorTestInject";
+    let facade = Facade::get_instance(key, Facade::new);
+
+    let mut injector = FsmInjector::new(door());
+    injector.initialize_notifier(key);
+    injector.inject();
+
+    assert!(facade.has_mediator(StateMachine::NAME), "Expecting StateMachine registered");
+    assert_eq!(current(&facade), Some("CLOSED".to_string()), "Expecting current state 'CLOSED'");
+
+    facade.send_notification(StateMachine::ACTION, None, Some("lock"));
+    assert_eq!(current(&facade), Some("LOCKED".to_string()), "Expecting current state 'LOCKED'");
+
+    facade.send_notification(StateMachine::ACTION, None, Some("open"));
+    assert_eq!(current(&facade), Some("LOCKED".to_string()), "Expecting locked door not to open");
+
+    facade.send_notification(StateMachine::ACTION, None, Some("unlock"));
+    assert_eq!(current(&facade), Some("CLOSED".to_string()), "Expecting current state 'CLOSED'");
+
+    Facade::remove_core(key);
+}
+
+/// Tests that a StateMachine is not injected into a core in deferred dispatch mode.
+///
+/// Switches the core's view to `DispatchMode::Deferred`, where guards could not cancel
+/// transitions, and asserts that `try_inject` fails and that no StateMachine was registered.
+#[test]
+fn test_inject_deferred() {
+    let key = "FsmInjectorTestDeferred";
+    let facade = Facade::get_instance(key, Facade::new);
+    View::get_instance(key, View::new).set_dispatch_mode(DispatchMode::Deferred);
+
+    let mut injector = FsmInjector::new(door());
+    injector.initialize_notifier(key);
+
+    assert_eq!(injector.try_inject(), Err(Error::UnsupportedDispatchMode { kind: "StateMachine", mode: DispatchMode::Deferred }));
+    injector.inject();
+    assert!(!facade.has_mediator(StateMachine::NAME), "Expecting no StateMachine registered");
+
+    Facade::remove_core(key);
+}
+
+/// Tests loading a definition from JSON.
+///
+/// Deserializes a door definition from JSON text, with optional fields left out, and asserts
+/// that it equals the definition built in code and that it creates a working `StateMachine`.
+#[cfg(feature = "serde")]
+#[test]
+fn test_definition_from_json() {
+    let json = r#"{
+        "initial": "CLOSED",
+        "states": [
+            { "name": "OPENED", "changed": "opened", "transitions": [{ "action": "close", "target": "CLOSED" }] },
+            { "name": "CLOSED", "changed": "closed", "transitions": [{ "action": "open", "target": "OPENED" }, { "action": "lock", "target": "LOCKED" }] },
+            { "name": "LOCKED", "changed": "locked", "transitions": [{ "action": "unlock", "target": "CLOSED" }] }
+        ]
+    }"#;
+    let definition: FsmDefinition = serde_json::from_str(json).expect("Expecting a valid definition");
+    assert_eq!(definition, door(), "Expecting the definition built in code");
+
+    let state_machine = FsmInjector::new(definition).create_state_machine();
+    let locked = state_machine.retrieve_state("LOCKED").expect("Expecting LOCKED state");
+    assert_eq!(locked.target("unlock"), Some("CLOSED"), "Expecting locked.target('unlock') == 'CLOSED'");
+}
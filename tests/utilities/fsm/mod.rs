@@ -0,0 +1,3 @@
+mod state_test;
+mod state_machine_test;
+mod fsm_injector_test;
@@ -0,0 +1,158 @@
+use std::sync::{Arc, Mutex, RwLock};
+use puremvc::core::View;
+use puremvc::interfaces::{IFacade, INotification};
+use puremvc::patterns::{Facade, Observer};
+use puremvc::utilities::fsm::{State, StateMachine};
+
+/// Records the name and type of every notification sent with one of the given names.
+fn record(key: &str, names: &[&str], log: &Arc<Mutex<Vec<String>>>) {
+    let view = View::get_instance(key, View::new);
+    for name in names {
+        let notify = {
+            let log = Arc::clone(log);
+            Arc::new(move |notification: &Arc<dyn INotification>| {
+                log.lock().unwrap().push(format!("{}:{}", notification.name(), notification.get_type().unwrap_or("")));
+            })
+        };
+        view.register_observer(name, Arc::new(Observer::new(Some(notify), Some(Arc::clone(log) as _))));
+    }
+}
+
+/// Creates a door `StateMachine` starting in the CLOSED state.
+fn door() -> StateMachine {
+    let mut opened = State::new("OPENED", Some("enteringOpened"), Some("exitingOpened"), Some("opened"));
+    opened.define_transition("close", "CLOSED");
+    let mut closed = State::new("CLOSED", Some("enteringClosed"), Some("exitingClosed"), Some("closed"));
+    closed.define_transition("open", "OPENED");
+
+    let mut state_machine = StateMachine::new();
+    state_machine.register_state(opened, false);
+    state_machine.register_state(closed, true);
+    state_machine
+}
+
+/// Returns the name of the current state of the `StateMachine` registered with the given facade.
+fn current(facade: &Arc<dyn IFacade>) -> Option<String> {
+    let mediator = facade.retrieve_mediator(StateMachine::NAME)?;
+    let mut guard = mediator.write().unwrap();
+    guard.as_any().downcast_mut::<StateMachine>()?
+        .current_state()
+        .map(|state| state.name().to_string())
+}
+
+/// Tests registering a StateMachine enters its initial state.
+///
+/// Registers a door `StateMachine` and asserts that the CLOSED state was entered, and that its
+/// entering, changed and `StateMachine::CHANGED` notifications were sent in order.
+#[test]
+fn test_register_enters_initial_state() {
+    let key = "StateMachineTestInitial";
+    let facade = Facade::get_instance(key, Facade::new);
+    let log = Arc::new(Mutex::new(Vec::new()));
+    record(key, &["enteringClosed", "closed", StateMachine::CHANGED], &log);
+
+    facade.register_mediator(Arc::new(RwLock::new(door())));
+
+    assert_eq!(current(&facade), Some("CLOSED".to_string()), "Expecting current state 'CLOSED'");
+    assert_eq!(*log.lock().unwrap(), vec![
+        "enteringClosed:".to_string(),
+        "closed:".to_string(),
+        format!("{}:CLOSED", StateMachine::CHANGED),
+    ]);
+
+    Facade::remove_core(key);
+}
+
+/// Tests transitioning between states with action notifications.
+///
+/// Sends the 'open' and 'close' actions and asserts the current state and the exiting,
+/// entering and changed notifications sent for each transition. An action with no transition
+/// defined in the current state is ignored.
+#[test]
+fn test_action_transitions() {
+    let key = "StateMachineTestAction";
+    let facade = Facade::get_instance(key, Facade::new);
+    facade.register_mediator(Arc::new(RwLock::new(door())));
+
+    let log = Arc::new(Mutex::new(Vec::new()));
+    record(key, &["exitingClosed", "enteringOpened", "opened", "exitingOpened", "enteringClosed", "closed"], &log);
+
+    facade.send_notification(StateMachine::ACTION, None, Some("close"));
+    assert_eq!(current(&facade), Some("CLOSED".to_string()), "Expecting undefined action to be ignored");
+
+    facade.send_notification(StateMachine::ACTION, None, Some("open"));
+    assert_eq!(current(&facade), Some("OPENED".to_string()), "Expecting current state 'OPENED'");
+
+    facade.send_notification(StateMachine::ACTION, None, Some("close"));
+    assert_eq!(current(&facade), Some("CLOSED".to_string()), "Expecting current state 'CLOSED'");
+
+    assert_eq!(*log.lock().unwrap(), vec![
+        "exitingClosed:OPENED", "enteringOpened:", "opened:",
+        "exitingOpened:CLOSED", "enteringClosed:", "closed:",
+    ]);
+
+    Facade::remove_core(key);
+}
+
+/// Tests canceling a transition from an exiting guard.
+///
+/// Registers an observer for the CLOSED state's exiting notification that sends
+/// `StateMachine::CANCEL`, then sends the 'open' action and asserts that the state machine
+/// stayed in the CLOSED state without entering OPENED.
+#[test]
+fn test_cancel_transition() {
+    let key = "StateMachineTestCancel";
+    let facade = Facade::get_instance(key, Facade::new);
+    facade.register_mediator(Arc::new(RwLock::new(door())));
+
+    let log = Arc::new(Mutex::new(Vec::new()));
+    record(key, &["enteringOpened", "opened"], &log);
+
+    let guard = {
+        let facade = Arc::clone(&facade);
+        Arc::new(move |_: &Arc<dyn INotification>| facade.send_notification(StateMachine::CANCEL, None, None))
+    };
+    View::get_instance(key, View::new)
+        .register_observer("exitingClosed", Arc::new(Observer::new(Some(guard), Some(Arc::new(0) as _))));
+
+    facade.send_notification(StateMachine::ACTION, None, Some("open"));
+
+    assert_eq!(current(&facade), Some("CLOSED".to_string()), "Expecting current state 'CLOSED'");
+    assert!(log.lock().unwrap().is_empty(), "Expecting OPENED not entered");
+
+    Facade::remove_core(key);
+}
+
+/// Tests sending an action while a transition is in progress.
+///
+/// Registers an observer for the OPENED state's changed notification that sends the 'close'
+/// action, then sends the 'open' action and asserts that the state machine opened, then closed
+/// once the first transition ended, instead of deadlocking on its own lock.
+#[test]
+fn test_action_during_transition() {
+    let key = "StateMachineTestReentrant";
+    let facade = Facade::get_instance(key, Facade::new);
+    facade.register_mediator(Arc::new(RwLock::new(door())));
+
+    let log = Arc::new(Mutex::new(Vec::new()));
+    record(key, &["opened", "closed", StateMachine::CHANGED], &log);
+
+    let reaction = {
+        let facade = Arc::clone(&facade);
+        Arc::new(move |_: &Arc<dyn INotification>| facade.send_notification(StateMachine::ACTION, None, Some("close")))
+    };
+    View::get_instance(key, View::new)
+        .register_observer("opened", Arc::new(Observer::new(Some(reaction), Some(Arc::new(0) as _))));
+
+    facade.send_notification(StateMachine::ACTION, None, Some("open"));
+
+    assert_eq!(current(&facade), Some("CLOSED".to_string()), "Expecting current state 'CLOSED'");
+    assert_eq!(*log.lock().unwrap(), vec![
+        "opened:".to_string(),
+        format!("{}:OPENED", StateMachine::CHANGED),
+        "closed:".to_string(),
+        format!("{}:CLOSED", StateMachine::CHANGED),
+    ], "Expecting the 'close' action performed after the 'open' transition");
+
+    Facade::remove_core(key);
+}
@@ -0,0 +1,38 @@
+use puremvc::utilities::fsm::{State, Transition};
+
+/// Tests the accessors of the State class.
+///
+/// Creates a `State` with entering, exiting and changed notification names and asserts that
+/// each accessor returns the value it was constructed with.
+#[test]
+fn test_state_accessors() {
+    let state = State::new("OPENED", Some("entering"), Some("exiting"), None);
+
+    assert_eq!(state.name(), "OPENED", "Expecting state.name() == 'OPENED'");
+    assert_eq!(state.entering(), Some("entering"), "Expecting state.entering() == 'entering'");
+    assert_eq!(state.exiting(), Some("exiting"), "Expecting state.exiting() == 'exiting'");
+    assert_eq!(state.changed(), None, "Expecting state.changed() == None");
+    assert!(state.transitions().is_empty(), "Expecting no transitions");
+}
+
+/// Tests defining and removing transitions.
+///
+/// Defines two transitions, redefines one of them, and removes the other, asserting the
+/// target returned for each action along the way.
+#[test]
+fn test_define_and_remove_transition() {
+    let mut state = State::new("CLOSED", None, None, None);
+    state.define_transition("open", "OPENED");
+    state.define_transition("lock", "LOCKED");
+
+    assert_eq!(state.target("open"), Some("OPENED"), "Expecting target('open') == 'OPENED'");
+    assert_eq!(state.target("lock"), Some("LOCKED"), "Expecting target('lock') == 'LOCKED'");
+
+    state.define_transition("open", "AJAR");
+    assert_eq!(state.target("open"), Some("AJAR"), "Expecting redefined target('open') == 'AJAR'");
+    assert_eq!(state.transitions().len(), 2, "Expecting two transitions");
+
+    state.remove_transition("lock");
+    assert_eq!(state.target("lock"), None, "Expecting target('lock') == None");
+    assert_eq!(state.transitions(), &[Transition::new("open", "AJAR")], "Expecting only the 'open' transition");
+}
@@ -0,0 +1,4 @@
+#[cfg(feature = "bridge")]
+mod bridge;
+mod fsm;
+mod pipes;
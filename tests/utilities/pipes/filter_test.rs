@@ -0,0 +1,75 @@
+use std::sync::{Arc, Mutex, RwLock};
+use puremvc::utilities::pipes::interfaces::{IPipeFitting, IPipeMessage};
+use puremvc::utilities::pipes::messages::{FilterControlMessage, Message};
+use puremvc::utilities::pipes::plumbing::{Filter, FilterFn, PipeListener};
+
+/// Creates a `PipeListener` that records the bodies of the messages it receives.
+fn recorder(received: Arc<Mutex<Vec<i32>>>) -> Arc<RwLock<dyn IPipeFitting>> {
+    Arc::new(RwLock::new(PipeListener::new(Arc::new(move |message: Arc<dyn IPipeMessage>| {
+        if let Some(body) = message.body().and_then(|body| body.downcast_ref::<i32>()) {
+            received.lock().unwrap().push(*body);
+        }
+    }))))
+}
+
+/// Creates a normal message carrying an integer body.
+fn message(body: i32) -> Arc<dyn IPipeMessage> {
+    Arc::new(Message::new(Message::NORMAL, None, Some(Arc::new(body)), None))
+}
+
+/// A filter function that multiplies the body by the `i32` filter parameter, dropping
+/// messages whose body is negative.
+fn multiplier() -> FilterFn {
+    Arc::new(|message, params| {
+        let body = *message.body()?.downcast_ref::<i32>()?;
+        if body < 0 { return None }
+        let factor = params.and_then(|params| params.downcast_ref::<i32>()).copied().unwrap_or(1);
+        Some(Arc::new(Message::new(message.get_type(), None, Some(Arc::new(body * factor)), Some(message.priority()))))
+    })
+}
+
+/// Tests that a `Filter` applies its filter function and parameters.
+///
+/// Writes messages through a multiplying filter and asserts the listener receives the modified
+/// bodies, while the negative one is filtered out.
+#[test]
+fn test_filter_applies_function() {
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let mut filter = Filter::new("scale", Some(recorder(received.clone())), Some(multiplier()), Some(Arc::new(10)));
+
+    assert!(filter.write(message(2)), "Expecting wrote message through filter");
+    assert!(!filter.write(message(-1)), "Expecting negative message filtered out");
+    assert_eq!(*received.lock().unwrap(), vec![20]);
+}
+
+/// Tests controlling a `Filter` with `FilterControlMessage`s.
+///
+/// Changes the filter parameters, toggles bypass mode and back, and asserts that control
+/// messages addressed to other filters are passed along.
+#[test]
+fn test_filter_control_messages() {
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let mut filter = Filter::new("scale", Some(recorder(received.clone())), Some(multiplier()), Some(Arc::new(10)));
+
+    filter.write(Arc::new(FilterControlMessage::new(FilterControlMessage::SET_PARAMS, "scale", None, Some(Arc::new(3)))));
+    filter.write(message(2));
+
+    filter.write(Arc::new(FilterControlMessage::new(FilterControlMessage::BYPASS, "scale", None, None)));
+    assert_eq!(filter.mode(), FilterControlMessage::BYPASS);
+    filter.write(message(2));
+
+    filter.write(Arc::new(FilterControlMessage::new(FilterControlMessage::FILTER, "scale", None, None)));
+    filter.write(Arc::new(FilterControlMessage::new(FilterControlMessage::SET_FILTER, "scale", Some(Arc::new(|_, _| None)), None)));
+    assert!(!filter.write(message(2)), "Expecting message filtered out by new filter function");
+
+    assert_eq!(*received.lock().unwrap(), vec![6, 2]);
+
+    let other = FilterControlMessage::new(FilterControlMessage::BYPASS, "other", None, None);
+    let passed = Arc::new(Mutex::new(0));
+    let counter = passed.clone();
+    let listener: Arc<RwLock<dyn IPipeFitting>> = Arc::new(RwLock::new(PipeListener::new(Arc::new(move |_| *counter.lock().unwrap() += 1))));
+    let mut filter = Filter::new("scale", Some(listener), None, None);
+    assert!(filter.write(Arc::new(other)), "Expecting control message for other filter passed along");
+    assert_eq!(*passed.lock().unwrap(), 1);
+    assert_eq!(filter.name(), "scale");
+}
@@ -0,0 +1,187 @@
+use std::any::Any;
+use std::sync::{Arc, Mutex, RwLock, Weak};
+use puremvc::interfaces::{IFacade, IMediator, INotification, INotifier};
+use puremvc::patterns::Facade;
+use puremvc::utilities::pipes::interfaces::{IPipeFitting, IPipeMessage};
+use puremvc::utilities::pipes::messages::Message;
+use puremvc::utilities::pipes::plumbing::{Junction, JunctionMediator, Pipe, TeeSplit};
+
+/// A JunctionMediator subclass used by JunctionMediatorTest.
+struct JunctionMediatorTestMediator {
+    mediator: JunctionMediator,
+    received: Arc<Mutex<Vec<String>>>,
+}
+
+impl JunctionMediatorTestMediator {
+    pub const NAME: &'static str = "JunctionMediatorTestMediator";
+
+    /// Constructor.
+    fn new(received: Arc<Mutex<Vec<String>>>) -> Self {
+        let mut junction = Junction::new();
+        junction.register_pipe(Junction::STDOUT, Junction::OUTPUT, Arc::new(RwLock::new(TeeSplit::new(vec![]))));
+        Self { mediator: JunctionMediator::new(Some(Self::NAME), junction), received }
+    }
+}
+
+impl INotifier for JunctionMediatorTestMediator {
+    /// Returns the key associated with this notifier.
+    fn key(&self) -> &str {
+        self.mediator.key()
+    }
+
+    /// Returns the facade instance for this notifier.
+    fn facade(&self) -> Arc<dyn IFacade> {
+        self.mediator.facade()
+    }
+
+    /// Initializes the notifier with the specified key.
+    ///
+    /// # Arguments
+    /// * `key` - The key to associate with this notifier
+    fn initialize_notifier(&mut self, key: &str) {
+        self.mediator.initialize_notifier(key);
+    }
+
+    /// Sends a notification with the specified name, body, and type.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the notification
+    /// * `body` - Optional data payload for the notification
+    /// * `type_` - Optional type identifier for the notification
+    fn send_notification(&self, name: &str, body: Option<Arc<dyn Any + Send + Sync>>, type_: Option<&str>) {
+        self.mediator.send_notification(name, body, type_);
+    }
+}
+
+impl IMediator for JunctionMediatorTestMediator {
+    /// Returns the name of the mediator.
+    fn name(&self) -> &str { self.mediator.name() }
+
+    /// Returns the component associated with the mediator, if any.
+    fn component(&self) -> Option<&Weak<dyn Any + Send + Sync>> {
+        self.mediator.component()
+    }
+
+    /// Sets the component for the mediator.
+    ///
+    /// # Arguments
+    /// * `component` - Optional component to be associated with the mediator
+    fn set_component(&mut self, component: Option<Weak<dyn Any + Send + Sync>>) {
+        self.mediator.set_component(component);
+    }
+
+    /// Lists the notifications this mediator is interested in.
+    fn list_notification_interests(&self) -> Vec<String> {
+        self.mediator.list_notification_interests()
+    }
+
+    /// Records pipe messages and delegates pipe connection notifications.
+    fn handle_notification(&mut self, notification: &Arc<dyn INotification>) {
+        if notification.name() != JunctionMediator::PIPE_MESSAGE {
+            return self.mediator.handle_notification(notification);
+        }
+
+        let body = notification.body()
+            .and_then(|body| body.downcast_ref::<Arc<dyn IPipeMessage>>())
+            .and_then(|message| message.body())
+            .and_then(|body| body.downcast_ref::<String>());
+        if let Some(body) = body {
+            self.received.lock().unwrap().push(format!("{}:{}", notification.get_type().unwrap_or(""), body));
+        }
+    }
+
+    /// Returns a mutable reference to the mediator as a dynamic `Any` type.
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// Sends a message on the STDOUT pipe of the test mediator registered in the given core.
+fn send(facade: &Arc<dyn IFacade>, body: &str) -> bool {
+    facade.retrieve_mediator(JunctionMediatorTestMediator::NAME)
+        .and_then(|mediator| {
+            let mut guard = mediator.write().unwrap();
+            guard.as_any().downcast_mut::<JunctionMediatorTestMediator>()
+                .map(|mediator| {
+                    let message = Message::new(Message::NORMAL, None, Some(Arc::new(body.to_string())), None);
+                    mediator.mediator.junction().send_message(Junction::STDOUT, Arc::new(message))
+                })
+        })
+        .unwrap_or(false)
+}
+
+/// Tests connecting two cores with pipes through their `JunctionMediator`s.
+///
+/// Connects the shell's STDOUT to the module's STDIN and the module's STDOUT to the shell's
+/// STDIN, then sends a message in each direction and asserts that each mediator received the
+/// other core's message on its STDIN pipe.
+#[test]
+fn test_connect_cores_through_junction_mediators() {
+    let shell_received = Arc::new(Mutex::new(Vec::new()));
+    let module_received = Arc::new(Mutex::new(Vec::new()));
+
+    let shell = Facade::get_instance("JunctionMediatorTestShell", Facade::new);
+    let module = Facade::get_instance("JunctionMediatorTestModule", Facade::new);
+    shell.register_mediator(Arc::new(RwLock::new(JunctionMediatorTestMediator::new(shell_received.clone()))));
+    module.register_mediator(Arc::new(RwLock::new(JunctionMediatorTestMediator::new(module_received.clone()))));
+
+    let shell_to_module: Arc<RwLock<dyn IPipeFitting>> = Arc::new(RwLock::new(Pipe::new(None)));
+    shell.send_notification(JunctionMediator::ACCEPT_OUTPUT_PIPE, Some(Arc::new(Arc::clone(&shell_to_module))), Some(Junction::STDOUT));
+    module.send_notification(JunctionMediator::ACCEPT_INPUT_PIPE, Some(Arc::new(Arc::clone(&shell_to_module))), Some(Junction::STDIN));
+
+    let module_to_shell: Arc<RwLock<dyn IPipeFitting>> = Arc::new(RwLock::new(Pipe::new(None)));
+    module.send_notification(JunctionMediator::ACCEPT_OUTPUT_PIPE, Some(Arc::new(Arc::clone(&module_to_shell))), Some(Junction::STDOUT));
+    shell.send_notification(JunctionMediator::ACCEPT_INPUT_PIPE, Some(Arc::new(Arc::clone(&module_to_shell))), Some(Junction::STDIN));
+
+    assert!(send(&shell, "hello module"), "Expecting shell sent message");
+    assert!(send(&module, "hello shell"), "Expecting module sent message");
+
+    assert_eq!(*module_received.lock().unwrap(), vec![format!("{}:hello module", Junction::STDIN)]);
+    assert_eq!(*shell_received.lock().unwrap(), vec![format!("{}:hello shell", Junction::STDIN)]);
+
+    Facade::remove_core("JunctionMediatorTestShell");
+    Facade::remove_core("JunctionMediatorTestModule");
+}
+
+/// Tests merging a second input pipe into an already accepted input pipe.
+///
+/// Accepts two pipes with the same input pipe name and asserts that messages written to either
+/// arrive at the mediator.
+#[test]
+fn test_accept_second_input_pipe_merges() {
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let module = Facade::get_instance("JunctionMediatorTestMerge", Facade::new);
+    module.register_mediator(Arc::new(RwLock::new(JunctionMediatorTestMediator::new(received.clone()))));
+
+    let pipe1: Arc<RwLock<dyn IPipeFitting>> = Arc::new(RwLock::new(Pipe::new(None)));
+    let pipe2: Arc<RwLock<dyn IPipeFitting>> = Arc::new(RwLock::new(Pipe::new(None)));
+    module.send_notification(JunctionMediator::ACCEPT_INPUT_PIPE, Some(Arc::new(Arc::clone(&pipe1))), Some(Junction::STDIN));
+    module.send_notification(JunctionMediator::ACCEPT_INPUT_PIPE, Some(Arc::new(Arc::clone(&pipe2))), Some(Junction::STDIN));
+
+    pipe1.write().unwrap().write(Arc::new(Message::new(Message::NORMAL, None, Some(Arc::new("one".to_string())), None)));
+    pipe2.write().unwrap().write(Arc::new(Message::new(Message::NORMAL, None, Some(Arc::new("two".to_string())), None)));
+
+    assert_eq!(*received.lock().unwrap(), vec![format!("{}:one", Junction::STDIN), format!("{}:two", Junction::STDIN)]);
+
+    Facade::remove_core("JunctionMediatorTestMerge");
+}
+
+/// Tests that a message arriving after its core was removed does not recreate the core.
+///
+/// Accepts an input pipe, removes the core, writes a message to the pipe, and asserts that the
+/// core is still gone and the mediator received nothing.
+#[test]
+fn test_message_after_remove_core() {
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let module = Facade::get_instance("JunctionMediatorTestRemoved", Facade::new);
+    module.register_mediator(Arc::new(RwLock::new(JunctionMediatorTestMediator::new(received.clone()))));
+
+    let pipe: Arc<RwLock<dyn IPipeFitting>> = Arc::new(RwLock::new(Pipe::new(None)));
+    module.send_notification(JunctionMediator::ACCEPT_INPUT_PIPE, Some(Arc::new(Arc::clone(&pipe))), Some(Junction::STDIN));
+    Facade::remove_core("JunctionMediatorTestRemoved");
+
+    pipe.write().unwrap().write(Arc::new(Message::new(Message::NORMAL, None, Some(Arc::new("late".to_string())), None)));
+
+    assert!(!Facade::has_core("JunctionMediatorTestRemoved"), "Expecting the removed core not recreated");
+    assert!(received.lock().unwrap().is_empty(), "Expecting no message received");
+}
@@ -0,0 +1,46 @@
+use std::sync::{Arc, Mutex, RwLock};
+use puremvc::utilities::pipes::interfaces::{IPipeFitting, IPipeMessage};
+use puremvc::utilities::pipes::messages::Message;
+use puremvc::utilities::pipes::plumbing::{Junction, Pipe};
+
+/// Tests registering, retrieving and removing pipes.
+#[test]
+fn test_register_retrieve_and_remove_pipe() {
+    let mut junction = Junction::new();
+    let pipe: Arc<RwLock<dyn IPipeFitting>> = Arc::new(RwLock::new(Pipe::new(None)));
+
+    assert!(junction.register_pipe(Junction::STDIN, Junction::INPUT, Arc::clone(&pipe)), "Expecting registered pipe");
+    assert!(!junction.register_pipe(Junction::STDIN, Junction::INPUT, Arc::clone(&pipe)), "Expecting duplicate name rejected");
+    assert!(junction.has_pipe(Junction::STDIN), "Expecting has pipe");
+    assert!(junction.has_input_pipe(Junction::STDIN), "Expecting has input pipe");
+    assert!(!junction.has_output_pipe(Junction::STDIN), "Expecting no output pipe");
+    assert!(junction.retrieve_pipe(Junction::STDIN).is_some_and(|retrieved| Arc::ptr_eq(&retrieved, &pipe)));
+
+    assert!(junction.remove_pipe(Junction::STDIN).is_some(), "Expecting removed pipe");
+    assert!(!junction.has_pipe(Junction::STDIN), "Expecting pipe gone");
+}
+
+/// Tests sending a message from an output pipe to a listener on an input pipe.
+///
+/// Both junctions share a pipe; the first uses it as output, the second as input with a
+/// listener.
+#[test]
+fn test_send_message_to_pipe_listener() {
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let pipe: Arc<RwLock<dyn IPipeFitting>> = Arc::new(RwLock::new(Pipe::new(None)));
+
+    let mut shell = Junction::new();
+    let mut module = Junction::new();
+    shell.register_pipe(Junction::STDOUT, Junction::OUTPUT, Arc::clone(&pipe));
+    module.register_pipe(Junction::STDIN, Junction::INPUT, Arc::clone(&pipe));
+
+    let sink = received.clone();
+    assert!(module.add_pipe_listener(Junction::STDIN, Arc::new(move |message: Arc<dyn IPipeMessage>| {
+        sink.lock().unwrap().push(message.get_type().to_string());
+    })), "Expecting listener added");
+    assert!(!module.add_pipe_listener(Junction::STDOUT, Arc::new(|_| {})), "Expecting no listener on missing input pipe");
+
+    assert!(shell.send_message(Junction::STDOUT, Arc::new(Message::new(Message::NORMAL, None, None, None))), "Expecting message sent");
+    assert!(!shell.send_message(Junction::STDIN, Arc::new(Message::new(Message::NORMAL, None, None, None))), "Expecting no send on missing output pipe");
+    assert_eq!(*received.lock().unwrap(), vec![Message::NORMAL.to_string()]);
+}
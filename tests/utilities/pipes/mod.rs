@@ -0,0 +1,6 @@
+mod pipe_test;
+mod tee_test;
+mod filter_test;
+mod queue_test;
+mod junction_test;
+mod junction_mediator_test;
\ No newline at end of file
@@ -0,0 +1,73 @@
+use std::sync::{Arc, Mutex, RwLock};
+use puremvc::utilities::pipes::interfaces::{IPipeFitting, IPipeMessage};
+use puremvc::utilities::pipes::messages::Message;
+use puremvc::utilities::pipes::plumbing::{Pipe, PipeListener};
+
+/// Creates a `PipeListener` that records the bodies of the messages it receives.
+fn recorder(received: Arc<Mutex<Vec<String>>>) -> Arc<RwLock<dyn IPipeFitting>> {
+    Arc::new(RwLock::new(PipeListener::new(Arc::new(move |message: Arc<dyn IPipeMessage>| {
+        if let Some(body) = message.body().and_then(|body| body.downcast_ref::<String>()) {
+            received.lock().unwrap().push(body.clone());
+        }
+    }))))
+}
+
+/// Tests connecting and disconnecting two pipes.
+///
+/// Connects a second pipe to the output of the first, asserts that a third pipe cannot be
+/// connected while an output is present, and that disconnecting returns the connected pipe.
+#[test]
+fn test_connecting_and_disconnecting_two_pipes() {
+    let mut pipe1 = Pipe::new(None);
+    let pipe2: Arc<RwLock<dyn IPipeFitting>> = Arc::new(RwLock::new(Pipe::new(None)));
+
+    assert!(pipe1.connect(Arc::clone(&pipe2)), "Expecting connected pipe1 to pipe2");
+    assert!(!pipe1.connect(Arc::new(RwLock::new(Pipe::new(None)))), "Expecting second connect to fail");
+
+    let disconnected = pipe1.disconnect();
+    assert!(disconnected.is_some_and(|pipe| Arc::ptr_eq(&pipe, &pipe2)), "Expecting disconnected pipe2 from pipe1");
+    assert!(pipe1.disconnect().is_none(), "Expecting nothing left to disconnect");
+}
+
+/// Tests writing a message through a pipeline to a `PipeListener`.
+///
+/// Writes a message into a two pipe pipeline terminated by a listener and asserts that the
+/// listener received the message body. Writing to an unconnected pipe fails.
+#[test]
+fn test_write_through_pipeline() {
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let pipe2: Arc<RwLock<dyn IPipeFitting>> = Arc::new(RwLock::new(Pipe::new(Some(recorder(received.clone())))));
+    let mut pipe1 = Pipe::new(Some(pipe2));
+
+    let message = Message::new(Message::NORMAL, None, Some(Arc::new("Hello".to_string())), None);
+    assert!(pipe1.write(Arc::new(message)), "Expecting wrote message to pipeline");
+    assert_eq!(*received.lock().unwrap(), vec!["Hello".to_string()]);
+
+    let mut unconnected = Pipe::new(None);
+    let message = Message::new(Message::NORMAL, None, None, None);
+    assert!(!unconnected.write(Arc::new(message)), "Expecting write to unconnected pipe to fail");
+}
+
+/// Tests that a `PipeListener` cannot be connected to an output.
+#[test]
+fn test_pipe_listener_cannot_connect() {
+    let mut listener = PipeListener::new(Arc::new(|_| {}));
+
+    assert!(!listener.connect(Arc::new(RwLock::new(Pipe::new(None)))), "Expecting listener connect to fail");
+    assert!(listener.disconnect().is_none(), "Expecting nothing to disconnect");
+}
+
+/// Tests the `Message` accessors and default priority.
+#[test]
+fn test_message_accessors() {
+    let mut message = Message::new(Message::NORMAL, Some(Arc::new("header")), None, None);
+
+    assert_eq!(message.get_type(), Message::NORMAL);
+    assert_eq!(message.priority(), Message::PRIORITY_MED);
+    assert!(message.header().and_then(|header| header.downcast_ref::<&str>()).is_some_and(|header| *header == "header"));
+
+    message.set_priority(Message::PRIORITY_HIGH);
+    message.set_body(Some(Arc::new(5)));
+    assert_eq!(message.priority(), Message::PRIORITY_HIGH);
+    assert!(message.body().and_then(|body| body.downcast_ref::<i32>()).is_some_and(|body| *body == 5));
+}
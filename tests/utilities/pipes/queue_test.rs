@@ -0,0 +1,58 @@
+use std::sync::{Arc, Mutex, RwLock};
+use puremvc::utilities::pipes::interfaces::{IPipeFitting, IPipeMessage};
+use puremvc::utilities::pipes::messages::{Message, QueueControlMessage};
+use puremvc::utilities::pipes::plumbing::{PipeListener, Queue};
+
+/// Creates a `PipeListener` that records the bodies of the messages it receives.
+fn recorder(received: Arc<Mutex<Vec<i32>>>) -> Arc<RwLock<dyn IPipeFitting>> {
+    Arc::new(RwLock::new(PipeListener::new(Arc::new(move |message: Arc<dyn IPipeMessage>| {
+        if let Some(body) = message.body().and_then(|body| body.downcast_ref::<i32>()) {
+            received.lock().unwrap().push(*body);
+        }
+    }))))
+}
+
+/// Creates a normal message carrying an integer body and the given priority.
+fn message(body: i32, priority: i32) -> Arc<dyn IPipeMessage> {
+    Arc::new(Message::new(Message::NORMAL, None, Some(Arc::new(body)), Some(priority)))
+}
+
+/// Tests that a `Queue` stores messages until flushed, in FIFO order.
+#[test]
+fn test_queue_fifo_flush() {
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let mut queue = Queue::new(Some(recorder(received.clone())));
+
+    queue.write(message(1, Message::PRIORITY_LOW));
+    queue.write(message(2, Message::PRIORITY_HIGH));
+    queue.write(message(3, Message::PRIORITY_MED));
+    assert_eq!(queue.len(), 3);
+    assert!(received.lock().unwrap().is_empty(), "Expecting nothing written before flush");
+
+    assert!(queue.write(Arc::new(QueueControlMessage::new(QueueControlMessage::FLUSH))), "Expecting flushed queue");
+    assert!(queue.is_empty(), "Expecting queue empty after flush");
+    assert_eq!(*received.lock().unwrap(), vec![1, 2, 3]);
+}
+
+/// Tests that a `Queue` in sort mode flushes messages by priority.
+///
+/// Messages with equal priority keep their arrival order.
+#[test]
+fn test_queue_sort_flush() {
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let mut queue = Queue::new(Some(recorder(received.clone())));
+
+    queue.write(Arc::new(QueueControlMessage::new(QueueControlMessage::SORT)));
+    queue.write(message(1, Message::PRIORITY_LOW));
+    queue.write(message(2, Message::PRIORITY_HIGH));
+    queue.write(message(3, Message::PRIORITY_MED));
+    queue.write(message(4, Message::PRIORITY_HIGH));
+    queue.write(Arc::new(QueueControlMessage::new(QueueControlMessage::FLUSH)));
+    assert_eq!(*received.lock().unwrap(), vec![2, 4, 3, 1]);
+
+    queue.write(Arc::new(QueueControlMessage::new(QueueControlMessage::FIFO)));
+    queue.write(message(5, Message::PRIORITY_LOW));
+    queue.write(message(6, Message::PRIORITY_HIGH));
+    queue.write(Arc::new(QueueControlMessage::new(QueueControlMessage::FLUSH)));
+    assert_eq!(*received.lock().unwrap(), vec![2, 4, 3, 1, 5, 6]);
+}
@@ -0,0 +1,70 @@
+use std::sync::{Arc, Mutex, RwLock};
+use puremvc::utilities::pipes::interfaces::{IPipeFitting, IPipeMessage};
+use puremvc::utilities::pipes::messages::Message;
+use puremvc::utilities::pipes::plumbing::{Pipe, PipeListener, TeeMerge, TeeSplit};
+
+/// Creates a `PipeListener` that records the bodies of the messages it receives.
+fn recorder(received: Arc<Mutex<Vec<i32>>>) -> Arc<RwLock<dyn IPipeFitting>> {
+    Arc::new(RwLock::new(PipeListener::new(Arc::new(move |message: Arc<dyn IPipeMessage>| {
+        if let Some(body) = message.body().and_then(|body| body.downcast_ref::<i32>()) {
+            received.lock().unwrap().push(*body);
+        }
+    }))))
+}
+
+/// Creates a normal message carrying an integer body.
+fn message(body: i32) -> Arc<dyn IPipeMessage> {
+    Arc::new(Message::new(Message::NORMAL, None, Some(Arc::new(body)), None))
+}
+
+/// Tests writing through a `TeeSplit` to multiple outputs.
+///
+/// Connects two listeners to a `TeeSplit` and asserts both receive the message.
+#[test]
+fn test_tee_split_writes_to_all_outputs() {
+    let received1 = Arc::new(Mutex::new(Vec::new()));
+    let received2 = Arc::new(Mutex::new(Vec::new()));
+    let mut tee = TeeSplit::new(vec![recorder(received1.clone())]);
+    tee.connect(recorder(received2.clone()));
+
+    assert!(tee.write(message(1)), "Expecting wrote message to tee");
+    assert_eq!(*received1.lock().unwrap(), vec![1]);
+    assert_eq!(*received2.lock().unwrap(), vec![1]);
+}
+
+/// Tests disconnecting outputs from a `TeeSplit`.
+///
+/// Disconnects a specific output and then the most recently connected one.
+#[test]
+fn test_tee_split_disconnect() {
+    let pipe1: Arc<RwLock<dyn IPipeFitting>> = Arc::new(RwLock::new(Pipe::new(None)));
+    let pipe2: Arc<RwLock<dyn IPipeFitting>> = Arc::new(RwLock::new(Pipe::new(None)));
+    let pipe3: Arc<RwLock<dyn IPipeFitting>> = Arc::new(RwLock::new(Pipe::new(None)));
+    let mut tee = TeeSplit::new(vec![Arc::clone(&pipe1), Arc::clone(&pipe2), Arc::clone(&pipe3)]);
+
+    assert!(tee.disconnect_fitting(&pipe2).is_some_and(|pipe| Arc::ptr_eq(&pipe, &pipe2)), "Expecting disconnected pipe2");
+    assert!(tee.disconnect_fitting(&pipe2).is_none(), "Expecting pipe2 no longer connected");
+    assert!(tee.disconnect().is_some_and(|pipe| Arc::ptr_eq(&pipe, &pipe3)), "Expecting disconnected pipe3");
+    assert!(tee.disconnect().is_some_and(|pipe| Arc::ptr_eq(&pipe, &pipe1)), "Expecting disconnected pipe1");
+    assert!(tee.disconnect().is_none(), "Expecting no outputs left");
+}
+
+/// Tests merging two input pipes through a `TeeMerge`.
+///
+/// Connects two input pipes to a `TeeMerge` whose output is a listener, writes to each input,
+/// and asserts that the listener received both messages in order.
+#[test]
+fn test_tee_merge_receives_from_all_inputs() {
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let merge = Arc::new(RwLock::new(TeeMerge::new()));
+    merge.write().unwrap().connect(recorder(received.clone()));
+
+    let input1: Arc<RwLock<dyn IPipeFitting>> = Arc::new(RwLock::new(Pipe::new(None)));
+    let input2: Arc<RwLock<dyn IPipeFitting>> = Arc::new(RwLock::new(Pipe::new(None)));
+    assert!(TeeMerge::connect_input(&merge, &input1), "Expecting connected input1");
+    assert!(TeeMerge::connect_input(&merge, &input2), "Expecting connected input2");
+
+    assert!(input1.write().unwrap().write(message(1)), "Expecting wrote message to input1");
+    assert!(input2.write().unwrap().write(message(2)), "Expecting wrote message to input2");
+    assert_eq!(*received.lock().unwrap(), vec![1, 2]);
+}